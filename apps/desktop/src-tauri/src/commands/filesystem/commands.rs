@@ -1,10 +1,11 @@
-use openvault_sdk::{FILESYSTEM_ROOT_FOLDER_ID, SecretVec};
+use openvault_sdk::{FILESYSTEM_ROOT_FOLDER_ID, FilesystemTrashItem};
 use uuid::Uuid;
 
 use super::contracts::{
     BrowseResult, BrowseVaultParams, ChangeFolderIconParams, CreateFolderParams, DeleteItemParams,
     ExportFileParams, ExportFolderParams, FileItem, FolderItem, ItemType, PathIsFileParams,
-    ReadFileParams, RenameItemParams, SetFavoriteItemParams, UploadFileParams, UploadFolderParams,
+    ReadFileParams, RenameItemParams, SetFavoriteItemParams, TrashItem, TrashItemParams,
+    UploadFileParams, UploadFolderParams,
 };
 use crate::errors::{Error, Result};
 use crate::internal::mime_type;
@@ -94,6 +95,61 @@ pub async fn delete_item(state: TauriState<'_>, params: DeleteItemParams) -> Res
     Ok(())
 }
 
+#[tauri::command]
+pub async fn list_trash(state: TauriState<'_>) -> Result<Vec<TrashItem>> {
+    vault_fs!(state, fs, vault);
+
+    let items = fs
+        .trash()
+        .iter()
+        .map(|entry| TrashItem {
+            id: entry.id.to_string(),
+            name: entry.item.name().unwrap_or_default().to_string(),
+            item_type: match entry.item {
+                FilesystemTrashItem::File(_) => ItemType::File,
+                FilesystemTrashItem::Folder { .. } => ItemType::Folder,
+            },
+            size: entry.item.size_bytes(),
+            original_parent_id: entry.original_parent_id.to_string(),
+            deleted_at: entry.deleted_at.to_string(),
+        })
+        .collect();
+
+    Ok(items)
+}
+
+#[tauri::command]
+pub async fn restore_trash_item(state: TauriState<'_>, params: TrashItemParams) -> Result {
+    vault_fs!(state, fs, vault);
+
+    let uuid = parse_uuid(&params.id)?;
+    fs.restore_from_trash(uuid)?;
+    vault.commit()?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn purge_trash_item(state: TauriState<'_>, params: TrashItemParams) -> Result {
+    vault_fs!(state, fs, vault);
+
+    let uuid = parse_uuid(&params.id)?;
+    fs.purge_from_trash(uuid)?;
+    vault.commit()?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn empty_trash(state: TauriState<'_>) -> Result {
+    vault_fs!(state, fs, vault);
+
+    fs.empty_trash()?;
+    vault.commit()?;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn rename_item(state: TauriState<'_>, params: RenameItemParams) -> Result {
     vault_fs!(state, fs, vault);
@@ -150,16 +206,13 @@ pub async fn expose_file_url(state: TauriState<'_>, params: ReadFileParams) -> R
         .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
 
     let uuid = parse_uuid(&params.id)?;
-    let content = fs.read_file_bytes(uuid)?;
 
     let token = Uuid::new_v4().to_string();
-    let secret_vec = SecretVec::new(content)
-        .map_err(|_| Error::Internal("Failed to create secret vec".to_string()))?;
 
     let extension = fs.get_file_extension(uuid)?;
     let mime_type = mime_type::mime_from_extension(&extension);
 
-    let secure_payload = SecurePayload::new(secret_vec, mime_type);
+    let secure_payload = SecurePayload::new(uuid, mime_type);
     secure_payloads.insert(token.clone(), secure_payload);
 
     Ok(secure::protocol_uri(&token))
@@ -115,6 +115,23 @@ pub struct FileItem {
     pub updated_at: String,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashItemParams {
+    pub id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashItem {
+    pub id: String,
+    pub name: String,
+    pub item_type: ItemType,
+    pub size: u64,
+    pub original_parent_id: String,
+    pub deleted_at: String,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowseResult {
@@ -1,10 +1,14 @@
-use openvault_sdk::MessageContact;
-use openvault_sdk::{EphemeralPublicKey, SigningPublicKey};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use openvault_sdk::{EphemeralPublicKey, STREAM_MAGIC, SigningPublicKey};
+use openvault_sdk::{FILESYSTEM_ROOT_FOLDER_ID, MessageContact, SharedFileDestination};
 
 use super::contracts::{
     AddContactParams, CreateCredentialsParams, DecryptFileParams, DecryptMessageParams,
-    EncryptFileParams, EncryptMessageParams, MessageContactItem, MessageCredentialsItem,
-    RemoveContactParams, RenameContactParams,
+    EncryptFileParams, EncryptMessageParams, ImportSharedFileParams, MessageContactItem,
+    MessageCredentialsItem, RemoveContactParams, RenameContactParams, ShareFileParams,
 };
 use crate::errors::{Error, Result};
 use crate::internal::parser::{parse_optional_datetime, parse_uuid};
@@ -180,17 +184,21 @@ pub async fn encrypt_file(state: TauriState<'_>, params: EncryptFileParams) -> R
 
     let id = parse_uuid(&params.contact_id)?;
 
-    let bytes = std::fs::read(&params.source_path).map_err(Error::Io)?;
-
-    if bytes.len() > 10 * 1024 * 1024 {
-        return Err(Error::InvalidInput("File too large (max 10MB)".to_string()));
-    }
+    let mut source = BufReader::new(File::open(&params.source_path).map_err(Error::Io)?);
+    let destination = Path::new(&params.destination_path);
+    let mut output = BufWriter::new(File::create_new(destination).map_err(Error::Io)?);
 
-    let encrypted = messages.encrypt_for_contact(id, &bytes)?;
+    let result = messages
+        .encrypt_stream_for_contact(id, &mut source, &mut output)
+        .map_err(Error::from)
+        .and_then(|_| output.flush().map_err(Error::Io));
+    drop(output);
 
-    std::fs::write(&params.destination_path, encrypted.as_bytes()).map_err(Error::Io)?;
+    if result.is_err() {
+        let _ = std::fs::remove_file(destination);
+    }
 
-    Ok(())
+    result
 }
 
 #[tauri::command]
@@ -199,11 +207,82 @@ pub async fn decrypt_file(state: TauriState<'_>, params: DecryptFileParams) -> R
 
     let id = parse_uuid(&params.contact_id)?;
 
-    let ciphertext = std::fs::read_to_string(&params.source_path).map_err(Error::Io)?;
+    let mut source = BufReader::new(File::open(&params.source_path).map_err(Error::Io)?);
+    let destination = Path::new(&params.destination_path);
+
+    // Files encrypted before streaming landed are armored text envelopes.
+    let streamed = source
+        .fill_buf()
+        .map_err(Error::Io)?
+        .starts_with(STREAM_MAGIC);
+    if !streamed {
+        let mut armored = String::new();
+        source.read_to_string(&mut armored).map_err(Error::Io)?;
+
+        let decrypted = messages.decrypt_from_contact(id, armored.as_bytes())?;
+        let mut output = File::create_new(destination).map_err(Error::Io)?;
+
+        return output.write_all(decrypted.as_bytes()).map_err(Error::Io);
+    }
+
+    let mut output = BufWriter::new(File::create_new(destination).map_err(Error::Io)?);
+
+    let result = messages
+        .decrypt_stream_from_contact(id, &mut source, &mut output)
+        .map_err(Error::from)
+        .and_then(|_| output.flush().map_err(Error::Io));
+    drop(output);
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(destination);
+    }
+
+    result
+}
+
+#[tauri::command]
+pub async fn share_file(state: TauriState<'_>, params: ShareFileParams) -> Result {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
 
-    let decrypted = messages.decrypt_from_contact(id, ciphertext.as_bytes())?;
+    let file_id = parse_uuid(&params.file_id)?;
+    let contact_id = parse_uuid(&params.contact_id)?;
 
-    std::fs::write(&params.destination_path, decrypted.as_bytes()).map_err(Error::Io)?;
+    vault.share_file(file_id, contact_id, PathBuf::from(params.destination_path))?;
 
     Ok(())
 }
+
+#[tauri::command]
+pub async fn import_shared_file(
+    state: TauriState<'_>,
+    params: ImportSharedFileParams,
+) -> Result<String> {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let contact_id = parse_uuid(&params.contact_id)?;
+    let folder_id = match params.folder_id.as_deref() {
+        Some(id) => parse_uuid(id)?,
+        None => FILESYSTEM_ROOT_FOLDER_ID,
+    };
+    let extension = Path::new(&params.name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_owned();
+
+    let id = vault.import_shared_file(
+        contact_id,
+        PathBuf::from(params.source_path),
+        SharedFileDestination {
+            folder_id,
+            name: params.name,
+            extension,
+        },
+    )?;
+
+    vault.commit()?;
+
+    Ok(id.to_string())
+}
@@ -58,6 +58,23 @@ pub struct DecryptFileParams {
     pub destination_path: String,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareFileParams {
+    pub contact_id: String,
+    pub file_id: String,
+    pub destination_path: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSharedFileParams {
+    pub contact_id: String,
+    pub source_path: String,
+    pub folder_id: Option<String>,
+    pub name: String,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MessageContactItem {
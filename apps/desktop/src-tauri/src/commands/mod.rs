@@ -1,3 +1,4 @@
 pub mod filesystem;
 pub mod messages;
+pub mod secrets;
 pub mod vault;
@@ -0,0 +1,186 @@
+use openvault_sdk::{
+    GeneratedPassword, NewApiKeySecret, NewApiKeySecretPatch, PassphraseOptions, PasswordOptions,
+    SECRETS_ROOT_FOLDER_ID, SecretEntry, SecretEntryView,
+};
+
+use super::contracts::{
+    AddApiKeyParams, ApiKeyDetails, ApiKeyItem, ApiKeyParams, GeneratePassphraseParams,
+    GeneratePasswordParams, GeneratedPasswordDetails, ListApiKeysParams, MoveApiKeyParams,
+    TotpCodeDetails, TotpParams, UpdateApiKeyParams,
+};
+use crate::errors::{Error, Result};
+use crate::internal::parser::{parse_optional_uuid, parse_uuid};
+use crate::state::TauriState;
+
+macro_rules! vault_secrets {
+    ($state:expr, mut $secrets:ident, $vault:ident) => {
+        let mut $vault = $state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+        let $vault = $vault.as_mut().ok_or(Error::VaultNotOpened)?;
+        let mut $secrets = $vault.secrets();
+    };
+    ($state:expr, $secrets:ident, $vault:ident) => {
+        let mut $vault = $state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+        let $vault = $vault.as_mut().ok_or(Error::VaultNotOpened)?;
+        let $secrets = $vault.secrets();
+    };
+}
+
+#[tauri::command]
+pub async fn list_api_keys(
+    state: TauriState<'_>,
+    params: ListApiKeysParams,
+) -> Result<Vec<ApiKeyItem>> {
+    vault_secrets!(state, secrets, vault);
+
+    let folder_id =
+        parse_optional_uuid(params.folder_id.as_deref())?.unwrap_or(SECRETS_ROOT_FOLDER_ID);
+
+    let items = secrets
+        .list_entries(folder_id)
+        .iter()
+        .filter_map(SecretEntry::as_api_key)
+        .map(|api_key| ApiKeyItem {
+            id: api_key.id.to_string(),
+            folder_id: api_key.folder_id.to_string(),
+            name: api_key.name.clone(),
+            created_at: api_key.created_at.to_rfc3339(),
+            updated_at: api_key.updated_at.to_rfc3339(),
+        })
+        .collect();
+
+    Ok(items)
+}
+
+#[tauri::command]
+pub async fn add_api_key(state: TauriState<'_>, params: AddApiKeyParams) -> Result<String> {
+    vault_secrets!(state, mut secrets, vault);
+
+    let folder_id =
+        parse_optional_uuid(params.folder_id.as_deref())?.unwrap_or(SECRETS_ROOT_FOLDER_ID);
+
+    let id = secrets.add_api_key(NewApiKeySecret {
+        folder_id,
+        name: params.name,
+        api_key: params.api_key,
+        website: params.website,
+        comments: params.comments,
+    })?;
+    vault.commit()?;
+
+    Ok(id.to_string())
+}
+
+#[tauri::command]
+pub async fn update_api_key(state: TauriState<'_>, params: UpdateApiKeyParams) -> Result {
+    vault_secrets!(state, mut secrets, vault);
+
+    let id = parse_uuid(&params.id)?;
+    secrets.update_api_key(
+        id,
+        NewApiKeySecretPatch {
+            folder_id: None,
+            name: params.name,
+            api_key: params.api_key,
+            website: params.website,
+            comments: params.comments,
+        },
+    )?;
+    vault.commit()?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn reveal_api_key(state: TauriState<'_>, params: ApiKeyParams) -> Result<ApiKeyDetails> {
+    vault_secrets!(state, secrets, vault);
+
+    let SecretEntryView::ApiKey(view) = secrets.reveal_entry(&parse_uuid(&params.id)?)? else {
+        return Err(Error::InvalidInput(format!(
+            "{} is not an API key",
+            params.id
+        )));
+    };
+
+    Ok(ApiKeyDetails {
+        id: view.id.to_string(),
+        folder_id: view.folder_id.to_string(),
+        name: view.name.clone(),
+        api_key: view.api_key.clone(),
+        website: view.website.clone(),
+        comments: view.comments.clone(),
+        created_at: view.created_at.to_rfc3339(),
+        updated_at: view.updated_at.to_rfc3339(),
+    })
+}
+
+#[tauri::command]
+pub async fn move_api_key(state: TauriState<'_>, params: MoveApiKeyParams) -> Result {
+    vault_secrets!(state, mut secrets, vault);
+
+    secrets.move_entry(parse_uuid(&params.id)?, parse_uuid(&params.folder_id)?)?;
+    vault.commit()?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn remove_api_key(state: TauriState<'_>, params: ApiKeyParams) -> Result {
+    vault_secrets!(state, mut secrets, vault);
+
+    secrets.remove_entry(parse_uuid(&params.id)?)?;
+    vault.commit()?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn current_totp(state: TauriState<'_>, params: TotpParams) -> Result<TotpCodeDetails> {
+    vault_secrets!(state, secrets, vault);
+
+    let totp = secrets.current_totp(&parse_uuid(&params.id)?)?;
+
+    Ok(TotpCodeDetails {
+        code: totp.code.clone(),
+        period: totp.period,
+        remaining_seconds: totp.remaining_seconds,
+    })
+}
+
+#[tauri::command]
+pub async fn generate_password(params: GeneratePasswordParams) -> Result<GeneratedPasswordDetails> {
+    let defaults = PasswordOptions::default();
+    let password = openvault_sdk::generate_password(&PasswordOptions {
+        length: params.length.unwrap_or(defaults.length),
+        lowercase: params.lowercase.unwrap_or(defaults.lowercase),
+        uppercase: params.uppercase.unwrap_or(defaults.uppercase),
+        digits: params.digits.unwrap_or(defaults.digits),
+        symbols: params.symbols.unwrap_or(defaults.symbols),
+        exclude_look_alikes: params
+            .exclude_look_alikes
+            .unwrap_or(defaults.exclude_look_alikes),
+    })?;
+
+    Ok(generated_details(&password))
+}
+
+#[tauri::command]
+pub async fn generate_passphrase(
+    params: GeneratePassphraseParams,
+) -> Result<GeneratedPasswordDetails> {
+    let defaults = PassphraseOptions::default();
+    let passphrase = openvault_sdk::generate_passphrase(&PassphraseOptions {
+        words: params.words.unwrap_or(defaults.words),
+        separator: params.separator.unwrap_or(defaults.separator),
+        capitalize: params.capitalize.unwrap_or(defaults.capitalize),
+        include_number: params.include_number.unwrap_or(defaults.include_number),
+    })?;
+
+    Ok(generated_details(&passphrase))
+}
+
+fn generated_details(password: &GeneratedPassword) -> GeneratedPasswordDetails {
+    GeneratedPasswordDetails {
+        value: password.as_str().to_string(),
+        entropy_bits: password.entropy_bits(),
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListApiKeysParams {
+    pub folder_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddApiKeyParams {
+    pub folder_id: Option<String>,
+    pub name: String,
+    pub api_key: String,
+    pub website: Option<String>,
+    pub comments: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateApiKeyParams {
+    pub id: String,
+    pub name: Option<String>,
+    pub api_key: Option<String>,
+    pub website: Option<String>,
+    pub comments: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveApiKeyParams {
+    pub id: String,
+    pub folder_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyParams {
+    pub id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyItem {
+    pub id: String,
+    pub folder_id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyDetails {
+    pub id: String,
+    pub folder_id: String,
+    pub name: String,
+    pub api_key: String,
+    pub website: String,
+    pub comments: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpParams {
+    pub id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpCodeDetails {
+    pub code: String,
+    pub period: u64,
+    pub remaining_seconds: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratePasswordParams {
+    pub length: Option<usize>,
+    pub lowercase: Option<bool>,
+    pub uppercase: Option<bool>,
+    pub digits: Option<bool>,
+    pub symbols: Option<bool>,
+    pub exclude_look_alikes: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratePassphraseParams {
+    pub words: Option<usize>,
+    pub separator: Option<String>,
+    pub capitalize: Option<bool>,
+    pub include_number: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedPasswordDetails {
+    pub value: String,
+    pub entropy_bits: f64,
+}
@@ -0,0 +1,4 @@
+mod commands;
+mod contracts;
+
+pub use commands::*;
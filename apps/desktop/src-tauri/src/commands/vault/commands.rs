@@ -1,12 +1,23 @@
-use openvault_sdk::{CompressionAlgorithm, CreateConfig, EncryptionAlgorithm};
+use openvault_sdk::{
+    ArchiveCredential, ArchiveDestination, ArchiveRecipient, ArchiveSelection,
+    CompressionAlgorithm, CreateConfig, Credential, EncryptionAlgorithm, VerifyReport,
+};
 use std::path::PathBuf;
 use std::str::FromStr;
 use zeroize::Zeroize;
 
-use super::contracts::{CreateVaultParams, OpenVaultParams};
-use crate::commands::vault::contracts::{CreateVaultResult, VaultMetaResult};
+use super::contracts::{
+    AddKeyfileSlotParams, ChangePasswordParams, CreateVaultParams, ExportArchiveParams,
+    ImportArchiveParams, ImportArchiveResult, OpenVaultParams, OpenVaultWithKeyfileParams,
+    OpenVaultWithRecoveryKeyParams, RevokeKeySlotParams, RotateVaultKeysParams, VerifyIssueItem,
+    VerifyVaultParams, VerifyVaultResult,
+};
+use crate::commands::vault::contracts::{
+    CreateVaultResult, KeySlotItem, SpaceReportResult, VaultMetaResult,
+};
 use crate::errors::{Error, Result};
 use crate::internal::format::string_from_bytes;
+use crate::internal::parser::parse_uuid;
 use crate::state::TauriState;
 
 #[tauri::command]
@@ -55,12 +66,43 @@ pub async fn open_vault(state: TauriState<'_>, params: OpenVaultParams) -> Resul
     Ok(())
 }
 
+#[tauri::command]
+pub async fn open_vault_with_recovery_key(
+    state: TauriState<'_>,
+    params: OpenVaultWithRecoveryKeyParams,
+) -> Result {
+    let path = PathBuf::from(params.path);
+    let mut recovery_key = params.recovery_key;
+
+    let vault = openvault_sdk::client().open_with_recovery_key(path, &recovery_key);
+    recovery_key.zeroize();
+
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    *vault_state = Some(vault?);
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn open_vault_with_keyfile(
+    state: TauriState<'_>,
+    params: OpenVaultWithKeyfileParams,
+) -> Result {
+    let path = PathBuf::from(params.path);
+    let vault = openvault_sdk::client().open_with_keyfile(path, params.keyfile_path)?;
+
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    *vault_state = Some(vault);
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn lock_vault(state: TauriState<'_>) -> Result {
     let mut vault_lock = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
 
-    if let Some(mut vault) = vault_lock.take() {
-        vault.zeroize();
+    if let Some(vault) = vault_lock.take() {
+        vault.lock()?;
     }
 
     Ok(())
@@ -76,6 +118,137 @@ pub async fn compact_vault(state: TauriState<'_>) -> Result {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn change_password(state: TauriState<'_>, params: ChangePasswordParams) -> Result {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let mut current_password = string_from_bytes(params.current_password)?;
+    let mut new_password = string_from_bytes(params.new_password)?;
+
+    let result = vault.change_password(&current_password, &new_password);
+
+    current_password.zeroize();
+    new_password.zeroize();
+
+    result.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn rotate_vault_keys(state: TauriState<'_>, params: RotateVaultKeysParams) -> Result {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let mut password = string_from_bytes(params.password)?;
+    let credential = Credential::password(password.as_bytes());
+    password.zeroize();
+
+    vault
+        .compact_and_rotate_keys(&[credential])
+        .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn export_archive(state: TauriState<'_>, params: ExportArchiveParams) -> Result {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let selection = ArchiveSelection {
+        filesystem_folder: params.folder_id.as_deref().map(parse_uuid).transpose()?,
+        secrets_folder: params
+            .secret_folder_id
+            .as_deref()
+            .map(parse_uuid)
+            .transpose()?,
+    };
+
+    let mut password = string_from_bytes(params.password)?;
+    let result = vault.export_archive(
+        &selection,
+        &ArchiveRecipient::Password(password.as_bytes()),
+        PathBuf::from(params.destination_path),
+    );
+    password.zeroize();
+
+    result.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn import_archive(
+    state: TauriState<'_>,
+    params: ImportArchiveParams,
+) -> Result<ImportArchiveResult> {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let mut password = string_from_bytes(params.password)?;
+    let result = vault.import_archive(
+        PathBuf::from(params.source_path),
+        &ArchiveCredential::Password(password.as_bytes()),
+        &ArchiveDestination::default(),
+    );
+    password.zeroize();
+
+    let summary = result?;
+
+    Ok(ImportArchiveResult {
+        folders: summary.folders,
+        files: summary.files,
+        secret_folders: summary.secret_folders,
+        secrets: summary.secrets,
+    })
+}
+
+#[tauri::command]
+pub async fn list_key_slots(state: TauriState<'_>) -> Result<Vec<KeySlotItem>> {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let slots = vault
+        .key_slots()?
+        .into_iter()
+        .map(|slot| KeySlotItem {
+            index: slot.index,
+            kind: slot.kind.to_string(),
+        })
+        .collect();
+
+    Ok(slots)
+}
+
+#[tauri::command]
+pub async fn add_recovery_key(state: TauriState<'_>) -> Result<String> {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let recovery_key = vault.add_recovery_key()?;
+
+    Ok(recovery_key.as_str().to_string())
+}
+
+#[tauri::command]
+pub async fn add_keyfile_slot(
+    state: TauriState<'_>,
+    params: AddKeyfileSlotParams,
+) -> Result<usize> {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let index = vault.add_keyfile(PathBuf::from(params.keyfile_path))?;
+
+    Ok(index)
+}
+
+#[tauri::command]
+pub async fn revoke_key_slot(state: TauriState<'_>, params: RevokeKeySlotParams) -> Result {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    vault.revoke_key_slot(params.index)?;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn get_vault_meta(state: TauriState<'_>) -> Result<VaultMetaResult> {
     let vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
@@ -88,3 +261,105 @@ pub async fn get_vault_meta(state: TauriState<'_>) -> Result<VaultMetaResult> {
         version: vault.version(),
     })
 }
+
+#[tauri::command]
+pub async fn get_space_report(state: TauriState<'_>) -> Result<SpaceReportResult> {
+    let mut vault_state = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault_state.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let report = vault.space_report()?;
+
+    Ok(SpaceReportResult {
+        total_bytes: report.total_bytes,
+        reclaimable_bytes: report.reclaimable_bytes,
+        reclaimable_ratio: report.reclaimable_ratio(),
+    })
+}
+
+#[tauri::command]
+pub async fn verify_vault(params: VerifyVaultParams) -> Result<VerifyVaultResult> {
+    let path = PathBuf::from(params.path);
+    let mut password = string_from_bytes(params.password)?;
+
+    let report = openvault_sdk::verify_vault(path, &password);
+    password.zeroize();
+
+    Ok(verify_result(report?))
+}
+
+fn verify_result(report: VerifyReport) -> VerifyVaultResult {
+    let mut issues = Vec::new();
+
+    if !report.boot_header_valid {
+        issues.push(VerifyIssueItem {
+            kind: "bootHeader".to_string(),
+            offset: 0,
+            message: "Boot header checksum or layout is invalid".to_string(),
+        });
+    } else if !report.subheader_valid {
+        issues.push(VerifyIssueItem {
+            kind: "subheader".to_string(),
+            offset: 0,
+            message: "Subheader could not be decrypted".to_string(),
+        });
+    }
+
+    if let Some(offset) = report.truncated_at {
+        issues.push(VerifyIssueItem {
+            kind: "truncated".to_string(),
+            offset,
+            message: "Frame runs past the end of the file".to_string(),
+        });
+    }
+
+    if let Some(error) = &report.replay_error {
+        issues.push(VerifyIssueItem {
+            kind: "replay".to_string(),
+            offset: 0,
+            message: error.clone(),
+        });
+    }
+
+    issues.extend(report.broken_frames.iter().map(|frame| VerifyIssueItem {
+        kind: "brokenFrame".to_string(),
+        offset: frame.offset,
+        message: match frame.expected {
+            Some(kind) => format!("{kind} frame failed authentication"),
+            None => "Frame failed authentication".to_string(),
+        },
+    }));
+
+    issues.extend(report.sequence_gaps.iter().map(|gap| VerifyIssueItem {
+        kind: "sequenceGap".to_string(),
+        offset: gap.offset,
+        message: format!("Expected sequence {}, found {}", gap.expected, gap.found),
+    }));
+
+    issues.extend(report.dangling_blobs.iter().map(|blob| VerifyIssueItem {
+        kind: "danglingBlob".to_string(),
+        offset: blob.manifest_offset,
+        message: format!(
+            "Blob {} is missing {} chunk(s)",
+            blob.blob_id,
+            blob.missing_chunks.len()
+        ),
+    }));
+
+    issues.extend(report.orphaned_frames.iter().map(|frame| VerifyIssueItem {
+        kind: "orphanedFrame".to_string(),
+        offset: frame.offset,
+        message: format!("Unreferenced {} frame of {} bytes", frame.kind, frame.size),
+    }));
+
+    VerifyVaultResult {
+        healthy: report.is_healthy(),
+        version: report.version,
+        frames_scanned: report.frames_scanned,
+        records_checked: report.records_checked,
+        checkpoints_checked: report.checkpoints_checked,
+        blobs_checked: report.blobs_checked,
+        recorded_reclaimable_bytes: report.recorded_reclaimable_bytes,
+        found_reclaimable_bytes: report.found_reclaimable_bytes,
+        issues,
+    }
+}
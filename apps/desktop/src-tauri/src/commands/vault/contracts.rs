@@ -25,9 +25,117 @@ pub struct VaultMetaResult {
     pub version: u16,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpaceReportResult {
+    pub total_bytes: u64,
+    pub reclaimable_bytes: u64,
+    pub reclaimable_ratio: f64,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenVaultParams {
     pub path: String,
     pub password: Vec<u8>,
 }
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyVaultParams {
+    pub path: String,
+    pub password: Vec<u8>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyIssueItem {
+    pub kind: String,
+    pub offset: u64,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyVaultResult {
+    pub healthy: bool,
+    pub version: u16,
+    pub frames_scanned: usize,
+    pub records_checked: usize,
+    pub checkpoints_checked: usize,
+    pub blobs_checked: usize,
+    pub recorded_reclaimable_bytes: u64,
+    pub found_reclaimable_bytes: u64,
+    pub issues: Vec<VerifyIssueItem>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePasswordParams {
+    pub current_password: Vec<u8>,
+    pub new_password: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateVaultKeysParams {
+    pub password: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportArchiveParams {
+    pub folder_id: Option<String>,
+    pub secret_folder_id: Option<String>,
+    pub password: Vec<u8>,
+    pub destination_path: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportArchiveParams {
+    pub source_path: String,
+    pub password: Vec<u8>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportArchiveResult {
+    pub folders: usize,
+    pub files: usize,
+    pub secret_folders: usize,
+    pub secrets: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenVaultWithRecoveryKeyParams {
+    pub path: String,
+    pub recovery_key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenVaultWithKeyfileParams {
+    pub path: String,
+    pub keyfile_path: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeySlotItem {
+    pub index: usize,
+    pub kind: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddKeyfileSlotParams {
+    pub keyfile_path: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeKeySlotParams {
+    pub index: usize,
+}
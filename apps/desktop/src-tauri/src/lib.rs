@@ -18,9 +18,21 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             crate::commands::vault::create_vault,
             crate::commands::vault::open_vault,
+            crate::commands::vault::open_vault_with_recovery_key,
+            crate::commands::vault::open_vault_with_keyfile,
             crate::commands::vault::compact_vault,
             crate::commands::vault::lock_vault,
+            crate::commands::vault::change_password,
+            crate::commands::vault::rotate_vault_keys,
+            crate::commands::vault::export_archive,
+            crate::commands::vault::import_archive,
+            crate::commands::vault::list_key_slots,
+            crate::commands::vault::add_recovery_key,
+            crate::commands::vault::add_keyfile_slot,
+            crate::commands::vault::revoke_key_slot,
             crate::commands::vault::get_vault_meta,
+            crate::commands::vault::get_space_report,
+            crate::commands::vault::verify_vault,
             crate::commands::filesystem::path_is_file,
             crate::commands::filesystem::browse_fs,
             crate::commands::filesystem::create_folder,
@@ -30,6 +42,10 @@ pub fn run() {
             crate::commands::filesystem::set_folder_icon,
             crate::commands::filesystem::set_favorite_item,
             crate::commands::filesystem::delete_item,
+            crate::commands::filesystem::list_trash,
+            crate::commands::filesystem::restore_trash_item,
+            crate::commands::filesystem::purge_trash_item,
+            crate::commands::filesystem::empty_trash,
             crate::commands::filesystem::rename_item,
             crate::commands::filesystem::export_file,
             crate::commands::filesystem::export_folder,
@@ -45,9 +61,20 @@ pub fn run() {
             crate::commands::messages::decrypt_message,
             crate::commands::messages::encrypt_file,
             crate::commands::messages::decrypt_file,
+            crate::commands::messages::share_file,
+            crate::commands::messages::import_shared_file,
+            crate::commands::secrets::list_api_keys,
+            crate::commands::secrets::add_api_key,
+            crate::commands::secrets::update_api_key,
+            crate::commands::secrets::reveal_api_key,
+            crate::commands::secrets::move_api_key,
+            crate::commands::secrets::remove_api_key,
+            crate::commands::secrets::current_totp,
+            crate::commands::secrets::generate_password,
+            crate::commands::secrets::generate_passphrase,
         ])
         .register_uri_scheme_protocol(secure::PROTOCOL_SCHEME, move |app, request| {
-            handle_secure_protocol(app.app_handle(), request.uri())
+            handle_secure_protocol(app.app_handle(), &request)
         })
         .setup(|app| {
             let state = app.state::<AppState>();
@@ -1,3 +1,4 @@
+pub(crate) mod range;
 pub(crate) mod response;
 pub(crate) mod secure;
 
@@ -0,0 +1,131 @@
+const RANGE_UNIT_PREFIX: &str = "bytes=";
+
+pub const MAX_RANGE_SIZE: u64 = 4 * 1024 * 1024;
+pub const MAX_FULL_SIZE: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ByteRange {
+    pub fn byte_count(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    pub fn content_range(&self, size: u64) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, size)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRequest {
+    Full,
+    Partial(ByteRange),
+    Unsatisfiable,
+}
+
+pub fn parse_range(header: Option<&str>, size: u64) -> RangeRequest {
+    let Some(spec) = header
+        .and_then(|value| value.trim().strip_prefix(RANGE_UNIT_PREFIX))
+        .and_then(|ranges| ranges.split(',').next())
+    else {
+        return RangeRequest::Full;
+    };
+
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return RangeRequest::Full;
+    };
+
+    let range = match (start.parse::<u64>(), end.parse::<u64>()) {
+        (Ok(start), Ok(end)) if start <= end => resolve(start, end, size),
+        (Ok(start), Err(_)) if end.is_empty() => resolve(start, u64::MAX, size),
+        (Err(_), Ok(0)) if start.is_empty() => None,
+        (Err(_), Ok(suffix)) if start.is_empty() => {
+            resolve(size.saturating_sub(suffix), u64::MAX, size)
+        }
+        _ => return RangeRequest::Full,
+    };
+
+    range.map_or(RangeRequest::Unsatisfiable, RangeRequest::Partial)
+}
+
+fn resolve(start: u64, end: u64, size: u64) -> Option<ByteRange> {
+    if start >= size {
+        return None;
+    }
+
+    let end = end
+        .min(size - 1)
+        .min(start.saturating_add(MAX_RANGE_SIZE - 1));
+
+    Some(ByteRange { start, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partial(start: u64, end: u64) -> RangeRequest {
+        RangeRequest::Partial(ByteRange { start, end })
+    }
+
+    #[test]
+    fn test_parse_range_without_header_is_full() {
+        assert_eq!(parse_range(None, 100), RangeRequest::Full);
+        assert_eq!(parse_range(Some("items=0-1"), 100), RangeRequest::Full);
+    }
+
+    #[test]
+    fn test_parse_range_bounded_and_open_ended() {
+        assert_eq!(parse_range(Some("bytes=10-19"), 100), partial(10, 19));
+        assert_eq!(parse_range(Some("bytes=90-200"), 100), partial(90, 99));
+        assert_eq!(parse_range(Some("bytes=40-"), 100), partial(40, 99));
+    }
+
+    #[test]
+    fn test_parse_range_suffix() {
+        assert_eq!(parse_range(Some("bytes=-10"), 100), partial(90, 99));
+        assert_eq!(parse_range(Some("bytes=-500"), 100), partial(0, 99));
+        assert_eq!(
+            parse_range(Some("bytes=-0"), 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(
+            parse_range(Some("bytes=-10"), 0),
+            RangeRequest::Unsatisfiable
+        );
+    }
+
+    #[test]
+    fn test_parse_range_start_after_end_is_ignored() {
+        assert_eq!(parse_range(Some("bytes=20-10"), 100), RangeRequest::Full);
+    }
+
+    #[test]
+    fn test_parse_range_multi_range_serves_the_first() {
+        assert_eq!(parse_range(Some("bytes=0-9, 50-59"), 100), partial(0, 9));
+    }
+
+    #[test]
+    fn test_parse_range_start_past_size_is_unsatisfiable() {
+        assert_eq!(
+            parse_range(Some("bytes=100-"), 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(
+            parse_range(Some("bytes=150-160"), 100),
+            RangeRequest::Unsatisfiable
+        );
+    }
+
+    #[test]
+    fn test_parse_range_caps_window_size() {
+        let size = MAX_RANGE_SIZE * 3;
+        assert_eq!(
+            parse_range(Some("bytes=0-"), size),
+            partial(0, MAX_RANGE_SIZE - 1)
+        );
+    }
+}
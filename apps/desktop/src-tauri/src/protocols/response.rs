@@ -8,7 +8,14 @@ fn with_default_headers(builder: http::response::Builder) -> http::response::Bui
     builder
         .header("Access-Control-Allow-Origin", TAURI_ORIGINS)
         .header("Access-Control-Allow-Methods", "GET, POST, OPTIONS")
-        .header("Access-Control-Allow-Headers", "Content-Type, Accept")
+        .header(
+            "Access-Control-Allow-Headers",
+            "Content-Type, Accept, Range",
+        )
+        .header(
+            "Access-Control-Expose-Headers",
+            "Content-Range, Accept-Ranges",
+        )
         .header("Cache-Control", "no-store")
 }
 
@@ -24,12 +31,43 @@ pub fn not_found() -> Response<Vec<u8>> {
         .unwrap_or_else(|_| internal_error())
 }
 
-pub fn ok(body: &[u8], content_type: &str) -> Response<Vec<u8>> {
+pub fn ok(body: Vec<u8>, content_type: &str) -> Response<Vec<u8>> {
     let body_size = body.len();
 
     with_default_headers(Response::builder().status(200))
         .header("Content-Length", body_size)
         .header("Content-Type", content_type)
-        .body(body.to_vec())
+        .header("Accept-Ranges", "bytes")
+        .body(body)
+        .unwrap_or_else(|_| internal_error())
+}
+
+pub fn partial_content(
+    body: Vec<u8>,
+    content_type: &str,
+    content_range: &str,
+) -> Response<Vec<u8>> {
+    let body_size = body.len();
+
+    with_default_headers(Response::builder().status(206))
+        .header("Content-Length", body_size)
+        .header("Content-Type", content_type)
+        .header("Content-Range", content_range)
+        .header("Accept-Ranges", "bytes")
+        .body(body)
+        .unwrap_or_else(|_| internal_error())
+}
+
+pub fn range_not_satisfiable(size: u64) -> Response<Vec<u8>> {
+    with_default_headers(Response::builder().status(416))
+        .header("Content-Range", format!("bytes */{size}"))
+        .body(Vec::new())
+        .unwrap_or_else(|_| internal_error())
+}
+
+pub fn ranges_required() -> Response<Vec<u8>> {
+    with_default_headers(Response::builder().status(413))
+        .header("Accept-Ranges", "bytes")
+        .body(Vec::new())
         .unwrap_or_else(|_| internal_error())
 }
@@ -1,23 +1,37 @@
-use openvault_sdk::SecretVec;
-use tauri::http::{Response, Uri};
+use std::io::{Read, Seek, SeekFrom};
+
+use tauri::http::header::RANGE;
+use tauri::http::{Request, Response};
 use tauri::{AppHandle, Manager};
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
+use super::range::{MAX_FULL_SIZE, RangeRequest, parse_range};
 use super::response;
 use crate::AppState;
+use crate::errors::{Error, Result};
 use crate::internal::mime_type::{is_mime_audio, is_mime_video};
 
 pub const PROTOCOL_SCHEME: &str = "secure";
 pub const PROTOCOL_HOST: &str = "localhost";
 
+#[derive(Clone)]
 pub struct SecurePayload {
-    pub data: SecretVec,
+    pub file_id: Uuid,
     pub content_type: String,
 }
 
 impl SecurePayload {
-    pub fn new(data: SecretVec, content_type: String) -> Self {
-        Self { data, content_type }
+    pub fn new(file_id: Uuid, content_type: String) -> Self {
+        Self {
+            file_id,
+            content_type,
+        }
+    }
+
+    // Media elements keep requesting ranges, so their token stays valid after each response.
+    fn is_streamable(&self) -> bool {
+        is_mime_video(&self.content_type) || is_mime_audio(&self.content_type)
     }
 }
 
@@ -25,10 +39,10 @@ pub fn protocol_uri(token: &str) -> String {
     format!("{PROTOCOL_SCHEME}://{PROTOCOL_HOST}/{token}")
 }
 
-pub fn handle_secure_protocol(app: &AppHandle, request_uri: &Uri) -> Response<Vec<u8>> {
+pub fn handle_secure_protocol(app: &AppHandle, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
     let state = app.state::<AppState>();
 
-    let request_uri_str = request_uri.to_string();
+    let request_uri_str = request.uri().to_string();
     let token = request_uri_str
         .strip_prefix(&protocol_uri(""))
         .unwrap_or_default()
@@ -38,21 +52,72 @@ pub fn handle_secure_protocol(app: &AppHandle, request_uri: &Uri) -> Response<Ve
         return response::not_found();
     }
 
-    let mut secure_payloads = match state.secure_payloads.lock() {
-        Ok(lock) => lock,
-        Err(_) => return response::internal_error(),
-    };
+    let payload = {
+        let mut secure_payloads = match state.secure_payloads.lock() {
+            Ok(lock) => lock,
+            Err(_) => return response::internal_error(),
+        };
 
-    let payload = match secure_payloads.remove(&token) {
-        Some(payload) => payload,
-        None => return response::not_found(),
+        let payload = match secure_payloads.remove(&token) {
+            Some(payload) => payload,
+            None => return response::not_found(),
+        };
+
+        if payload.is_streamable() {
+            secure_payloads.insert(token, payload.clone());
+        }
+
+        payload
     };
 
-    let response = response::ok(payload.data.as_bytes(), &payload.content_type);
+    let range = request
+        .headers()
+        .get(RANGE)
+        .and_then(|value| value.to_str().ok());
+
+    serve_payload(&state, &payload, range).unwrap_or_else(|_| response::internal_error())
+}
 
-    if is_mime_video(&payload.content_type) || is_mime_audio(&payload.content_type) {
-        secure_payloads.insert(token, payload);
+fn serve_payload(
+    state: &AppState,
+    payload: &SecurePayload,
+    range: Option<&str>,
+) -> Result<Response<Vec<u8>>> {
+    let mut vault = state.vault.lock().map_err(|_| Error::LockPoisoned)?;
+    let vault = vault.as_mut().ok_or(Error::VaultNotOpened)?;
+
+    let mut fs = vault.filesystem();
+    let mut reader = fs.open_file(payload.file_id)?;
+    let size = reader.seek(SeekFrom::End(0))?;
+
+    match parse_range(range, size) {
+        // Anything larger would be decrypted into memory at once, the client has to ask for ranges.
+        RangeRequest::Full if size > MAX_FULL_SIZE => Ok(response::ranges_required()),
+        RangeRequest::Full => {
+            let body = read_payload(&mut reader, 0, size)?;
+
+            Ok(response::ok(body, &payload.content_type))
+        }
+        RangeRequest::Partial(range) => {
+            let body = read_payload(&mut reader, range.start, range.byte_count())?;
+
+            Ok(response::partial_content(
+                body,
+                &payload.content_type,
+                &range.content_range(size),
+            ))
+        }
+        RangeRequest::Unsatisfiable => Ok(response::range_not_satisfiable(size)),
     }
+}
+
+// Plaintext stays in a zeroizing buffer until it is handed over to the webview, so a failed
+// read never leaves decrypted bytes behind.
+fn read_payload(reader: &mut (impl Read + Seek), start: u64, len: u64) -> Result<Vec<u8>> {
+    let mut body = Zeroizing::new(vec![0u8; len as usize]);
+
+    reader.seek(SeekFrom::Start(start))?;
+    reader.read_exact(&mut body)?;
 
-    response
+    Ok(std::mem::take(&mut *body))
 }
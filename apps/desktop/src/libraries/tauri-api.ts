@@ -1,6 +1,19 @@
 import { logger } from "@/libraries/logger";
-import { ItemType, VaultMetaResult, type BrowseResult } from "@/types/filesystem";
+import {
+  ItemType,
+  VaultMetaResult,
+  type BrowseResult,
+  type ImportArchiveResult,
+  type SpaceReportResult,
+  type VerifyVaultResult,
+} from "@/types/filesystem";
 import { type MessageContact, type MessageCredentials } from "@/types/messages";
+import {
+  type ApiKeyDetails,
+  type ApiKeyItem,
+  type GeneratedPassword,
+  type TotpCode,
+} from "@/types/secrets";
 import { invoke } from "@tauri-apps/api/core";
 
 export type Result<T> = { success: true; data: T } | { success: false; error: unknown };
@@ -34,14 +47,67 @@ export const tauriApi = {
     return safeInvokeTauri<void>("open_vault", { params });
   },
 
+  openVaultWithRecoveryKey: (params: { path: string; recoveryKey: string }) => {
+    return safeInvokeTauri<void>("open_vault_with_recovery_key", { params });
+  },
+
+  openVaultWithKeyfile: (params: { path: string; keyfilePath: string }) => {
+    return safeInvokeTauri<void>("open_vault_with_keyfile", { params });
+  },
+
   lockVault: () => {
     return safeInvokeTauri<void>("lock_vault", {});
   },
 
+  changePassword: (params: { currentPassword: number[]; newPassword: number[] }) => {
+    return safeInvokeTauri<void>("change_password", { params });
+  },
+
+  rotateVaultKeys: (params: { password: number[] }) => {
+    return safeInvokeTauri<void>("rotate_vault_keys", { params });
+  },
+
+  exportArchive: (params: {
+    folderId?: string;
+    secretFolderId?: string;
+    password: number[];
+    destinationPath: string;
+  }) => {
+    return safeInvokeTauri<void>("export_archive", { params });
+  },
+
+  importArchive: (params: { sourcePath: string; password: number[] }) => {
+    return safeInvokeTauri<ImportArchiveResult>("import_archive", { params });
+  },
+
+  listKeySlots: () => {
+    return safeInvokeTauri<{ index: number; kind: string }[]>("list_key_slots", {});
+  },
+
+  addRecoveryKey: () => {
+    return safeInvokeTauri<string>("add_recovery_key", {});
+  },
+
+  addKeyfileSlot: (params: { keyfilePath: string }) => {
+    return safeInvokeTauri<number>("add_keyfile_slot", { params });
+  },
+
+  revokeKeySlot: (params: { index: number }) => {
+    return safeInvokeTauri<void>("revoke_key_slot", { params });
+  },
+
   getVaultMeta: () => {
     return safeInvokeTauri<VaultMetaResult>("get_vault_meta", {});
   },
 
+  getSpaceReport: () => {
+    return safeInvokeTauri<SpaceReportResult>("get_space_report", {});
+  },
+
+  verifyVault: (params: { path: string; password: number[] }) => {
+    return safeInvokeTauri<VerifyVaultResult>("verify_vault", { params });
+  },
+
   browseFs: (params: { parentId?: string }) => {
     return safeInvokeTauri<BrowseResult>("browse_fs", { params });
   },
@@ -149,4 +215,81 @@ export const tauriApi = {
   }) => {
     return safeInvokeTauri<void>("decrypt_file", { params });
   },
+
+  shareFile: (params: {
+    contactId: string;
+    fileId: string;
+    destinationPath: string;
+  }) => {
+    return safeInvokeTauri<void>("share_file", { params });
+  },
+
+  importSharedFile: (params: {
+    contactId: string;
+    sourcePath: string;
+    folderId?: string;
+    name: string;
+  }) => {
+    return safeInvokeTauri<string>("import_shared_file", { params });
+  },
+
+  listApiKeys: (params: { folderId?: string }) => {
+    return safeInvokeTauri<ApiKeyItem[]>("list_api_keys", { params });
+  },
+
+  addApiKey: (params: {
+    folderId?: string;
+    name: string;
+    apiKey: string;
+    website?: string;
+    comments?: string;
+  }) => {
+    return safeInvokeTauri<string>("add_api_key", { params });
+  },
+
+  updateApiKey: (params: {
+    id: string;
+    name?: string;
+    apiKey?: string;
+    website?: string;
+    comments?: string;
+  }) => {
+    return safeInvokeTauri<void>("update_api_key", { params });
+  },
+
+  revealApiKey: (params: { id: string }) => {
+    return safeInvokeTauri<ApiKeyDetails>("reveal_api_key", { params });
+  },
+
+  moveApiKey: (params: { id: string; folderId: string }) => {
+    return safeInvokeTauri<void>("move_api_key", { params });
+  },
+
+  removeApiKey: (params: { id: string }) => {
+    return safeInvokeTauri<void>("remove_api_key", { params });
+  },
+
+  currentTotp: (params: { id: string }) => {
+    return safeInvokeTauri<TotpCode>("current_totp", { params });
+  },
+
+  generatePassword: (params: {
+    length?: number;
+    lowercase?: boolean;
+    uppercase?: boolean;
+    digits?: boolean;
+    symbols?: boolean;
+    excludeLookAlikes?: boolean;
+  }) => {
+    return safeInvokeTauri<GeneratedPassword>("generate_password", { params });
+  },
+
+  generatePassphrase: (params: {
+    words?: number;
+    separator?: string;
+    capitalize?: boolean;
+    includeNumber?: boolean;
+  }) => {
+    return safeInvokeTauri<GeneratedPassword>("generate_passphrase", { params });
+  },
 };
@@ -37,3 +37,34 @@ export type VaultMetaResult = {
   sizeInBytes: number;
   version: number;
 };
+
+export type ImportArchiveResult = {
+  folders: number;
+  files: number;
+  secretFolders: number;
+  secrets: number;
+};
+
+export type SpaceReportResult = {
+  totalBytes: number;
+  reclaimableBytes: number;
+  reclaimableRatio: number;
+};
+
+export type VerifyIssueItem = {
+  kind: string;
+  offset: number;
+  message: string;
+};
+
+export type VerifyVaultResult = {
+  healthy: boolean;
+  version: number;
+  framesScanned: number;
+  recordsChecked: number;
+  checkpointsChecked: number;
+  blobsChecked: number;
+  recordedReclaimableBytes: number;
+  foundReclaimableBytes: number;
+  issues: VerifyIssueItem[];
+};
@@ -0,0 +1,29 @@
+export type ApiKeyItem = {
+  id: string;
+  folderId: string;
+  name: string;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type ApiKeyDetails = {
+  id: string;
+  folderId: string;
+  name: string;
+  apiKey: string;
+  website: string;
+  comments: string;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type TotpCode = {
+  code: string;
+  period: number;
+  remainingSeconds: number;
+};
+
+export type GeneratedPassword = {
+  value: string;
+  entropyBits: number;
+};
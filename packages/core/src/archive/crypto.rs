@@ -0,0 +1,240 @@
+use std::io::{Read, Write};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use openvault_crypto::encryption::Nonce;
+use openvault_crypto::keys::derived_key::DerivedKey;
+use openvault_crypto::keys::kdf_params::KdfParams;
+use openvault_crypto::keys::master_key::MasterKey;
+use openvault_crypto::keys::salt::Salt;
+use openvault_crypto::keys::{EphemeralKeyPair, EphemeralPrivateKey, EphemeralPublicKey};
+
+use super::header::{ArchiveHeader, ArchiveKeyMode};
+use crate::errors::{Error, Result};
+use crate::internal::io_ext::ReadExt;
+use crate::vault::crypto::envelope::Envelope;
+use crate::vault::versions::shared::frame::{FrameHeader, write_frame};
+
+const ARCHIVE_KEY_INFO: &[u8] = b"openvault-archive-v1";
+const MAX_FRAME_SIZE: u32 = 64 * 1024 * 1024;
+const MAX_MANIFEST_SIZE: usize = 64 * 1024 * 1024;
+
+pub(super) const ARCHIVE_CHUNK_SIZE: usize = 256 * 1024;
+
+pub enum ArchiveRecipient<'a> {
+    Password(&'a [u8]),
+    PublicKey(&'a EphemeralPublicKey),
+}
+
+pub enum ArchiveCredential<'a> {
+    Password(&'a [u8]),
+    PrivateKey(&'a EphemeralPrivateKey),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub(super) enum FrameDomain {
+    Manifest = 1,
+    Chunk = 2,
+    End = 3,
+}
+
+impl FrameDomain {
+    // Writers never put more than this in a frame, so anything larger is a bomb.
+    fn plaintext_limit(self) -> usize {
+        match self {
+            Self::Manifest => MAX_MANIFEST_SIZE,
+            Self::Chunk => ARCHIVE_CHUNK_SIZE,
+            Self::End => 0,
+        }
+    }
+}
+
+impl ArchiveRecipient<'_> {
+    pub(super) fn seal_key(&self) -> Result<(ArchiveKeyMode, DerivedKey)> {
+        match self {
+            Self::Password(password) => {
+                let salt = Salt::random();
+                let kdf = KdfParams::default();
+                let master = MasterKey::derive_with_params(password, &salt, &kdf)?;
+
+                let key_mode = ArchiveKeyMode::Password {
+                    salt: salt.into_bytes(),
+                    kdf,
+                };
+                Ok((key_mode, master.expand(ARCHIVE_KEY_INFO)?))
+            }
+            Self::PublicKey(public_key) => {
+                let ephemeral = EphemeralKeyPair::generate()?;
+                let master = MasterKey::new(ephemeral.private.shared_secret(public_key))?;
+
+                let key_mode = ArchiveKeyMode::Recipient {
+                    ephemeral_public_key: ephemeral.public.to_bytes(),
+                };
+                Ok((key_mode, master.expand(ARCHIVE_KEY_INFO)?))
+            }
+        }
+    }
+}
+
+impl ArchiveCredential<'_> {
+    pub(super) fn open_key(&self, header: &ArchiveHeader) -> Result<DerivedKey> {
+        let master = match (self, &header.key_mode) {
+            (Self::Password(password), ArchiveKeyMode::Password { salt, kdf }) => {
+                // The header is untrusted, refuse to let it pick an arbitrarily expensive derivation.
+                if !KdfParams::MAX.is_at_least(kdf) {
+                    return Err(Error::InvalidArchiveFormat);
+                }
+                MasterKey::derive_with_params(password, &Salt::new(*salt), kdf)?
+            }
+            (
+                Self::PrivateKey(private_key),
+                ArchiveKeyMode::Recipient {
+                    ephemeral_public_key,
+                },
+            ) => {
+                let peer = EphemeralPublicKey::from_bytes(*ephemeral_public_key);
+                MasterKey::new(private_key.shared_secret(&peer))?
+            }
+            _ => return Err(Error::ArchiveUnlockFailed),
+        };
+
+        master.expand(ARCHIVE_KEY_INFO).map_err(Into::into)
+    }
+}
+
+pub(super) struct FrameCipher {
+    header: Vec<u8>,
+    key: DerivedKey,
+    envelope: Envelope,
+    index: u64,
+}
+
+impl FrameCipher {
+    pub fn new(header: &ArchiveHeader, header_bytes: Vec<u8>, key: DerivedKey) -> Self {
+        Self {
+            header: header_bytes,
+            key,
+            envelope: Envelope::new(header.compressor, header.cipher),
+            index: 0,
+        }
+    }
+
+    pub fn seal(&mut self, writer: &mut dyn Write, domain: FrameDomain, data: &[u8]) -> Result {
+        let nonce = Nonce::random();
+        let aad = self.next_aad(domain)?;
+
+        let ciphertext = self
+            .envelope
+            .seal_bytes(data, self.key.as_bytes(), &nonce, &aad)?;
+
+        write_frame(writer, &nonce, &ciphertext)
+    }
+
+    pub fn open(&mut self, reader: &mut dyn Read, domain: FrameDomain) -> Result<Vec<u8>> {
+        let frame = FrameHeader::read_from(reader)?;
+        if frame.size > MAX_FRAME_SIZE {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        let ciphertext = reader.read_exact_vec(frame.size as usize)?;
+        let aad = self.next_aad(domain)?;
+
+        self.envelope
+            .open_bytes_bounded(
+                &ciphertext,
+                self.key.as_bytes(),
+                &frame.nonce,
+                &aad,
+                domain.plaintext_limit(),
+            )
+            .map_err(|_| Error::InvalidArchiveFormat)
+    }
+
+    // Binding the header, the frame kind and its position stops frames from being
+    // swapped, dropped or replayed from another archive.
+    fn next_aad(&mut self, domain: FrameDomain) -> Result<Vec<u8>> {
+        let mut aad = self.header.clone();
+        aad.write_u8(domain as u8)?;
+        aad.write_u64::<LittleEndian>(self.index)?;
+
+        self.index += 1;
+
+        Ok(aad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use openvault_crypto::keys::EphemeralKeyPair;
+
+    use super::{
+        ARCHIVE_CHUNK_SIZE, ArchiveCredential, ArchiveRecipient, FrameCipher, FrameDomain,
+    };
+    use crate::archive::header::ArchiveHeader;
+    use crate::errors::Error;
+
+    fn cipher_pair() -> (FrameCipher, FrameCipher) {
+        let pair = EphemeralKeyPair::generate().expect("generate key pair");
+        let (key_mode, seal_key) = ArchiveRecipient::PublicKey(&pair.public)
+            .seal_key()
+            .expect("seal key");
+        let header = ArchiveHeader::new(Default::default(), Default::default(), key_mode);
+        let header_bytes = header.to_bytes().expect("encode header");
+        let open_key = ArchiveCredential::PrivateKey(&pair.private)
+            .open_key(&header)
+            .expect("open key");
+
+        (
+            FrameCipher::new(&header, header_bytes.clone(), seal_key),
+            FrameCipher::new(&header, header_bytes, open_key),
+        )
+    }
+
+    #[test]
+    fn chunk_frames_above_the_chunk_size_are_rejected() {
+        let (mut sealer, mut opener) = cipher_pair();
+        let mut archive = Vec::new();
+
+        sealer
+            .seal(
+                &mut archive,
+                FrameDomain::Chunk,
+                &vec![0u8; ARCHIVE_CHUNK_SIZE],
+            )
+            .expect("seal full chunk");
+        sealer
+            .seal(
+                &mut archive,
+                FrameDomain::Chunk,
+                &vec![0u8; ARCHIVE_CHUNK_SIZE + 1],
+            )
+            .expect("seal oversized chunk");
+
+        let mut reader = Cursor::new(archive);
+        let chunk = opener
+            .open(&mut reader, FrameDomain::Chunk)
+            .expect("open full chunk");
+        assert_eq!(chunk.len(), ARCHIVE_CHUNK_SIZE);
+        assert!(matches!(
+            opener.open(&mut reader, FrameDomain::Chunk),
+            Err(Error::InvalidArchiveFormat)
+        ));
+    }
+
+    #[test]
+    fn end_frames_must_be_empty() {
+        let (mut sealer, mut opener) = cipher_pair();
+        let mut archive = Vec::new();
+
+        sealer
+            .seal(&mut archive, FrameDomain::End, b"x")
+            .expect("seal end frame");
+
+        assert!(matches!(
+            opener.open(&mut Cursor::new(archive), FrameDomain::End),
+            Err(Error::InvalidArchiveFormat)
+        ));
+    }
+}
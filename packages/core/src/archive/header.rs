@@ -0,0 +1,155 @@
+use std::io::{Cursor, Read, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use openvault_crypto::compression::CompressionAlgorithm;
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::hash::Crc32Hasher;
+use openvault_crypto::keys::ephemeral::EPHEMERAL_KEY_SIZE;
+use openvault_crypto::keys::kdf_params::{KDF_PARAMS_SIZE, KdfParams};
+use openvault_crypto::keys::salt::SALT_SIZE;
+
+use crate::errors::{Error, Result};
+use crate::internal::io_ext::ReadExt;
+
+const ARCHIVE_MAGIC: &[u8; 6] = b"OPENVA";
+const CRC_SIZE: usize = 4;
+
+pub const ARCHIVE_VERSION: u16 = 3;
+const MIN_ARCHIVE_VERSION: u16 = 1;
+
+const PASSWORD_MODE: u8 = 1;
+const RECIPIENT_MODE: u8 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveKeyMode {
+    Password {
+        salt: [u8; SALT_SIZE],
+        kdf: KdfParams,
+    },
+    Recipient {
+        ephemeral_public_key: [u8; EPHEMERAL_KEY_SIZE],
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveHeader {
+    pub version: u16,
+    pub cipher: EncryptionAlgorithm,
+    pub compressor: CompressionAlgorithm,
+    pub key_mode: ArchiveKeyMode,
+}
+
+impl ArchiveHeader {
+    pub fn new(
+        cipher: EncryptionAlgorithm,
+        compressor: CompressionAlgorithm,
+        key_mode: ArchiveKeyMode,
+    ) -> Self {
+        Self {
+            version: ARCHIVE_VERSION,
+            cipher,
+            compressor,
+            key_mode,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        bytes.write_all(ARCHIVE_MAGIC)?;
+        bytes.write_u16::<LittleEndian>(self.version)?;
+        bytes.write_u8(self.cipher as u8)?;
+        bytes.write_u8(self.compressor as u8)?;
+
+        match &self.key_mode {
+            ArchiveKeyMode::Password { salt, kdf } => {
+                bytes.write_u8(PASSWORD_MODE)?;
+                bytes.write_all(salt)?;
+                bytes.write_all(&kdf.to_bytes())?;
+            }
+            ArchiveKeyMode::Recipient {
+                ephemeral_public_key,
+            } => {
+                bytes.write_u8(RECIPIENT_MODE)?;
+                bytes.write_all(ephemeral_public_key)?;
+            }
+        }
+
+        let crc = Crc32Hasher::checksum(&bytes);
+        bytes.write_u32::<LittleEndian>(crc)?;
+
+        Ok(bytes)
+    }
+
+    // Returns the raw header bytes alongside the header, they authenticate every frame.
+    pub fn read_from(reader: &mut dyn Read) -> Result<(Self, Vec<u8>)> {
+        let mut prefix = reader.read_exact_vec(ARCHIVE_MAGIC.len() + 5)?;
+        if prefix[..ARCHIVE_MAGIC.len()] != *ARCHIVE_MAGIC {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        let mode_size = match prefix[prefix.len() - 1] {
+            PASSWORD_MODE => SALT_SIZE + KDF_PARAMS_SIZE,
+            RECIPIENT_MODE => EPHEMERAL_KEY_SIZE,
+            _ => return Err(Error::InvalidArchiveFormat),
+        };
+        prefix.extend(reader.read_exact_vec(mode_size + CRC_SIZE)?);
+
+        let header = Self::from_bytes(&prefix)?;
+
+        Ok((header, prefix))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < ARCHIVE_MAGIC.len() + CRC_SIZE {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        let (payload, crc_bytes) = bytes.split_at(bytes.len() - CRC_SIZE);
+        let stored_crc = u32::from_le_bytes(
+            crc_bytes
+                .try_into()
+                .map_err(|_| Error::InvalidArchiveFormat)?,
+        );
+        if stored_crc != Crc32Hasher::checksum(payload) {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        let mut cursor = Cursor::new(payload);
+
+        let magic = cursor.read_exact_arr::<6>()?;
+        if magic != *ARCHIVE_MAGIC {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        let version = cursor.read_u16::<LittleEndian>()?;
+        if !(MIN_ARCHIVE_VERSION..=ARCHIVE_VERSION).contains(&version) {
+            return Err(Error::UnsupportedArchiveVersion(version));
+        }
+
+        let cipher = EncryptionAlgorithm::try_from(cursor.read_u8()?)?;
+        let compressor = CompressionAlgorithm::try_from(cursor.read_u8()?)?;
+
+        let key_mode = match cursor.read_u8()? {
+            PASSWORD_MODE => ArchiveKeyMode::Password {
+                salt: cursor.read_exact_arr::<SALT_SIZE>()?,
+                kdf: KdfParams::from_bytes(cursor.read_exact_arr::<KDF_PARAMS_SIZE>()?)?,
+            },
+            RECIPIENT_MODE => ArchiveKeyMode::Recipient {
+                ephemeral_public_key: cursor.read_exact_arr::<EPHEMERAL_KEY_SIZE>()?,
+            },
+            _ => return Err(Error::InvalidArchiveFormat),
+        };
+
+        if cursor.position() as usize != payload.len() {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        Ok(Self {
+            version,
+            cipher,
+            compressor,
+            key_mode,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use std::num::{NonZeroU8, NonZeroU64};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::models::{ArchiveFile, ArchiveFolder, ArchiveLogin, ArchiveManifest, ArchiveSecret};
+use crate::features::secrets::{TOTP, TotpAlgorithm};
+
+// Version 1 archives predate the TOTP hash algorithm, every code they carry uses SHA-1.
+#[derive(Deserialize)]
+pub struct V1ArchiveTotp {
+    pub secret: String,
+    pub period: NonZeroU64,
+    pub digits: NonZeroU8,
+}
+
+#[derive(Deserialize)]
+pub struct V1ArchiveLogin {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub username: String,
+    pub password: String,
+    pub website: String,
+    pub comments: String,
+    pub totp: Option<V1ArchiveTotp>,
+}
+
+#[derive(Deserialize)]
+pub struct V1ArchiveManifest {
+    pub created_at: DateTime<Utc>,
+    pub folders: Vec<ArchiveFolder>,
+    pub files: Vec<ArchiveFile>,
+    pub secret_folders: Vec<ArchiveFolder>,
+    pub logins: Vec<V1ArchiveLogin>,
+}
+
+// Version 2 archives only carry logins, other secret kinds were left out on export.
+#[derive(Deserialize)]
+pub struct V2ArchiveManifest {
+    pub created_at: DateTime<Utc>,
+    pub folders: Vec<ArchiveFolder>,
+    pub files: Vec<ArchiveFile>,
+    pub secret_folders: Vec<ArchiveFolder>,
+    pub logins: Vec<ArchiveLogin>,
+}
+
+impl From<V1ArchiveTotp> for TOTP {
+    fn from(legacy: V1ArchiveTotp) -> Self {
+        Self {
+            secret: legacy.secret,
+            period: legacy.period,
+            digits: legacy.digits,
+            algorithm: TotpAlgorithm::Sha1,
+        }
+    }
+}
+
+impl From<V1ArchiveLogin> for ArchiveLogin {
+    fn from(legacy: V1ArchiveLogin) -> Self {
+        Self {
+            folder_id: legacy.folder_id,
+            name: legacy.name,
+            username: legacy.username,
+            password: legacy.password,
+            website: legacy.website,
+            comments: legacy.comments,
+            totp: legacy.totp.map(Into::into),
+        }
+    }
+}
+
+impl From<V1ArchiveManifest> for ArchiveManifest {
+    fn from(legacy: V1ArchiveManifest) -> Self {
+        Self {
+            created_at: legacy.created_at,
+            folders: legacy.folders,
+            files: legacy.files,
+            secret_folders: legacy.secret_folders,
+            secrets: legacy
+                .logins
+                .into_iter()
+                .map(|login| ArchiveSecret::Login(login.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<V2ArchiveManifest> for ArchiveManifest {
+    fn from(legacy: V2ArchiveManifest) -> Self {
+        Self {
+            created_at: legacy.created_at,
+            folders: legacy.folders,
+            files: legacy.files,
+            secret_folders: legacy.secret_folders,
+            secrets: legacy
+                .logins
+                .into_iter()
+                .map(ArchiveSecret::Login)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+mod crypto;
+mod header;
+mod legacy;
+mod models;
+mod reader;
+mod writer;
+
+pub use crypto::{ArchiveCredential, ArchiveRecipient};
+pub use header::{ARCHIVE_VERSION, ArchiveHeader, ArchiveKeyMode};
+pub use models::{
+    ArchiveApiKey, ArchiveCustom, ArchiveFile, ArchiveFolder, ArchiveIdentity, ArchiveLogin,
+    ArchiveManifest, ArchivePaymentCard, ArchiveSecret, ArchiveSecureNote, ArchiveSshKey,
+    ArchiveWifiNetwork,
+};
+pub use reader::{ArchiveContent, ArchiveReader};
+pub use writer::ArchiveWriter;
+
+pub const ARCHIVE_EXTENSION: &str = "ova";
@@ -0,0 +1,313 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use zeroize::Zeroize;
+
+use crate::features::secrets::{CustomField, TOTP, WifiSecurity};
+
+// Entries whose parent is `None` belong directly under the folder chosen on import.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveManifest {
+    pub created_at: DateTime<Utc>,
+    pub folders: Vec<ArchiveFolder>,
+    pub files: Vec<ArchiveFile>,
+    pub secret_folders: Vec<ArchiveFolder>,
+    pub secrets: Vec<ArchiveSecret>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveFolder {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveFile {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub extension: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ArchiveSecret {
+    Login(ArchiveLogin),
+    ApiKey(ArchiveApiKey),
+    SecureNote(ArchiveSecureNote),
+    PaymentCard(ArchivePaymentCard),
+    SshKey(ArchiveSshKey),
+    Identity(ArchiveIdentity),
+    WifiNetwork(ArchiveWifiNetwork),
+    Custom(ArchiveCustom),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveLogin {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub username: String,
+    pub password: String,
+    pub website: String,
+    pub comments: String,
+    pub totp: Option<TOTP>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveApiKey {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub api_key: String,
+    pub website: String,
+    pub comments: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveSecureNote {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchivePaymentCard {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub cardholder_name: String,
+    pub number: String,
+    pub expiry: String,
+    pub security_code: String,
+    pub comments: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveSshKey {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub private_key: String,
+    pub public_key: String,
+    pub passphrase: String,
+    pub comments: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveIdentity {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub full_name: String,
+    pub email: String,
+    pub phone: String,
+    pub address: String,
+    pub document_number: String,
+    pub comments: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveWifiNetwork {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub ssid: String,
+    pub password: String,
+    pub security: WifiSecurity,
+    pub comments: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveCustom {
+    pub folder_id: Option<Uuid>,
+    pub name: String,
+    pub fields: Vec<CustomField>,
+    pub comments: String,
+}
+
+impl ArchiveManifest {
+    pub fn new() -> Self {
+        Self {
+            created_at: Utc::now(),
+            folders: Vec::new(),
+            files: Vec::new(),
+            secret_folders: Vec::new(),
+            secrets: Vec::new(),
+        }
+    }
+}
+
+impl Zeroize for ArchiveManifest {
+    fn zeroize(&mut self) {
+        self.secrets.zeroize();
+    }
+}
+
+impl Drop for ArchiveManifest {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ArchiveSecret {
+    pub fn folder_id(&self) -> Option<Uuid> {
+        match self {
+            Self::Login(secret) => secret.folder_id,
+            Self::ApiKey(secret) => secret.folder_id,
+            Self::SecureNote(secret) => secret.folder_id,
+            Self::PaymentCard(secret) => secret.folder_id,
+            Self::SshKey(secret) => secret.folder_id,
+            Self::Identity(secret) => secret.folder_id,
+            Self::WifiNetwork(secret) => secret.folder_id,
+            Self::Custom(secret) => secret.folder_id,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Login(secret) => &secret.name,
+            Self::ApiKey(secret) => &secret.name,
+            Self::SecureNote(secret) => &secret.name,
+            Self::PaymentCard(secret) => &secret.name,
+            Self::SshKey(secret) => &secret.name,
+            Self::Identity(secret) => &secret.name,
+            Self::WifiNetwork(secret) => &secret.name,
+            Self::Custom(secret) => &secret.name,
+        }
+    }
+}
+
+impl Zeroize for ArchiveSecret {
+    fn zeroize(&mut self) {
+        match self {
+            Self::Login(secret) => secret.zeroize(),
+            Self::ApiKey(secret) => secret.zeroize(),
+            Self::SecureNote(secret) => secret.zeroize(),
+            Self::PaymentCard(secret) => secret.zeroize(),
+            Self::SshKey(secret) => secret.zeroize(),
+            Self::Identity(secret) => secret.zeroize(),
+            Self::WifiNetwork(secret) => secret.zeroize(),
+            Self::Custom(secret) => secret.zeroize(),
+        }
+    }
+}
+
+impl Zeroize for ArchiveLogin {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.username.zeroize();
+        self.password.zeroize();
+        self.website.zeroize();
+        self.comments.zeroize();
+        self.totp.zeroize();
+    }
+}
+
+impl Drop for ArchiveLogin {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchiveApiKey {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.api_key.zeroize();
+        self.website.zeroize();
+        self.comments.zeroize();
+    }
+}
+
+impl Drop for ArchiveApiKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchiveSecureNote {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.content.zeroize();
+    }
+}
+
+impl Drop for ArchiveSecureNote {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchivePaymentCard {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.cardholder_name.zeroize();
+        self.number.zeroize();
+        self.expiry.zeroize();
+        self.security_code.zeroize();
+        self.comments.zeroize();
+    }
+}
+
+impl Drop for ArchivePaymentCard {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchiveSshKey {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.private_key.zeroize();
+        self.public_key.zeroize();
+        self.passphrase.zeroize();
+        self.comments.zeroize();
+    }
+}
+
+impl Drop for ArchiveSshKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchiveIdentity {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.full_name.zeroize();
+        self.email.zeroize();
+        self.phone.zeroize();
+        self.address.zeroize();
+        self.document_number.zeroize();
+        self.comments.zeroize();
+    }
+}
+
+impl Drop for ArchiveIdentity {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchiveWifiNetwork {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.ssid.zeroize();
+        self.password.zeroize();
+        self.comments.zeroize();
+    }
+}
+
+impl Drop for ArchiveWifiNetwork {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for ArchiveCustom {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.fields.zeroize();
+        self.comments.zeroize();
+    }
+}
+
+impl Drop for ArchiveCustom {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -0,0 +1,115 @@
+use std::io::{self, Read};
+
+use zeroize::Zeroize;
+
+use super::crypto::{ArchiveCredential, FrameCipher, FrameDomain};
+use super::header::{ARCHIVE_VERSION, ArchiveHeader};
+use super::legacy::{V1ArchiveManifest, V2ArchiveManifest};
+use super::models::ArchiveManifest;
+use crate::errors::{Error, Result};
+
+pub struct ArchiveReader<'a> {
+    reader: &'a mut dyn Read,
+    cipher: FrameCipher,
+}
+
+impl<'a> ArchiveReader<'a> {
+    pub fn open(
+        reader: &'a mut dyn Read,
+        credential: &ArchiveCredential,
+    ) -> Result<(Self, ArchiveManifest)> {
+        let (header, header_bytes) = ArchiveHeader::read_from(reader)?;
+        let key = credential.open_key(&header)?;
+
+        let mut cipher = FrameCipher::new(&header, header_bytes, key);
+
+        let mut manifest_bytes = cipher
+            .open(reader, FrameDomain::Manifest)
+            .map_err(|_| Error::ArchiveUnlockFailed)?;
+        let manifest = match header.version {
+            ARCHIVE_VERSION => postcard::from_bytes(&manifest_bytes),
+            2 => postcard::from_bytes::<V2ArchiveManifest>(&manifest_bytes).map(Into::into),
+            1 => postcard::from_bytes::<V1ArchiveManifest>(&manifest_bytes).map(Into::into),
+            version => return Err(Error::UnsupportedArchiveVersion(version)),
+        };
+        manifest_bytes.zeroize();
+
+        let manifest = manifest.map_err(|_| Error::InvalidArchiveFormat)?;
+
+        Ok((Self { reader, cipher }, manifest))
+    }
+
+    pub fn content(&mut self, size_bytes: u64) -> ArchiveContent<'_, 'a> {
+        ArchiveContent {
+            archive: self,
+            remaining: size_bytes,
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    pub fn finish(mut self) -> Result {
+        let end = self.cipher.open(self.reader, FrameDomain::End)?;
+        if !end.is_empty() {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        let mut trailing = [0u8; 1];
+        if self.reader.read(&mut trailing)? != 0 {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ArchiveContent<'r, 'a> {
+    archive: &'r mut ArchiveReader<'a>,
+    remaining: u64,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl ArchiveContent<'_, '_> {
+    fn next_chunk(&mut self) -> Result {
+        self.buffer.zeroize();
+
+        let chunk = self
+            .archive
+            .cipher
+            .open(self.archive.reader, FrameDomain::Chunk)?;
+        if chunk.is_empty() || chunk.len() as u64 > self.remaining {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        self.remaining -= chunk.len() as u64;
+        self.buffer = chunk;
+        self.position = 0;
+
+        Ok(())
+    }
+}
+
+impl Read for ArchiveContent<'_, '_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.buffer.len() {
+            if self.remaining == 0 {
+                return Ok(0);
+            }
+
+            self.next_chunk().map_err(io::Error::other)?;
+        }
+
+        let n = buf.len().min(self.buffer.len() - self.position);
+        buf[..n].copy_from_slice(&self.buffer[self.position..self.position + n]);
+        self.position += n;
+
+        Ok(n)
+    }
+}
+
+impl Drop for ArchiveContent<'_, '_> {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
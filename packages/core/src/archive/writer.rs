@@ -0,0 +1,78 @@
+use std::io::{Read, Write};
+
+use openvault_crypto::compression::CompressionAlgorithm;
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use zeroize::{Zeroize, Zeroizing};
+
+use super::crypto::{ARCHIVE_CHUNK_SIZE, ArchiveRecipient, FrameCipher, FrameDomain};
+use super::header::ArchiveHeader;
+use super::models::ArchiveManifest;
+use crate::errors::{Error, Result};
+
+pub struct ArchiveWriter<'a> {
+    writer: &'a mut dyn Write,
+    cipher: FrameCipher,
+}
+
+impl<'a> ArchiveWriter<'a> {
+    pub fn create(
+        writer: &'a mut dyn Write,
+        recipient: &ArchiveRecipient,
+        compressor: CompressionAlgorithm,
+        cipher: EncryptionAlgorithm,
+        manifest: &ArchiveManifest,
+    ) -> Result<Self> {
+        let (key_mode, key) = recipient.seal_key()?;
+
+        let header = ArchiveHeader::new(cipher, compressor, key_mode);
+        let header_bytes = header.to_bytes()?;
+        writer.write_all(&header_bytes)?;
+
+        let mut archive = Self {
+            writer,
+            cipher: FrameCipher::new(&header, header_bytes, key),
+        };
+
+        let manifest_bytes = Zeroizing::new(
+            postcard::to_allocvec(manifest).map_err(|_| Error::InvalidArchiveFormat)?,
+        );
+        archive
+            .cipher
+            .seal(archive.writer, FrameDomain::Manifest, &manifest_bytes)?;
+
+        Ok(archive)
+    }
+
+    pub fn write_content(&mut self, source: &mut dyn Read, size_bytes: u64) -> Result {
+        let mut written = 0u64;
+
+        loop {
+            let mut buffer = Vec::with_capacity(ARCHIVE_CHUNK_SIZE);
+
+            let n = source
+                .take(ARCHIVE_CHUNK_SIZE as u64)
+                .read_to_end(&mut buffer)? as u64;
+
+            if n == 0 {
+                break;
+            }
+
+            self.cipher.seal(self.writer, FrameDomain::Chunk, &buffer)?;
+            buffer.zeroize();
+
+            written += n;
+        }
+
+        if written != size_bytes {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result {
+        self.cipher.seal(self.writer, FrameDomain::End, &[])?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
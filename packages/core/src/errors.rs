@@ -5,6 +5,9 @@ pub enum Error {
     #[error(transparent)]
     Crypto(#[from] openvault_crypto::errors::Error),
 
+    #[error(transparent)]
+    Chunks(#[from] crate::features::chunks::ChunksError),
+
     #[error(transparent)]
     Filesystem(#[from] crate::features::filesystem::FilesystemError),
 
@@ -43,6 +46,42 @@ pub enum Error {
 
     #[error("Unable to unlock vault. Verify password and selected algorithms")]
     UnlockFailed,
+
+    #[error("All key slots are in use")]
+    KeySlotsFull,
+
+    #[error("Key slot not found: {0}")]
+    KeySlotNotFound(usize),
+
+    #[error("Cannot revoke the last key slot")]
+    CannotRevokeLastKeySlot,
+
+    #[error("No credential provided for key slot: {0}")]
+    KeySlotCredentialMissing(usize),
+
+    #[error("Vault format does not support key slots")]
+    KeySlotsUnsupported,
+
+    #[error("Vault format does not support custom key derivation parameters")]
+    KdfParamsUnsupported,
+
+    #[error("Key derivation parameters are weaker than the current ones")]
+    KdfParamsDowngrade,
+
+    #[error("Vault history is not available for the requested point in time")]
+    HistoryUnavailable,
+
+    #[error("Vault frame at offset {0} is damaged. Run verify before opening the vault")]
+    DamagedFrame(u64),
+
+    #[error("Invalid archive format")]
+    InvalidArchiveFormat,
+
+    #[error("Unsupported archive version: {0}")]
+    UnsupportedArchiveVersion(u16),
+
+    #[error("Unable to unlock archive. Verify the password or key")]
+    ArchiveUnlockFailed,
 }
 
 pub type Result<T = ()> = std::result::Result<T, Error>;
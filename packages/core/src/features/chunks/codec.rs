@@ -0,0 +1,37 @@
+use super::ChunksSnapshot;
+use super::error::{ChunksError, Result};
+use super::events::ChunksChange;
+use crate::features::shared::FeatureCodec;
+
+pub const CHUNKS_WIRE_VERSION: u16 = 1;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ChunksCodec;
+
+impl FeatureCodec for ChunksCodec {
+    type Error = ChunksError;
+    type DomainChange = ChunksChange;
+    type DomainSnapshot = ChunksSnapshot;
+
+    fn wire_version() -> u16 {
+        CHUNKS_WIRE_VERSION
+    }
+
+    fn encode_change(change: Self::DomainChange) -> Result<Vec<u8>> {
+        let payload = postcard::to_allocvec(&change)
+            .map_err(|e| ChunksError::InvalidPayload(e.to_string()))?;
+
+        Ok(payload)
+    }
+
+    fn decode_change(wire_version: u16, payload: &[u8]) -> Result<Self::DomainChange> {
+        if wire_version != CHUNKS_WIRE_VERSION {
+            return Err(ChunksError::UnsupportedWireVersion(wire_version));
+        }
+
+        let decoded: Self::DomainChange = postcard::from_bytes(payload)
+            .map_err(|e| ChunksError::InvalidPayload(e.to_string()))?;
+
+        Ok(decoded)
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ChunksError {
+    #[error("Unsupported chunks wire version: {0}")]
+    UnsupportedWireVersion(u16),
+
+    #[error("Invalid chunks payload: {0}")]
+    InvalidPayload(String),
+
+    #[error("Invalid snapshot")]
+    InvalidSnapshot,
+}
+
+pub type Result<T = ()> = std::result::Result<T, ChunksError>;
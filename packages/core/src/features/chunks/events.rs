@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::error::ChunksError;
+use super::models::{ChunkEntry, ChunkHash};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct ChunksSnapshot {
+    pub chunks: HashMap<ChunkHash, ChunkEntry>,
+}
+
+impl ChunksSnapshot {
+    pub fn new(chunks: HashMap<ChunkHash, ChunkEntry>) -> Self {
+        Self { chunks }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChunksDelta {
+    Retained {
+        hash: ChunkHash,
+        offset: u64,
+        size: u32,
+    },
+    Released(ChunkHash),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChunksChange {
+    Snapshot(ChunksSnapshot),
+    Deltas(Vec<ChunksDelta>),
+}
+
+impl From<ChunksSnapshot> for ChunksChange {
+    fn from(value: ChunksSnapshot) -> Self {
+        Self::Snapshot(value)
+    }
+}
+
+impl TryFrom<ChunksChange> for ChunksSnapshot {
+    type Error = ChunksError;
+
+    fn try_from(value: ChunksChange) -> Result<Self, Self::Error> {
+        match value {
+            ChunksChange::Snapshot(snapshot) => Ok(snapshot),
+            ChunksChange::Deltas(_) => Err(ChunksError::InvalidSnapshot),
+        }
+    }
+}
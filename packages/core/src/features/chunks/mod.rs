@@ -0,0 +1,12 @@
+pub mod store;
+
+mod codec;
+mod error;
+mod events;
+mod models;
+
+pub use codec::{CHUNKS_WIRE_VERSION, ChunksCodec};
+pub use error::{ChunksError, Result};
+pub use events::{ChunksChange, ChunksDelta, ChunksSnapshot};
+pub use models::{CHUNK_HASH_SIZE, ChunkEntry, ChunkHash};
+pub use store::ChunksStore;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+pub const CHUNK_HASH_SIZE: usize = 32;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ChunkHash(pub [u8; CHUNK_HASH_SIZE]);
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChunkEntry {
+    pub offset: u64,
+    pub size: u32,
+    pub refs: u32,
+}
+
+impl ChunkEntry {
+    pub fn new(offset: u64, size: u32) -> Self {
+        Self {
+            offset,
+            size,
+            refs: 1,
+        }
+    }
+}
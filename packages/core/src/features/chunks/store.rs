@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use zeroize::Zeroize;
+
+use crate::features::shared::DEFAULT_SNAPSHOT_THRESHOLD;
+
+use super::events::{ChunksChange, ChunksDelta, ChunksSnapshot};
+use super::models::{ChunkEntry, ChunkHash};
+
+#[derive(Clone, Debug, Default)]
+pub struct ChunksStore {
+    chunks: HashMap<ChunkHash, ChunkEntry>,
+    deltas: Vec<ChunksDelta>,
+    released_bytes: u64,
+}
+
+impl ChunksStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn restore(snapshot: ChunksSnapshot, deltas: Vec<ChunksDelta>) -> Self {
+        let mut store = Self {
+            chunks: snapshot.chunks,
+            deltas: Vec::new(),
+            released_bytes: 0,
+        };
+
+        for delta in &deltas {
+            store.apply_delta(delta);
+        }
+
+        store
+    }
+
+    pub fn find(&self, hash: &ChunkHash) -> Option<&ChunkEntry> {
+        self.chunks.get(hash)
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn retain(&mut self, hash: ChunkHash, offset: u64, size: u32) {
+        self.commit_delta(ChunksDelta::Retained { hash, offset, size });
+    }
+
+    pub fn release(&mut self, hash: ChunkHash) {
+        self.commit_delta(ChunksDelta::Released(hash));
+    }
+
+    // Space freed by released blobs, recorded as reclaimable once the release is committed.
+    pub fn add_released_bytes(&mut self, bytes: u64) {
+        self.released_bytes += bytes;
+    }
+
+    pub fn released_bytes(&self) -> u64 {
+        self.released_bytes
+    }
+
+    pub fn snapshot(&self) -> ChunksSnapshot {
+        ChunksSnapshot::new(self.chunks.clone())
+    }
+
+    pub fn pending_changes(&self) -> Option<ChunksChange> {
+        if self.deltas.is_empty() {
+            return None;
+        }
+
+        if self.deltas.len() >= DEFAULT_SNAPSHOT_THRESHOLD {
+            return Some(ChunksChange::Snapshot(self.snapshot()));
+        }
+
+        Some(ChunksChange::Deltas(self.deltas.clone()))
+    }
+
+    pub fn clear_deltas(&mut self) {
+        self.deltas.clear();
+        self.released_bytes = 0;
+    }
+
+    fn commit_delta(&mut self, delta: ChunksDelta) {
+        self.apply_delta(&delta);
+        self.deltas.push(delta);
+    }
+
+    fn apply_delta(&mut self, delta: &ChunksDelta) {
+        match delta {
+            ChunksDelta::Retained { hash, offset, size } => {
+                self.chunks
+                    .entry(*hash)
+                    .and_modify(|entry| entry.refs += 1)
+                    .or_insert_with(|| ChunkEntry::new(*offset, *size));
+            }
+            ChunksDelta::Released(hash) => {
+                if let Some(entry) = self.chunks.get_mut(hash) {
+                    entry.refs = entry.refs.saturating_sub(1);
+
+                    if entry.refs == 0 {
+                        self.chunks.remove(hash);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Zeroize for ChunksStore {
+    fn zeroize(&mut self) {
+        for (mut hash, _) in self.chunks.drain() {
+            hash.0.zeroize();
+        }
+        self.deltas.clear();
+        self.released_bytes = 0;
+    }
+}
@@ -1,9 +1,10 @@
 use super::FilesystemSnapshot;
 use super::errors::{FilesystemError, Result};
 use super::events::FilesystemChange;
+use super::legacy::{V1FilesystemChange, V2FilesystemChange};
 use crate::features::shared::FeatureCodec;
 
-pub const FILESYSTEM_WIRE_VERSION: u16 = 1;
+pub const FILESYSTEM_WIRE_VERSION: u16 = 3;
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct FilesystemCodec;
@@ -25,13 +26,13 @@ impl FeatureCodec for FilesystemCodec {
     }
 
     fn decode_change(wire_version: u16, payload: &[u8]) -> Result<Self::DomainChange> {
-        if wire_version != FILESYSTEM_WIRE_VERSION {
-            return Err(FilesystemError::UnsupportedWireVersion(wire_version));
-        }
-
-        let decoded: Self::DomainChange = postcard::from_bytes(payload)
-            .map_err(|e| FilesystemError::InvalidPayload(e.to_string()))?;
-
-        Ok(decoded)
+        let decoded = match wire_version {
+            FILESYSTEM_WIRE_VERSION => postcard::from_bytes(payload),
+            2 => postcard::from_bytes::<V2FilesystemChange>(payload).map(Into::into),
+            1 => postcard::from_bytes::<V1FilesystemChange>(payload).map(Into::into),
+            _ => return Err(FilesystemError::UnsupportedWireVersion(wire_version)),
+        };
+
+        decoded.map_err(|e| FilesystemError::InvalidPayload(e.to_string()))
     }
 }
@@ -15,6 +15,15 @@ pub enum FilesystemError {
     #[error("File not found: {0}")]
     FileNotFound(Uuid),
 
+    #[error("File revision not found: {0}")]
+    RevisionNotFound(Uuid),
+
+    #[error("Trash item not found: {0}")]
+    TrashItemNotFound(Uuid),
+
+    #[error("Trash item {0} holds no folder")]
+    EmptyTrashItem(Uuid),
+
     #[error("Parent folder not found: {0}")]
     ParentFolderNotFound(Uuid),
 
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use zeroize::Zeroize;
@@ -8,16 +9,26 @@ use crate::features::filesystem::FilesystemError;
 
 use super::models::{FileMetadata, FolderMetadata};
 use super::patch::{FileMetadataPatch, FolderMetadataPatch};
+use super::trash::FilesystemTrashEntry;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct FilesystemSnapshot {
     pub folders: HashMap<Uuid, FolderMetadata>,
     pub files: HashMap<Uuid, FileMetadata>,
+    pub trash: HashMap<Uuid, FilesystemTrashEntry>,
 }
 
 impl FilesystemSnapshot {
-    pub fn new(folders: HashMap<Uuid, FolderMetadata>, files: HashMap<Uuid, FileMetadata>) -> Self {
-        Self { folders, files }
+    pub fn new(
+        folders: HashMap<Uuid, FolderMetadata>,
+        files: HashMap<Uuid, FileMetadata>,
+        trash: HashMap<Uuid, FilesystemTrashEntry>,
+    ) -> Self {
+        Self {
+            folders,
+            files,
+            trash,
+        }
     }
 }
 
@@ -35,6 +46,24 @@ pub enum FilesystemDelta {
         id: Uuid,
         patch: FileMetadataPatch,
     },
+    FileRevisionsPruned {
+        id: Uuid,
+        revision_ids: Vec<Uuid>,
+    },
+    FileTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    FolderTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    TrashRestored {
+        id: Uuid,
+        parent_id: Uuid,
+        name: String,
+    },
+    TrashPurged(Vec<Uuid>),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -67,6 +96,7 @@ impl Zeroize for FilesystemDelta {
             FilesystemDelta::FileAdded(file) => file.zeroize(),
             FilesystemDelta::FolderUpdated { patch, .. } => patch.zeroize(),
             FilesystemDelta::FileUpdated { patch, .. } => patch.zeroize(),
+            FilesystemDelta::TrashRestored { name, .. } => name.zeroize(),
             _ => {}
         }
     }
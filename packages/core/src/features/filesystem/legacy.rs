@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::events::{FilesystemChange, FilesystemDelta, FilesystemSnapshot};
+use super::models::{FileMetadata, FolderMetadata};
+use super::patch::{FileMetadataPatch, FolderMetadataPatch};
+use crate::features::shared::BlobRef;
+
+#[derive(Debug, Deserialize)]
+pub struct V2FilesystemSnapshot {
+    pub folders: HashMap<Uuid, FolderMetadata>,
+    pub files: HashMap<Uuid, FileMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V2FilesystemChange {
+    Snapshot(V2FilesystemSnapshot),
+    Deltas(Vec<FilesystemDelta>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V1FileMetadata {
+    pub id: Uuid,
+    pub parent_id: Uuid,
+    pub name: String,
+    pub extension: String,
+    pub blob: BlobRef,
+    pub is_favourite: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V1FilesystemSnapshot {
+    pub folders: HashMap<Uuid, FolderMetadata>,
+    pub files: HashMap<Uuid, V1FileMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V1FilesystemDelta {
+    FolderAdded(FolderMetadata),
+    FolderDeleted(Uuid),
+    FolderUpdated {
+        id: Uuid,
+        patch: FolderMetadataPatch,
+    },
+    FileAdded(V1FileMetadata),
+    FileDeleted(Uuid),
+    FileUpdated {
+        id: Uuid,
+        patch: FileMetadataPatch,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V1FilesystemChange {
+    Snapshot(V1FilesystemSnapshot),
+    Deltas(Vec<V1FilesystemDelta>),
+}
+
+impl From<V1FileMetadata> for FileMetadata {
+    fn from(legacy: V1FileMetadata) -> Self {
+        Self {
+            id: legacy.id,
+            parent_id: legacy.parent_id,
+            name: legacy.name,
+            extension: legacy.extension,
+            blob: legacy.blob,
+            revisions: Vec::new(),
+            is_favourite: legacy.is_favourite,
+            created_at: legacy.created_at,
+            updated_at: legacy.updated_at,
+        }
+    }
+}
+
+impl From<V1FilesystemSnapshot> for FilesystemSnapshot {
+    fn from(legacy: V1FilesystemSnapshot) -> Self {
+        let files = legacy
+            .files
+            .into_iter()
+            .map(|(id, file)| (id, file.into()))
+            .collect();
+
+        Self::new(legacy.folders, files, HashMap::new())
+    }
+}
+
+impl From<V1FilesystemDelta> for FilesystemDelta {
+    fn from(legacy: V1FilesystemDelta) -> Self {
+        match legacy {
+            V1FilesystemDelta::FolderAdded(folder) => Self::FolderAdded(folder),
+            V1FilesystemDelta::FolderDeleted(id) => Self::FolderDeleted(id),
+            V1FilesystemDelta::FolderUpdated { id, patch } => Self::FolderUpdated { id, patch },
+            V1FilesystemDelta::FileAdded(file) => Self::FileAdded(file.into()),
+            V1FilesystemDelta::FileDeleted(id) => Self::FileDeleted(id),
+            V1FilesystemDelta::FileUpdated { id, patch } => Self::FileUpdated { id, patch },
+        }
+    }
+}
+
+impl From<V1FilesystemChange> for FilesystemChange {
+    fn from(legacy: V1FilesystemChange) -> Self {
+        match legacy {
+            V1FilesystemChange::Snapshot(snapshot) => Self::Snapshot(snapshot.into()),
+            V1FilesystemChange::Deltas(deltas) => {
+                Self::Deltas(deltas.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+impl From<V2FilesystemChange> for FilesystemChange {
+    fn from(legacy: V2FilesystemChange) -> Self {
+        match legacy {
+            V2FilesystemChange::Snapshot(snapshot) => Self::Snapshot(FilesystemSnapshot::new(
+                snapshot.folders,
+                snapshot.files,
+                HashMap::new(),
+            )),
+            V2FilesystemChange::Deltas(deltas) => Self::Deltas(deltas),
+        }
+    }
+}
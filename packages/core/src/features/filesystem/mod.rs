@@ -4,9 +4,11 @@ mod codec;
 mod errors;
 mod events;
 mod index;
+mod legacy;
 mod models;
 mod namings;
 mod patch;
+mod trash;
 mod validate;
 
 #[cfg(test)]
@@ -15,8 +17,11 @@ mod tests;
 pub use codec::{FILESYSTEM_WIRE_VERSION, FilesystemCodec};
 pub use errors::{FilesystemError, Result};
 pub use events::{FilesystemChange, FilesystemDelta, FilesystemSnapshot};
-pub use models::{FILESYSTEM_ROOT_FOLDER_ID, FileMetadata, FolderMetadata};
+pub use models::{
+    FILESYSTEM_ROOT_FOLDER_ID, FileMetadata, FileRevision, FolderMetadata, RevisionRetention,
+};
 pub use patch::{FileMetadataPatch, FolderMetadataPatch};
 pub use store::FilesystemStore;
+pub use trash::{FilesystemTrashEntry, FilesystemTrashItem};
 
 pub use crate::internal::scanner::{ScannedFolder, scan_directory};
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
@@ -62,6 +62,7 @@ pub struct FileMetadata {
     #[validate(length(max = 10))]
     pub extension: String,
     pub blob: BlobRef,
+    pub revisions: Vec<FileRevision>,
     pub is_favourite: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -81,6 +82,7 @@ impl FileMetadata {
             name: name.into(),
             extension: extension.into(),
             blob,
+            revisions: Vec::new(),
             is_favourite: false,
             created_at: now,
             updated_at: now,
@@ -90,6 +92,68 @@ impl FileMetadata {
     pub fn size_bytes(&self) -> u64 {
         self.blob.size_bytes
     }
+
+    pub fn revision(&self, id: &Uuid) -> Option<&FileRevision> {
+        self.revisions.iter().find(|revision| revision.id() == *id)
+    }
+
+    pub fn blobs(&self) -> impl Iterator<Item = &BlobRef> {
+        std::iter::once(&self.blob).chain(self.revisions.iter().map(|revision| &revision.blob))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileRevision {
+    pub blob: BlobRef,
+    pub replaced_at: DateTime<Utc>,
+}
+
+impl FileRevision {
+    pub fn new(blob: BlobRef, replaced_at: DateTime<Utc>) -> Self {
+        Self { blob, replaced_at }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.blob.id
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        self.blob.size_bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RevisionRetention {
+    pub max_count: Option<usize>,
+    pub max_age: Option<TimeDelta>,
+}
+
+impl RevisionRetention {
+    pub fn with_max_count(mut self, max_count: usize) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    pub fn with_max_age(mut self, max_age: TimeDelta) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn expired(&self, revisions: &[FileRevision], now: DateTime<Utc>) -> Vec<Uuid> {
+        let excess = self
+            .max_count
+            .map_or(0, |max_count| revisions.len().saturating_sub(max_count));
+        let cutoff = self.max_age.map(|max_age| now - max_age);
+
+        revisions
+            .iter()
+            .enumerate()
+            .filter(|(index, revision)| {
+                *index < excess || cutoff.is_some_and(|cutoff| revision.replaced_at < cutoff)
+            })
+            .map(|(_, revision)| revision.id())
+            .collect()
+    }
 }
 
 impl Zeroize for FolderMetadata {
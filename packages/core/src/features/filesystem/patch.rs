@@ -49,6 +49,13 @@ impl FileMetadataPatch {
             ..Default::default()
         }
     }
+
+    pub fn set_blob(blob: BlobRef) -> Self {
+        Self {
+            blob: Some(blob),
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, TimeDelta, Utc};
 use uuid::Uuid;
 use validator::Validate;
 use zeroize::Zeroize;
@@ -7,17 +8,19 @@ use zeroize::Zeroize;
 use super::errors::{FilesystemError, Result};
 use super::events::{FilesystemChange, FilesystemDelta, FilesystemSnapshot};
 use super::index::FilesystemIndex;
-use super::models::{FileMetadata, FolderMetadata};
+use super::models::{FileMetadata, FileRevision, FolderMetadata, RevisionRetention};
 use super::patch::{FileMetadataPatch, FolderMetadataPatch};
+use super::trash::{FilesystemTrashEntry, FilesystemTrashItem};
 use super::validate;
 use crate::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
 use crate::features::filesystem::namings::{generate_file_name, generate_folder_name};
-use crate::features::shared::{BlobRef, DEFAULT_SNAPSHOT_THRESHOLD};
+use crate::features::shared::{BlobRef, DEFAULT_SNAPSHOT_THRESHOLD, TrashEntry};
 
 #[derive(Clone, Debug)]
 pub struct FilesystemStore {
     pub(crate) folders: HashMap<Uuid, FolderMetadata>,
     pub(crate) files: HashMap<Uuid, FileMetadata>,
+    pub(crate) trash: HashMap<Uuid, FilesystemTrashEntry>,
     pub(crate) index: FilesystemIndex,
     pub(crate) deltas: Vec<FilesystemDelta>,
 }
@@ -37,6 +40,7 @@ impl FilesystemStore {
         Self {
             folders,
             files: HashMap::new(),
+            trash: HashMap::new(),
             index: FilesystemIndex::new(),
             deltas: Vec::new(),
         }
@@ -48,6 +52,7 @@ impl FilesystemStore {
         let mut store = Self {
             folders: snapshot.folders,
             files: snapshot.files,
+            trash: snapshot.trash,
             index,
             deltas: Vec::new(),
         };
@@ -71,6 +76,16 @@ impl FilesystemStore {
         self.files.get(id)
     }
 
+    pub fn trashed(&self, id: &Uuid) -> Option<&FilesystemTrashEntry> {
+        self.trash.get(id)
+    }
+
+    pub fn trash(&self) -> Vec<FilesystemTrashEntry> {
+        let mut entries: Vec<_> = self.trash.values().cloned().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.deleted_at));
+        entries
+    }
+
     pub fn folders(&self, parent_id: Uuid) -> Vec<FolderMetadata> {
         let folder_ids = self.index.folders(&parent_id);
 
@@ -156,6 +171,66 @@ impl FilesystemStore {
         self.commit_delta(&FilesystemDelta::FolderDeleted(id))
     }
 
+    pub fn trash_file(&mut self, id: Uuid) -> Result {
+        let deleted_at = Utc::now();
+        self.commit_delta(&FilesystemDelta::FileTrashed { id, deleted_at })
+    }
+
+    pub fn trash_folder(&mut self, id: Uuid) -> Result {
+        let deleted_at = Utc::now();
+        self.commit_delta(&FilesystemDelta::FolderTrashed { id, deleted_at })
+    }
+
+    pub fn restore_from_trash(&mut self, id: Uuid) -> Result {
+        let entry = self
+            .trash
+            .get(&id)
+            .ok_or(FilesystemError::TrashItemNotFound(id))?;
+
+        let parent_id = if self.folders.contains_key(&entry.original_parent_id) {
+            entry.original_parent_id
+        } else {
+            FILESYSTEM_ROOT_FOLDER_ID
+        };
+
+        let name = match &entry.item {
+            FilesystemTrashItem::File(file) => {
+                generate_file_name(&self.files, &self.folders, parent_id, &file.name)?
+            }
+            FilesystemTrashItem::Folder { folders, .. } => {
+                let root = folders.first().ok_or(FilesystemError::EmptyTrashItem(id))?;
+                generate_folder_name(&self.folders, parent_id, &root.name)?
+            }
+        };
+
+        self.commit_delta(&FilesystemDelta::TrashRestored {
+            id,
+            parent_id,
+            name,
+        })
+    }
+
+    pub fn purge_from_trash(&mut self, id: Uuid) -> Result<Vec<BlobRef>> {
+        self.purge_trash_entries(vec![id])
+    }
+
+    pub fn empty_trash(&mut self) -> Result<Vec<BlobRef>> {
+        let ids = self.trash.keys().copied().collect();
+        self.purge_trash_entries(ids)
+    }
+
+    pub fn purge_expired_trash(&mut self, retention: TimeDelta) -> Result<Vec<BlobRef>> {
+        let now = Utc::now();
+        let ids = self
+            .trash
+            .values()
+            .filter(|entry| entry.is_expired(retention, now))
+            .map(|entry| entry.id)
+            .collect();
+
+        self.purge_trash_entries(ids)
+    }
+
     pub fn rename_file(&mut self, id: Uuid, new_name: String) -> Result {
         let patch = FileMetadataPatch::rename(new_name);
         self.commit_delta(&FilesystemDelta::FileUpdated { id, patch })
@@ -191,8 +266,47 @@ impl FilesystemStore {
         self.commit_delta(&FilesystemDelta::FileUpdated { id, patch })
     }
 
+    pub fn replace_file_blob(&mut self, id: Uuid, blob: BlobRef) -> Result {
+        let patch = FileMetadataPatch::set_blob(blob);
+        self.commit_delta(&FilesystemDelta::FileUpdated { id, patch })
+    }
+
+    pub fn restore_file_revision(&mut self, id: Uuid, revision_id: Uuid) -> Result {
+        let revision = self
+            .file(&id)
+            .ok_or(FilesystemError::FileNotFound(id))?
+            .revision(&revision_id)
+            .ok_or(FilesystemError::RevisionNotFound(revision_id))?;
+
+        self.replace_file_blob(id, revision.blob.clone())
+    }
+
+    pub fn prune_file_revisions(
+        &mut self,
+        id: Uuid,
+        retention: RevisionRetention,
+    ) -> Result<Vec<BlobRef>> {
+        let file = self.file(&id).ok_or(FilesystemError::FileNotFound(id))?;
+
+        let revision_ids = retention.expired(&file.revisions, Utc::now());
+        if revision_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let pruned = file
+            .revisions
+            .iter()
+            .filter(|revision| revision_ids.contains(&revision.id()))
+            .map(|revision| revision.blob.clone())
+            .collect();
+
+        self.commit_delta(&FilesystemDelta::FileRevisionsPruned { id, revision_ids })?;
+
+        Ok(pruned)
+    }
+
     pub fn snapshot(&self) -> FilesystemSnapshot {
-        FilesystemSnapshot::new(self.folders.clone(), self.files.clone())
+        FilesystemSnapshot::new(self.folders.clone(), self.files.clone(), self.trash.clone())
     }
 
     pub fn pending_changes(&self) -> Option<FilesystemChange> {
@@ -211,6 +325,52 @@ impl FilesystemStore {
         self.deltas.clear();
     }
 
+    fn purge_trash_entries(&mut self, ids: Vec<Uuid>) -> Result<Vec<BlobRef>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut blobs = Vec::new();
+        for id in &ids {
+            let entry = self
+                .trash
+                .get(id)
+                .ok_or(FilesystemError::TrashItemNotFound(*id))?;
+            blobs.extend(entry.item.blobs().cloned());
+        }
+
+        self.commit_delta(&FilesystemDelta::TrashPurged(ids))?;
+
+        Ok(blobs)
+    }
+
+    fn collect_subtree(
+        &self,
+        id: Uuid,
+        folders: &mut Vec<FolderMetadata>,
+        files: &mut Vec<FileMetadata>,
+    ) -> Result {
+        let folder = self
+            .folders
+            .get(&id)
+            .ok_or(FilesystemError::FolderNotFound(id))?;
+        folders.push(folder.clone());
+
+        files.extend(
+            self.index
+                .files(&id)
+                .iter()
+                .filter_map(|file_id| self.files.get(file_id))
+                .cloned(),
+        );
+
+        for subfolder_id in self.index.folders(&id) {
+            self.collect_subtree(*subfolder_id, folders, files)?;
+        }
+
+        Ok(())
+    }
+
     fn commit_delta(&mut self, delta: &FilesystemDelta) -> Result {
         self.apply_delta(delta, true)
     }
@@ -227,6 +387,21 @@ impl FilesystemStore {
             FilesystemDelta::FileAdded(file) => self.apply_file_added(file.clone()),
             FilesystemDelta::FileDeleted(id) => self.apply_file_deleted(*id),
             FilesystemDelta::FileUpdated { id, patch } => self.apply_file_updated(*id, patch),
+            FilesystemDelta::FileRevisionsPruned { id, revision_ids } => {
+                self.apply_file_revisions_pruned(*id, revision_ids)
+            }
+            FilesystemDelta::FileTrashed { id, deleted_at } => {
+                self.apply_file_trashed(*id, *deleted_at)
+            }
+            FilesystemDelta::FolderTrashed { id, deleted_at } => {
+                self.apply_folder_trashed(*id, *deleted_at)
+            }
+            FilesystemDelta::TrashRestored {
+                id,
+                parent_id,
+                name,
+            } => self.apply_trash_restored(*id, *parent_id, name),
+            FilesystemDelta::TrashPurged(ids) => self.apply_trash_purged(ids),
         }?;
 
         if track_delta {
@@ -417,8 +592,14 @@ impl FilesystemStore {
             file.extension = extension.clone();
         }
 
-        if let Some(blob) = &patch.blob {
-            file.blob = blob.clone();
+        if let Some(blob) = &patch.blob
+            && *blob != file.blob
+        {
+            let previous = std::mem::replace(&mut file.blob, blob.clone());
+
+            file.revisions.retain(|revision| revision.blob != *blob);
+            file.revisions
+                .push(FileRevision::new(previous, patch.updated_at));
         }
 
         if patch.parent_id.is_some() {
@@ -427,6 +608,138 @@ impl FilesystemStore {
 
         Ok(())
     }
+
+    fn apply_file_revisions_pruned(&mut self, id: Uuid, revision_ids: &[Uuid]) -> Result {
+        let file = self
+            .files
+            .get_mut(&id)
+            .ok_or(FilesystemError::FileNotFound(id))?;
+
+        file.revisions
+            .retain(|revision| !revision_ids.contains(&revision.id()));
+
+        Ok(())
+    }
+
+    fn apply_file_trashed(&mut self, id: Uuid, deleted_at: DateTime<Utc>) -> Result {
+        let file = self
+            .files
+            .get(&id)
+            .cloned()
+            .ok_or(FilesystemError::FileNotFound(id))?;
+
+        self.apply_file_deleted(id)?;
+
+        let entry = TrashEntry::new(
+            id,
+            file.parent_id,
+            deleted_at,
+            FilesystemTrashItem::File(file),
+        );
+        self.trash.insert(id, entry);
+
+        Ok(())
+    }
+
+    fn apply_folder_trashed(&mut self, id: Uuid, deleted_at: DateTime<Utc>) -> Result {
+        if id == FILESYSTEM_ROOT_FOLDER_ID {
+            return Err(FilesystemError::CannotDeleteRootFolder);
+        }
+
+        let mut folders = Vec::new();
+        let mut files = Vec::new();
+        self.collect_subtree(id, &mut folders, &mut files)?;
+
+        let parent_id = folders[0]
+            .parent_id
+            .ok_or(FilesystemError::FolderMissingParent(id))?;
+
+        self.apply_folder_deleted(id)?;
+
+        let item = FilesystemTrashItem::Folder { folders, files };
+        self.trash
+            .insert(id, TrashEntry::new(id, parent_id, deleted_at, item));
+
+        Ok(())
+    }
+
+    fn apply_trash_restored(&mut self, id: Uuid, parent_id: Uuid, name: &str) -> Result {
+        let mut item = self
+            .trash
+            .get(&id)
+            .ok_or(FilesystemError::TrashItemNotFound(id))?
+            .item
+            .clone();
+
+        match &mut item {
+            FilesystemTrashItem::File(file) => {
+                file.parent_id = parent_id;
+                file.name = name.to_string();
+            }
+            FilesystemTrashItem::Folder { folders, .. } => {
+                let root = folders
+                    .first_mut()
+                    .ok_or(FilesystemError::EmptyTrashItem(id))?;
+                root.parent_id = Some(parent_id);
+                root.name = name.to_string();
+            }
+        }
+
+        // The trash entry is only dropped once every item is back in place.
+        self.restore_trash_item(item)?;
+        self.trash.remove(&id);
+
+        Ok(())
+    }
+
+    fn restore_trash_item(&mut self, item: FilesystemTrashItem) -> Result {
+        let (folders, files) = match item {
+            FilesystemTrashItem::File(file) => return self.apply_file_added(file),
+            FilesystemTrashItem::Folder { folders, files } => (folders, files),
+        };
+
+        let mut restored_folders = Vec::with_capacity(folders.len());
+        let mut restored_files = Vec::with_capacity(files.len());
+
+        let result = folders
+            .into_iter()
+            .try_for_each(|folder| {
+                let id = folder.id;
+                self.apply_folder_added(folder)?;
+                restored_folders.push(id);
+                Ok(())
+            })
+            .and_then(|()| {
+                files.into_iter().try_for_each(|file| {
+                    let id = file.id;
+                    self.apply_file_added(file)?;
+                    restored_files.push(id);
+                    Ok(())
+                })
+            });
+
+        if result.is_err() {
+            // Undo in reverse so every folder is empty by the time it is removed.
+            for id in restored_files.into_iter().rev() {
+                let _ = self.apply_file_deleted(id);
+            }
+            for id in restored_folders.into_iter().rev() {
+                let _ = self.apply_folder_deleted(id);
+            }
+        }
+
+        result
+    }
+
+    fn apply_trash_purged(&mut self, ids: &[Uuid]) -> Result {
+        for id in ids {
+            self.trash
+                .remove(id)
+                .ok_or(FilesystemError::TrashItemNotFound(*id))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Zeroize for FilesystemStore {
@@ -441,6 +754,11 @@ impl Zeroize for FilesystemStore {
         }
         self.files.clear();
 
+        for entry in self.trash.values_mut() {
+            entry.item.zeroize();
+        }
+        self.trash.clear();
+
         for delta in &mut self.deltas {
             delta.zeroize();
         }
@@ -1,2 +1,6 @@
 #[cfg(test)]
+mod revisions;
+#[cfg(test)]
+mod trash;
+#[cfg(test)]
 mod validate;
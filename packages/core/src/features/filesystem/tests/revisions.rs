@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::features::filesystem::codec::FilesystemCodec;
+use crate::features::filesystem::errors::FilesystemError;
+use crate::features::filesystem::models::{
+    FILESYSTEM_ROOT_FOLDER_ID, FileRevision, FolderMetadata, RevisionRetention,
+};
+use crate::features::filesystem::store::FilesystemStore;
+use crate::features::shared::{BlobRef, FeatureCodec};
+
+fn blob(size_bytes: u64) -> BlobRef {
+    BlobRef::new(Uuid::new_v4(), size_bytes, size_bytes * 10)
+}
+
+fn store_with_file() -> (FilesystemStore, Uuid) {
+    let mut store = FilesystemStore::new();
+    let id = store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "notes.txt".to_string(),
+            "txt".to_string(),
+            blob(1),
+        )
+        .unwrap();
+
+    (store, id)
+}
+
+#[test]
+fn test_replace_blob_keeps_previous_revision() {
+    let (mut store, id) = store_with_file();
+    let original = store.file(&id).unwrap().blob.clone();
+
+    let updated = blob(2);
+    store.replace_file_blob(id, updated.clone()).unwrap();
+
+    let file = store.file(&id).unwrap();
+    assert_eq!(file.blob, updated);
+    assert_eq!(file.revisions.len(), 1);
+    assert_eq!(file.revisions[0].blob, original);
+}
+
+#[test]
+fn test_restore_revision_swaps_current_blob() {
+    let (mut store, id) = store_with_file();
+    let original = store.file(&id).unwrap().blob.clone();
+    let updated = blob(2);
+    store.replace_file_blob(id, updated.clone()).unwrap();
+
+    store.restore_file_revision(id, original.id).unwrap();
+
+    let file = store.file(&id).unwrap();
+    assert_eq!(file.blob, original);
+    assert_eq!(file.revisions.len(), 1);
+    assert_eq!(file.revisions[0].blob, updated);
+
+    match store.restore_file_revision(id, Uuid::new_v4()) {
+        Err(FilesystemError::RevisionNotFound(_)) => {}
+        res => panic!("Expected RevisionNotFound error, got {:?}", res),
+    }
+}
+
+#[test]
+fn test_prune_revisions_by_count() {
+    let (mut store, id) = store_with_file();
+    for size in 2..=4 {
+        store.replace_file_blob(id, blob(size)).unwrap();
+    }
+
+    let pruned = store
+        .prune_file_revisions(id, RevisionRetention::default().with_max_count(1))
+        .unwrap();
+
+    let file = store.file(&id).unwrap();
+    assert_eq!(
+        pruned.iter().map(|b| b.size_bytes).collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(file.revisions.len(), 1);
+    assert_eq!(file.revisions[0].size_bytes(), 3);
+
+    let replayed =
+        FilesystemStore::restore(FilesystemStore::new().snapshot(), store.deltas.clone()).unwrap();
+    assert_eq!(replayed.file(&id), store.file(&id));
+}
+
+#[test]
+fn test_retention_expires_old_revisions() {
+    let now = Utc::now();
+    let revisions = vec![
+        FileRevision::new(blob(1), now - TimeDelta::days(40)),
+        FileRevision::new(blob(2), now - TimeDelta::days(1)),
+    ];
+
+    let expired = RevisionRetention::default()
+        .with_max_age(TimeDelta::days(30))
+        .expired(&revisions, now);
+
+    assert_eq!(expired, vec![revisions[0].id()]);
+    assert!(
+        RevisionRetention::default()
+            .expired(&revisions, now)
+            .is_empty()
+    );
+}
+
+#[derive(Serialize)]
+struct V1File {
+    id: Uuid,
+    parent_id: Uuid,
+    name: String,
+    extension: String,
+    blob: BlobRef,
+    is_favourite: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct V1Snapshot {
+    folders: HashMap<Uuid, FolderMetadata>,
+    files: HashMap<Uuid, V1File>,
+}
+
+#[derive(Serialize)]
+enum V1Change {
+    Snapshot(V1Snapshot),
+}
+
+#[test]
+fn test_decode_legacy_snapshot() {
+    let root = FolderMetadata::root();
+    let file = V1File {
+        id: Uuid::new_v4(),
+        parent_id: root.id,
+        name: "legacy.txt".to_string(),
+        extension: "txt".to_string(),
+        blob: blob(7),
+        is_favourite: false,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    let file_id = file.id;
+
+    let payload = postcard::to_allocvec(&V1Change::Snapshot(V1Snapshot {
+        folders: HashMap::from([(root.id, root)]),
+        files: HashMap::from([(file_id, file)]),
+    }))
+    .unwrap();
+
+    let snapshot = FilesystemCodec::decode_snapshot(1, &payload).unwrap();
+    let file = &snapshot.files[&file_id];
+
+    assert_eq!(file.name, "legacy.txt");
+    assert!(file.revisions.is_empty());
+}
@@ -0,0 +1,177 @@
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+
+use crate::features::filesystem::errors::FilesystemError;
+use crate::features::filesystem::events::FilesystemDelta;
+use crate::features::filesystem::models::FILESYSTEM_ROOT_FOLDER_ID;
+use crate::features::filesystem::store::FilesystemStore;
+use crate::features::filesystem::trash::FilesystemTrashItem;
+use crate::features::shared::{BlobRef, TrashEntry};
+
+fn blob() -> BlobRef {
+    BlobRef::new(Uuid::new_v4(), 4, 0)
+}
+
+fn add_file(store: &mut FilesystemStore, parent_id: Uuid, name: &str) -> Uuid {
+    store
+        .add_file(parent_id, name.to_string(), "txt".to_string(), blob())
+        .unwrap()
+}
+
+#[test]
+fn test_trash_folder_keeps_subtree() {
+    let mut store = FilesystemStore::new();
+    let folder_id = store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "photos".to_string())
+        .unwrap();
+    let nested_id = store.add_folder(folder_id, "2024".to_string()).unwrap();
+    let file_id = add_file(&mut store, nested_id, "beach.txt");
+
+    store.trash_folder(folder_id).unwrap();
+
+    assert!(store.folder(&folder_id).is_none());
+    assert!(store.file(&file_id).is_none());
+
+    let entry = store.trashed(&folder_id).unwrap();
+    assert_eq!(entry.original_parent_id, FILESYSTEM_ROOT_FOLDER_ID);
+    assert_eq!(entry.item.files().len(), 1);
+
+    store.restore_from_trash(folder_id).unwrap();
+
+    assert!(store.trashed(&folder_id).is_none());
+    assert_eq!(store.file(&file_id).unwrap().parent_id, nested_id);
+    assert_eq!(store.folder(&nested_id).unwrap().parent_id, Some(folder_id));
+}
+
+#[test]
+fn test_restore_renames_on_conflict() {
+    let mut store = FilesystemStore::new();
+    let file_id = add_file(&mut store, FILESYSTEM_ROOT_FOLDER_ID, "notes.txt");
+
+    store.trash_file(file_id).unwrap();
+    add_file(&mut store, FILESYSTEM_ROOT_FOLDER_ID, "notes.txt");
+
+    store.restore_from_trash(file_id).unwrap();
+
+    assert_eq!(store.file(&file_id).unwrap().name, "notes (1).txt");
+}
+
+#[test]
+fn test_restore_falls_back_to_root_when_parent_is_gone() {
+    let mut store = FilesystemStore::new();
+    let folder_id = store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "docs".to_string())
+        .unwrap();
+    let file_id = add_file(&mut store, folder_id, "report.txt");
+
+    store.trash_file(file_id).unwrap();
+    store.remove_folder(folder_id).unwrap();
+    store.restore_from_trash(file_id).unwrap();
+
+    assert_eq!(
+        store.file(&file_id).unwrap().parent_id,
+        FILESYSTEM_ROOT_FOLDER_ID
+    );
+}
+
+#[test]
+fn test_purge_returns_blobs_and_replays() {
+    let mut store = FilesystemStore::new();
+    let kept_id = add_file(&mut store, FILESYSTEM_ROOT_FOLDER_ID, "kept.txt");
+    let purged_id = add_file(&mut store, FILESYSTEM_ROOT_FOLDER_ID, "purged.txt");
+    let purged_blob = store.file(&purged_id).unwrap().blob.clone();
+
+    store.trash_file(kept_id).unwrap();
+    store.trash_file(purged_id).unwrap();
+
+    assert!(
+        store
+            .purge_expired_trash(TimeDelta::days(30))
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        store.purge_from_trash(purged_id).unwrap(),
+        vec![purged_blob]
+    );
+
+    match store.purge_from_trash(purged_id) {
+        Err(FilesystemError::TrashItemNotFound(id)) => assert_eq!(id, purged_id),
+        res => panic!("Expected TrashItemNotFound error, got {:?}", res),
+    }
+
+    let replayed =
+        FilesystemStore::restore(FilesystemStore::new().snapshot(), store.deltas.clone()).unwrap();
+    let trash = replayed.trash();
+
+    assert_eq!(trash.len(), 1);
+    assert!(matches!(&trash[0].item, FilesystemTrashItem::File(file) if file.id == kept_id));
+}
+
+#[test]
+fn test_restore_rejects_empty_folder_item() {
+    let folder_id = Uuid::new_v4();
+    let mut snapshot = FilesystemStore::new().snapshot();
+    snapshot.trash.insert(
+        folder_id,
+        TrashEntry::new(
+            folder_id,
+            FILESYSTEM_ROOT_FOLDER_ID,
+            Utc::now(),
+            FilesystemTrashItem::Folder {
+                folders: Vec::new(),
+                files: Vec::new(),
+            },
+        ),
+    );
+
+    let mut store = FilesystemStore::restore(snapshot.clone(), Vec::new()).unwrap();
+    match store.restore_from_trash(folder_id) {
+        Err(FilesystemError::EmptyTrashItem(id)) => assert_eq!(id, folder_id),
+        res => panic!("Expected EmptyTrashItem error, got {:?}", res),
+    }
+    assert!(store.trashed(&folder_id).is_some());
+
+    let restored = FilesystemStore::restore(
+        snapshot,
+        vec![FilesystemDelta::TrashRestored {
+            id: folder_id,
+            parent_id: FILESYSTEM_ROOT_FOLDER_ID,
+            name: "photos".to_string(),
+        }],
+    );
+    assert!(matches!(restored, Err(FilesystemError::EmptyTrashItem(id)) if id == folder_id));
+}
+
+#[test]
+fn test_failed_restore_keeps_trash_entry_and_store_unchanged() {
+    let mut store = FilesystemStore::new();
+    let folder_id = store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "photos".to_string())
+        .unwrap();
+    let nested_id = store.add_folder(folder_id, "2024".to_string()).unwrap();
+    let file_id = add_file(&mut store, nested_id, "beach.txt");
+    let mut clash = store.file(&file_id).unwrap().clone();
+
+    store.trash_folder(folder_id).unwrap();
+
+    // A live file reusing the trashed file's id makes the last re-add fail.
+    clash.parent_id = FILESYSTEM_ROOT_FOLDER_ID;
+    let mut snapshot = store.snapshot();
+    snapshot.files.insert(file_id, clash);
+    let mut store = FilesystemStore::restore(snapshot, Vec::new()).unwrap();
+
+    match store.restore_from_trash(folder_id) {
+        Err(FilesystemError::DuplicateId(id)) => assert_eq!(id, file_id),
+        res => panic!("Expected DuplicateId error, got {:?}", res),
+    }
+
+    assert!(store.folder(&folder_id).is_none());
+    assert!(store.folder(&nested_id).is_none());
+    assert_eq!(
+        store.file(&file_id).unwrap().parent_id,
+        FILESYSTEM_ROOT_FOLDER_ID
+    );
+    assert_eq!(store.trashed(&folder_id).unwrap().item.files().len(), 1);
+    assert!(store.folders(FILESYSTEM_ROOT_FOLDER_ID).is_empty());
+}
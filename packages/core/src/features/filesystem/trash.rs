@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use super::models::{FileMetadata, FolderMetadata};
+use crate::features::shared::{BlobRef, TrashEntry};
+
+pub type FilesystemTrashEntry = TrashEntry<FilesystemTrashItem>;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FilesystemTrashItem {
+    File(FileMetadata),
+    Folder {
+        folders: Vec<FolderMetadata>,
+        files: Vec<FileMetadata>,
+    },
+}
+
+impl FilesystemTrashItem {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::File(file) => Some(&file.name),
+            Self::Folder { folders, .. } => folders.first().map(|folder| folder.name.as_str()),
+        }
+    }
+
+    pub fn files(&self) -> &[FileMetadata] {
+        match self {
+            Self::File(file) => std::slice::from_ref(file),
+            Self::Folder { files, .. } => files,
+        }
+    }
+
+    pub fn files_mut(&mut self) -> &mut [FileMetadata] {
+        match self {
+            Self::File(file) => std::slice::from_mut(file),
+            Self::Folder { files, .. } => files,
+        }
+    }
+
+    pub fn blobs(&self) -> impl Iterator<Item = &BlobRef> {
+        self.files().iter().flat_map(FileMetadata::blobs)
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        self.files().iter().map(FileMetadata::size_bytes).sum()
+    }
+}
+
+impl Zeroize for FilesystemTrashItem {
+    fn zeroize(&mut self) {
+        match self {
+            Self::File(file) => file.zeroize(),
+            Self::Folder { folders, files } => {
+                folders.iter_mut().for_each(Zeroize::zeroize);
+                files.iter_mut().for_each(Zeroize::zeroize);
+            }
+        }
+    }
+}
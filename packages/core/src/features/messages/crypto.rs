@@ -1,5 +1,11 @@
+use std::io::{Read, Write};
+
 use openvault_crypto::keys::{EphemeralPublicKey, SigningPublicKey};
-use openvault_crypto::protocols::messaging::{sign_then_encrypt, verify_then_decrypt};
+use openvault_crypto::protocols::messaging::{
+    MessageStreamReader, SignedMessage, open_signed_message, sign_then_encrypt,
+    sign_then_encrypt_bytes, sign_then_encrypt_multi, sign_then_encrypt_stream,
+    verify_then_decrypt, verify_then_decrypt_bytes,
+};
 
 use super::error::Result;
 use super::models::MessageCredentials;
@@ -20,3 +26,87 @@ pub fn open_message(
     let ephemeral_private_key = &recipient.ephemeral_keys.private;
     verify_then_decrypt(ciphertext, ephemeral_private_key, sender_public_key).map_err(From::from)
 }
+
+pub fn seal_message_bytes(
+    plaintext: &[u8],
+    sender: &MessageCredentials,
+    recipient_public_key: &EphemeralPublicKey,
+) -> Result<Vec<u8>> {
+    sign_then_encrypt_bytes(plaintext, &sender.signing_keys, recipient_public_key)
+        .map_err(From::from)
+}
+
+pub fn seal_message_multi(
+    plaintext: &[u8],
+    sender: &MessageCredentials,
+    recipient_public_keys: &[EphemeralPublicKey],
+) -> Result<Vec<u8>> {
+    sign_then_encrypt_multi(plaintext, &sender.signing_keys, recipient_public_keys)
+        .map_err(From::from)
+}
+
+pub fn open_message_bytes(
+    ciphertext: &[u8],
+    recipient: &MessageCredentials,
+    sender_public_key: &SigningPublicKey,
+) -> Result<Vec<u8>> {
+    let ephemeral_private_key = &recipient.ephemeral_keys.private;
+    verify_then_decrypt_bytes(ciphertext, ephemeral_private_key, sender_public_key)
+        .map_err(From::from)
+}
+
+pub fn open_message_unverified(
+    ciphertext: &[u8],
+    recipient: &MessageCredentials,
+) -> Result<SignedMessage> {
+    open_signed_message(ciphertext, &recipient.ephemeral_keys.private).map_err(From::from)
+}
+
+pub fn seal_message_stream(
+    source: &mut dyn Read,
+    destination: &mut dyn Write,
+    sender: &MessageCredentials,
+    recipient_public_key: &EphemeralPublicKey,
+) -> Result<u64> {
+    sign_then_encrypt_stream(
+        source,
+        destination,
+        &sender.signing_keys,
+        recipient_public_key,
+    )
+    .map_err(From::from)
+}
+
+pub fn open_message_stream<'a>(
+    source: &'a mut dyn Read,
+    recipient: &MessageCredentials,
+    sender_public_key: &SigningPublicKey,
+) -> Result<IncomingMessageStream<'a>> {
+    let reader = MessageStreamReader::open(source, &recipient.ephemeral_keys.private)?;
+
+    Ok(IncomingMessageStream {
+        reader,
+        sender_public_key: sender_public_key.clone(),
+    })
+}
+
+/// Plaintext of a stream received from a contact. Content read from it must be
+/// discarded unless `finish` confirms the contact's signature.
+pub struct IncomingMessageStream<'a> {
+    reader: MessageStreamReader<'a>,
+    sender_public_key: SigningPublicKey,
+}
+
+impl IncomingMessageStream<'_> {
+    pub fn finish(self) -> Result<u64> {
+        self.reader
+            .finish(&self.sender_public_key)
+            .map_err(From::from)
+    }
+}
+
+impl Read for IncomingMessageStream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
@@ -8,9 +8,13 @@ mod models;
 mod patch;
 
 pub use codec::{MESSAGES_WIRE_VERSION, MessagesCodec};
-pub use crypto::{open_message, seal_message};
+pub use crypto::{
+    IncomingMessageStream, open_message, open_message_bytes, open_message_stream,
+    open_message_unverified, seal_message, seal_message_bytes, seal_message_multi,
+    seal_message_stream,
+};
 pub use error::{MessagesError, Result};
 pub use events::{MessagesChange, MessagesDelta, MessagesSnapshot};
-pub use models::{MessageContact, MessageCredentials, MessageCredentialsView};
+pub use models::{DecryptedMessage, MessageContact, MessageCredentials, MessageCredentialsView};
 pub use patch::MessageContactPatch;
 pub use store::MessagesStore;
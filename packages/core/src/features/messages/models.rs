@@ -7,6 +7,7 @@ use zeroize::Zeroize;
 use openvault_crypto::keys::{
     EphemeralKeyPair, EphemeralPublicKey, SigningKeyPair, SigningPublicKey,
 };
+use openvault_crypto::protocols::messaging::SenderFingerprint;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
 pub struct MessageCredentials {
@@ -30,6 +31,19 @@ pub struct MessageContact {
     pub created_at: DateTime<Utc>,
 }
 
+/// Outcome of decrypting a message without naming its sender up front. Plaintext is
+/// only returned once the signature matches a known contact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecryptedMessage {
+    Verified {
+        contact: MessageContact,
+        plaintext: Vec<u8>,
+    },
+    UnknownSender {
+        fingerprint: Option<SenderFingerprint>,
+    },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct MessageCredentialsView {
     pub name: String,
@@ -1,19 +1,25 @@
 use std::collections::HashMap;
+use std::io::{Read, Write};
 
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use zeroize::Zeroize;
 
 use openvault_crypto::keys::{EphemeralKeyPair, SigningKeyPair};
+use openvault_crypto::protocols::messaging::{armor, dearmor, sender_fingerprint};
 use validator::Validate;
 
 use crate::features::messages::MessageContactPatch;
 use crate::features::shared::DEFAULT_SNAPSHOT_THRESHOLD;
 
-use super::crypto::{open_message, seal_message};
+use super::crypto::{
+    IncomingMessageStream, open_message, open_message_bytes, open_message_stream,
+    open_message_unverified, seal_message, seal_message_bytes, seal_message_multi,
+    seal_message_stream,
+};
 use super::error::{MessagesError, Result};
 use super::events::{MessagesChange, MessagesDelta, MessagesSnapshot};
-use super::models::{MessageContact, MessageCredentials, MessageCredentialsView};
+use super::models::{DecryptedMessage, MessageContact, MessageCredentials, MessageCredentialsView};
 
 #[derive(Clone, Debug, Default)]
 pub struct MessagesStore {
@@ -118,6 +124,115 @@ impl MessagesStore {
         open_message(payload, credentials, &contact.signing_pub_key)
     }
 
+    pub fn encrypt_bytes_for_contact(&self, id: Uuid, payload: &[u8]) -> Result<Vec<u8>> {
+        let credentials = self.ensure_credentials()?;
+        let contact = self
+            .contacts
+            .get(&id)
+            .ok_or_else(|| MessagesError::NotFound(id.to_string()))?;
+
+        seal_message_bytes(payload, credentials, &contact.ephemeral_pub_key)
+    }
+
+    pub fn decrypt_bytes_from_contact(&self, id: Uuid, payload: &[u8]) -> Result<Vec<u8>> {
+        let credentials = self.ensure_credentials()?;
+        let contact = self
+            .contacts
+            .get(&id)
+            .ok_or_else(|| MessagesError::NotFound(id.to_string()))?;
+
+        open_message_bytes(payload, credentials, &contact.signing_pub_key)
+    }
+
+    pub fn encrypt_for_contacts(&self, ids: &[Uuid], payload: &[u8]) -> Result<String> {
+        let envelope = self.encrypt_bytes_for_contacts(ids, payload)?;
+        Ok(armor(&envelope))
+    }
+
+    pub fn encrypt_bytes_for_contacts(&self, ids: &[Uuid], payload: &[u8]) -> Result<Vec<u8>> {
+        let credentials = self.ensure_credentials()?;
+        if ids.is_empty() {
+            return Err(MessagesError::InvalidInput(
+                "At least one contact is required".to_string(),
+            ));
+        }
+
+        let recipients = ids
+            .iter()
+            .map(|id| {
+                self.contacts
+                    .get(id)
+                    .map(|contact| contact.ephemeral_pub_key.clone())
+                    .ok_or_else(|| MessagesError::NotFound(id.to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        seal_message_multi(payload, credentials, &recipients)
+    }
+
+    pub fn encrypt_stream_for_contact(
+        &self,
+        id: Uuid,
+        source: &mut dyn Read,
+        destination: &mut dyn Write,
+    ) -> Result<u64> {
+        let credentials = self.ensure_credentials()?;
+        let contact = self
+            .contacts
+            .get(&id)
+            .ok_or_else(|| MessagesError::NotFound(id.to_string()))?;
+
+        seal_message_stream(source, destination, credentials, &contact.ephemeral_pub_key)
+    }
+
+    pub fn open_stream_from_contact<'a>(
+        &self,
+        id: Uuid,
+        source: &'a mut dyn Read,
+    ) -> Result<IncomingMessageStream<'a>> {
+        let credentials = self.ensure_credentials()?;
+        let contact = self
+            .contacts
+            .get(&id)
+            .ok_or_else(|| MessagesError::NotFound(id.to_string()))?;
+
+        open_message_stream(source, credentials, &contact.signing_pub_key)
+    }
+
+    pub fn decrypt_auto(&self, payload: &[u8]) -> Result<DecryptedMessage> {
+        let envelope = dearmor(payload)?;
+        self.decrypt_bytes_auto(&envelope)
+    }
+
+    pub fn decrypt_bytes_auto(&self, payload: &[u8]) -> Result<DecryptedMessage> {
+        let credentials = self.ensure_credentials()?;
+        let message = open_message_unverified(payload, credentials)?;
+        let fingerprint = message.sender_fingerprint().copied();
+
+        // Legacy envelopes carry no fingerprint, so every contact's key is tried instead.
+        let contact = match fingerprint {
+            Some(fingerprint) => self
+                .contacts
+                .values()
+                .find(|contact| sender_fingerprint(&contact.signing_pub_key) == fingerprint),
+            None => self
+                .contacts
+                .values()
+                .find(|contact| message.is_signed_by(&contact.signing_pub_key)),
+        };
+
+        let Some(contact) = contact else {
+            return Ok(DecryptedMessage::UnknownSender { fingerprint });
+        };
+
+        let plaintext = message.verify(&contact.signing_pub_key)?;
+
+        Ok(DecryptedMessage::Verified {
+            contact: contact.clone(),
+            plaintext,
+        })
+    }
+
     pub fn encrypt_for_contact_name(&self, name: &str, payload: &[u8]) -> Result<String> {
         let contact = self.contact_by_name(name)?;
         self.encrypt_for_contact(contact.id, payload)
@@ -128,6 +243,16 @@ impl MessagesStore {
         self.decrypt_from_contact(contact.id, payload)
     }
 
+    pub fn encrypt_bytes_for_contact_name(&self, name: &str, payload: &[u8]) -> Result<Vec<u8>> {
+        let contact = self.contact_by_name(name)?;
+        self.encrypt_bytes_for_contact(contact.id, payload)
+    }
+
+    pub fn decrypt_bytes_from_contact_name(&self, name: &str, payload: &[u8]) -> Result<Vec<u8>> {
+        let contact = self.contact_by_name(name)?;
+        self.decrypt_bytes_from_contact(contact.id, payload)
+    }
+
     pub fn snapshot(&self) -> MessagesSnapshot {
         MessagesSnapshot::new(self.credentials.clone(), self.contacts.clone())
     }
@@ -1,3 +1,4 @@
+pub mod chunks;
 pub mod filesystem;
 pub mod messages;
 pub mod secrets;
@@ -8,7 +9,9 @@ use strum_macros::Display;
 
 use crate::errors::{Error, Result};
 use crate::operations::compact::{CompactionBundle, build_bundle_for};
-use crate::repositories::{FilesystemRepository, MessagesRepository, SecretsRepository};
+use crate::repositories::{
+    ChunksRepository, FilesystemRepository, MessagesRepository, SecretsRepository,
+};
 use crate::vault::runtime::VaultSession;
 
 #[repr(u16)]
@@ -17,6 +20,7 @@ pub enum FeatureType {
     Filesystem = 1,
     Messages = 2,
     Secrets = 3,
+    Chunks = 4,
 }
 
 impl FeatureType {
@@ -25,6 +29,7 @@ impl FeatureType {
             FeatureType::Filesystem => build_bundle_for::<FilesystemRepository>(session, *self),
             FeatureType::Messages => build_bundle_for::<MessagesRepository>(session, *self),
             FeatureType::Secrets => build_bundle_for::<SecretsRepository>(session, *self),
+            FeatureType::Chunks => build_bundle_for::<ChunksRepository>(session, *self),
         }
     }
 }
@@ -37,6 +42,7 @@ impl TryFrom<u16> for FeatureType {
             1 => Ok(Self::Filesystem),
             2 => Ok(Self::Messages),
             3 => Ok(Self::Secrets),
+            4 => Ok(Self::Chunks),
             _ => Err(Error::InvalidVaultFormat),
         }
     }
@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::hash::{HmacSha256, Sha1Hasher};
+use openvault_crypto::keys::derived_key::DerivedKey;
+use openvault_crypto::keys::salt::Salt;
+
+use super::error::{Result, SecretError};
+use super::models::{LoginEntry, LoginEntryView};
+
+const DEFAULT_STALE_DAYS: i64 = 365;
+const DEFAULT_MIN_ENTROPY_BITS: f64 = 60.0;
+const MIN_PATTERN_RUN: usize = 4;
+
+const KEYBOARD_ROWS: [&str; 4] = ["qwertyuiop", "asdfghjkl", "zxcvbnm", "azertyuiop"];
+const COMMON_PASSWORDS: [&str; 32] = [
+    "password",
+    "passw0rd",
+    "123456",
+    "12345678",
+    "qwerty",
+    "abc123",
+    "letmein",
+    "welcome",
+    "admin",
+    "administrator",
+    "iloveyou",
+    "monkey",
+    "dragon",
+    "master",
+    "sunshine",
+    "princess",
+    "football",
+    "baseball",
+    "shadow",
+    "superman",
+    "trustno1",
+    "starwars",
+    "whatever",
+    "freedom",
+    "secret",
+    "changeme",
+    "default",
+    "login",
+    "hello",
+    "charlie",
+    "michael",
+    "ninja",
+];
+
+#[derive(Clone, Debug)]
+pub struct AuditOptions {
+    pub stale_after: TimeDelta,
+    pub min_entropy_bits: f64,
+    pub breaches: Option<BreachIndex>,
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        Self {
+            stale_after: TimeDelta::days(DEFAULT_STALE_DAYS),
+            min_entropy_bits: DEFAULT_MIN_ENTROPY_BITS,
+            breaches: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditReport {
+    pub audited: usize,
+    pub findings: Vec<AuditFinding>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditFinding {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub issues: Vec<AuditIssue>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuditIssue {
+    ReusedPassword {
+        shared_with: Vec<Uuid>,
+    },
+    WeakPassword {
+        entropy_bits: f64,
+        patterns: Vec<WeakPattern>,
+    },
+    BreachedPassword {
+        occurrences: u64,
+    },
+    Stale {
+        days: i64,
+    },
+    MissingTotp,
+    InsecureWebsite,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WeakPattern {
+    CommonPassword,
+    RepeatedCharacters,
+    Sequence,
+    KeyboardWalk,
+    ContainsUsername,
+}
+
+// Local breach corpus in the Pwned Passwords formats, so no password or hash leaves the device.
+// Lines are either a full SHA-1 (`HASH[:COUNT]`) or a k-anonymity range: a five character prefix
+// line followed by the `SUFFIX[:COUNT]` lines the range API returned for it.
+#[derive(Clone, Debug, Default)]
+pub struct BreachIndex {
+    ranges: HashMap<String, HashMap<String, u64>>,
+}
+
+impl BreachIndex {
+    pub fn parse(reader: impl BufRead) -> Result<Self> {
+        let mut ranges: HashMap<String, HashMap<String, u64>> = HashMap::new();
+        let mut prefix: Option<String> = None;
+
+        for (number, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| SecretError::InvalidInput(e.to_string()))?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid =
+                || SecretError::InvalidInput(format!("Invalid breach line {}", number + 1));
+            let (hash, count) = match line.split_once(':') {
+                Some((hash, count)) => (hash, count.trim().parse().map_err(|_| invalid())?),
+                None => (line, 1),
+            };
+            if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(invalid());
+            }
+            let hash = hash.to_ascii_uppercase();
+
+            let (range, suffix) = match hash.len() {
+                5 => {
+                    prefix = Some(hash);
+                    continue;
+                }
+                35 => (prefix.clone().ok_or_else(invalid)?, hash),
+                40 => (hash[..5].to_string(), hash[5..].to_string()),
+                _ => return Err(invalid()),
+            };
+            ranges.entry(range).or_default().insert(suffix, count);
+        }
+
+        Ok(Self { ranges })
+    }
+
+    pub fn occurrences(&self, password: &str) -> Option<u64> {
+        let hash = Zeroizing::new(hex::encode_upper(Sha1Hasher::hash(password.as_bytes())));
+        let (prefix, suffix) = hash.split_at(5);
+
+        self.ranges.get(prefix)?.get(suffix).copied()
+    }
+}
+
+pub(crate) fn audit_logins<'a>(
+    logins: impl Iterator<Item = &'a LoginEntry>,
+    key: &DerivedKey,
+    cipher: EncryptionAlgorithm,
+    options: &AuditOptions,
+) -> Result<AuditReport> {
+    // Passwords are compared through a keyed digest that only lives for this audit.
+    let digest_key = Salt::random();
+    let now = Utc::now();
+
+    let mut findings = Vec::new();
+    let mut digests: HashMap<[u8; 32], Vec<Uuid>> = HashMap::new();
+
+    for login in logins {
+        let view = login.reveal(key, cipher)?;
+        let mut issues = password_issues(&view, options);
+
+        let digest = HmacSha256::mac(digest_key.as_slice(), view.password.as_bytes())
+            .map_err(|e| SecretError::CryptoError(e.to_string()))?;
+        digests.entry(digest).or_default().push(login.id);
+
+        if !view.website.is_empty() && !has_https_scheme(&view.website) {
+            issues.push(AuditIssue::InsecureWebsite);
+        }
+        drop(view);
+
+        if login.totp.is_none() {
+            issues.push(AuditIssue::MissingTotp);
+        }
+
+        let age = now - login.updated_at;
+        if age > options.stale_after {
+            issues.push(AuditIssue::Stale {
+                days: age.num_days(),
+            });
+        }
+
+        findings.push(AuditFinding {
+            id: login.id,
+            folder_id: login.folder_id,
+            name: login.name.clone(),
+            issues,
+        });
+    }
+
+    let audited = findings.len();
+    let reused: HashMap<Uuid, &Vec<Uuid>> = digests
+        .values()
+        .filter(|ids| ids.len() > 1)
+        .flat_map(|ids| ids.iter().map(move |id| (*id, ids)))
+        .collect();
+
+    for finding in &mut findings {
+        if let Some(ids) = reused.get(&finding.id) {
+            let shared_with = ids.iter().copied().filter(|id| *id != finding.id).collect();
+            finding
+                .issues
+                .insert(0, AuditIssue::ReusedPassword { shared_with });
+        }
+    }
+
+    findings.retain(|finding| !finding.issues.is_empty());
+    findings.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+    Ok(AuditReport { audited, findings })
+}
+
+fn password_issues(view: &LoginEntryView, options: &AuditOptions) -> Vec<AuditIssue> {
+    let mut issues = Vec::new();
+
+    let entropy_bits = estimate_entropy(&view.password);
+    let patterns = weak_patterns(&view.password, &view.username);
+    if entropy_bits < options.min_entropy_bits || !patterns.is_empty() {
+        issues.push(AuditIssue::WeakPassword {
+            entropy_bits,
+            patterns,
+        });
+    }
+
+    if let Some(occurrences) = options
+        .breaches
+        .as_ref()
+        .and_then(|breaches| breaches.occurrences(&view.password))
+    {
+        issues.push(AuditIssue::BreachedPassword { occurrences });
+    }
+
+    issues
+}
+
+// Brute-force estimate from the character classes in use, patterns are reported separately.
+fn estimate_entropy(password: &str) -> f64 {
+    let classes = [
+        (password.chars().any(|c| c.is_ascii_lowercase()), 26),
+        (password.chars().any(|c| c.is_ascii_uppercase()), 26),
+        (password.chars().any(|c| c.is_ascii_digit()), 10),
+        (
+            password
+                .chars()
+                .any(|c| c.is_ascii_punctuation() || c == ' '),
+            33,
+        ),
+        (!password.is_ascii(), 100),
+    ];
+    let pool: u32 = classes
+        .iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, size)| size)
+        .sum();
+
+    if pool == 0 {
+        return 0.0;
+    }
+
+    password.chars().count() as f64 * f64::from(pool).log2()
+}
+
+fn weak_patterns(password: &str, username: &str) -> Vec<WeakPattern> {
+    let lowered = Zeroizing::new(password.to_lowercase());
+    let chars = Zeroizing::new(lowered.chars().collect::<Vec<_>>());
+    let mut patterns = Vec::new();
+
+    let stem = lowered.trim_end_matches(|c: char| !c.is_ascii_alphabetic());
+    if COMMON_PASSWORDS.contains(&lowered.as_str()) || COMMON_PASSWORDS.contains(&stem) {
+        patterns.push(WeakPattern::CommonPassword);
+    }
+
+    if chars
+        .windows(3)
+        .any(|run| run[0] == run[1] && run[1] == run[2])
+    {
+        patterns.push(WeakPattern::RepeatedCharacters);
+    }
+
+    let is_sequence = |run: &[char], step: i32| {
+        run.windows(2)
+            .all(|pair| pair[1] as i32 - pair[0] as i32 == step)
+    };
+    if chars
+        .windows(MIN_PATTERN_RUN)
+        .any(|run| run[0].is_ascii_alphanumeric() && (is_sequence(run, 1) || is_sequence(run, -1)))
+    {
+        patterns.push(WeakPattern::Sequence);
+    }
+
+    let walks = KEYBOARD_ROWS.iter().flat_map(|row| {
+        (0..=row.len() - MIN_PATTERN_RUN).map(move |start| &row[start..start + MIN_PATTERN_RUN])
+    });
+    for walk in walks {
+        let reversed: String = walk.chars().rev().collect();
+        if lowered.contains(walk) || lowered.contains(&reversed) {
+            patterns.push(WeakPattern::KeyboardWalk);
+            break;
+        }
+    }
+
+    let username = Zeroizing::new(username.to_lowercase());
+    let local_part = username.split('@').next().unwrap_or_default();
+    if local_part.chars().count() >= 3 && lowered.contains(local_part) {
+        patterns.push(WeakPattern::ContainsUsername);
+    }
+
+    patterns
+}
+
+fn has_https_scheme(website: &str) -> bool {
+    website
+        .trim_start()
+        .get(..8)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("https://"))
+}
@@ -1,4 +1,5 @@
 use super::error::{Result, SecretError};
+use super::legacy::{V1SecretsChange, V2SecretsChange, V3SecretsChange, V4SecretsChange};
 use super::records::{SECRETS_WIRE_VERSION, SecretSnapshot, SecretsChange};
 use crate::features::shared::FeatureCodec;
 
@@ -24,13 +25,15 @@ impl FeatureCodec for SecretCodec {
     }
 
     fn decode_change(wire_version: u16, payload: &[u8]) -> Result<Self::DomainChange> {
-        if wire_version != SECRETS_WIRE_VERSION {
-            return Err(SecretError::UnsupportedWireVersion(wire_version));
-        }
-
-        let decoded: Self::DomainChange = postcard::from_bytes(payload)
-            .map_err(|e| SecretError::InvalidPayload(e.to_string()))?;
-
-        Ok(decoded)
+        let decoded = match wire_version {
+            SECRETS_WIRE_VERSION => postcard::from_bytes(payload),
+            4 => postcard::from_bytes::<V4SecretsChange>(payload).map(Into::into),
+            3 => postcard::from_bytes::<V3SecretsChange>(payload).map(Into::into),
+            2 => postcard::from_bytes::<V2SecretsChange>(payload).map(Into::into),
+            1 => postcard::from_bytes::<V1SecretsChange>(payload).map(Into::into),
+            _ => return Err(SecretError::UnsupportedWireVersion(wire_version)),
+        };
+
+        decoded.map_err(|e| SecretError::InvalidPayload(e.to_string()))
     }
 }
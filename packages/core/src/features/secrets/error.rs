@@ -1,6 +1,8 @@
 use thiserror::Error;
 use uuid::Uuid;
 
+use super::models::SecretKind;
+
 #[derive(Debug, Error)]
 pub enum SecretError {
     #[error(transparent)]
@@ -15,6 +17,18 @@ pub enum SecretError {
     #[error("Entry not found: {0}")]
     EntryNotFound(Uuid),
 
+    #[error("Entry {0} cannot be updated with a {1:?} patch")]
+    EntryKindMismatch(Uuid, SecretKind),
+
+    #[error("Entry {0} has no TOTP configured")]
+    TotpNotConfigured(Uuid),
+
+    #[error("Trash item not found: {0}")]
+    TrashItemNotFound(Uuid),
+
+    #[error("Trash item {0} holds no folder")]
+    EmptyTrashItem(Uuid),
+
     #[error("Parent folder not found: {0}")]
     ParentFolderNotFound(Uuid),
 
@@ -71,6 +85,9 @@ pub enum SecretError {
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+
+    #[error("Name exhausted under folder {parent_id} for entry {name}")]
+    NameExhausted { parent_id: Uuid, name: String },
 }
 
 impl SecretError {
@@ -80,6 +97,13 @@ impl SecretError {
             name: name.to_string(),
         }
     }
+
+    pub fn name_exhausted(parent_id: Uuid, name: &str) -> Self {
+        Self::NameExhausted {
+            parent_id,
+            name: name.to_string(),
+        }
+    }
 }
 
 pub type Result<T = ()> = std::result::Result<T, SecretError>;
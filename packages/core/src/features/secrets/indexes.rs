@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::error::{Result, SecretError};
-use super::models::{LoginEntry, SECRETS_ROOT_FOLDER_ID, SecretFolder};
+use super::models::{SECRETS_ROOT_FOLDER_ID, SecretEntry, SecretFolder};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum NameOwner {
@@ -24,7 +24,7 @@ impl SecretIndex {
 
     pub fn build(
         folders: &HashMap<Uuid, SecretFolder>,
-        entries: &HashMap<Uuid, LoginEntry>,
+        entries: &HashMap<Uuid, SecretEntry>,
     ) -> Result<Self> {
         let mut index = Self::new();
 
@@ -94,29 +94,26 @@ impl SecretIndex {
             .insert((parent_id, new_name.to_string()), NameOwner::Folder(id));
     }
 
-    pub fn track_entry(&mut self, entry: &LoginEntry) -> Result {
-        self.ensure_name_available(
-            entry.folder_id,
-            &entry.name,
-            Some(NameOwner::Entry(entry.id)),
-        )?;
+    pub fn track_entry(&mut self, entry: &SecretEntry) -> Result {
+        let (id, folder_id) = (entry.id(), entry.folder_id());
 
-        self.names.insert(
-            (entry.folder_id, entry.name.clone()),
-            NameOwner::Entry(entry.id),
-        );
+        self.ensure_name_available(folder_id, entry.name(), Some(NameOwner::Entry(id)))?;
+
+        self.names
+            .insert((folder_id, entry.name().to_string()), NameOwner::Entry(id));
 
         self.entries_by_parent
-            .entry(entry.folder_id)
+            .entry(folder_id)
             .or_default()
-            .push(entry.id);
+            .push(id);
 
         Ok(())
     }
 
-    pub fn untrack_entry(&mut self, entry: &LoginEntry) {
-        self.names.remove(&(entry.folder_id, entry.name.clone()));
-        Self::remove_child(&mut self.entries_by_parent, entry.folder_id, entry.id);
+    pub fn untrack_entry(&mut self, entry: &SecretEntry) {
+        self.names
+            .remove(&(entry.folder_id(), entry.name().to_string()));
+        Self::remove_child(&mut self.entries_by_parent, entry.folder_id(), entry.id());
     }
 
     pub fn ensure_entry_name_available(
@@ -0,0 +1,523 @@
+use std::collections::HashMap;
+use std::num::{NonZeroU8, NonZeroU64};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::models::{
+    ApiKeyEntry, CustomEntry, EncryptedTotp, IdentityEntry, LoginEntry, PaymentCardEntry,
+    SealedValue, SecretEntry, SecretFolder, SecureNoteEntry, SshKeyEntry, TotpAlgorithm,
+    WifiNetworkEntry,
+};
+use super::patch::{
+    ApiKeyEntryPatch, CustomEntryPatch, IdentityEntryPatch, LoginEntryPatch, PaymentCardEntryPatch,
+    SecretEntryPatch, SecretFolderPatch, SecureNoteEntryPatch, SshKeyEntryPatch,
+    WifiNetworkEntryPatch,
+};
+use super::records::{SecretDelta, SecretSnapshot, SecretsChange};
+use super::trash::{SecretTrashEntry, SecretTrashItem};
+use crate::features::shared::TrashEntry;
+
+// Versions 1 to 4 predate the TOTP hash algorithm, every stored code used SHA-1.
+#[derive(Debug, Deserialize)]
+pub struct V4EncryptedTotp {
+    pub secret: SealedValue,
+    pub period: NonZeroU64,
+    pub digits: NonZeroU8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V4LoginEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub username: SealedValue,
+    pub password: SealedValue,
+    pub website: SealedValue,
+    pub comments: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub totp: Option<V4EncryptedTotp>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V4LoginEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub username: Option<SealedValue>,
+    pub password: Option<SealedValue>,
+    pub website: Option<SealedValue>,
+    pub comments: Option<SealedValue>,
+    pub totp: Option<Option<V4EncryptedTotp>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V4SecretEntry {
+    Login(V4LoginEntry),
+    ApiKey(ApiKeyEntry),
+    SecureNote(SecureNoteEntry),
+    PaymentCard(PaymentCardEntry),
+    SshKey(SshKeyEntry),
+    Identity(IdentityEntry),
+    WifiNetwork(WifiNetworkEntry),
+    Custom(CustomEntry),
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V4SecretEntryPatch {
+    Login(V4LoginEntryPatch),
+    ApiKey(ApiKeyEntryPatch),
+    SecureNote(SecureNoteEntryPatch),
+    PaymentCard(PaymentCardEntryPatch),
+    SshKey(SshKeyEntryPatch),
+    Identity(IdentityEntryPatch),
+    WifiNetwork(WifiNetworkEntryPatch),
+    Custom(CustomEntryPatch),
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V4SecretDelta {
+    FolderAdded(SecretFolder),
+    FolderDeleted(Uuid),
+    FolderUpdated {
+        id: Uuid,
+        patch: SecretFolderPatch,
+    },
+    EntryAdded(V4SecretEntry),
+    EntryUpdated {
+        id: Uuid,
+        patch: V4SecretEntryPatch,
+    },
+    EntryDeleted(Uuid),
+    EntryTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    FolderTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    TrashRestored {
+        id: Uuid,
+        parent_id: Uuid,
+        name: String,
+    },
+    TrashPurged(Vec<Uuid>),
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V4SecretTrashItem {
+    Entry(V4SecretEntry),
+    Folder {
+        folders: Vec<SecretFolder>,
+        entries: Vec<V4SecretEntry>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V4SecretSnapshot {
+    pub folders: HashMap<Uuid, SecretFolder>,
+    pub entries: HashMap<Uuid, V4SecretEntry>,
+    pub trash: HashMap<Uuid, TrashEntry<V4SecretTrashItem>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V4SecretsChange {
+    Snapshot(V4SecretSnapshot),
+    Deltas(Vec<V4SecretDelta>),
+}
+
+// Versions 1 to 3 share this delta layout; version 3 appended the API key variants.
+#[derive(Debug, Deserialize)]
+pub enum V3SecretDelta {
+    FolderAdded(SecretFolder),
+    FolderDeleted(Uuid),
+    FolderUpdated {
+        id: Uuid,
+        patch: SecretFolderPatch,
+    },
+    EntryAdded(V4LoginEntry),
+    EntryUpdated {
+        id: Uuid,
+        patch: V4LoginEntryPatch,
+    },
+    EntryDeleted(Uuid),
+    EntryTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    FolderTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    TrashRestored {
+        id: Uuid,
+        parent_id: Uuid,
+        name: String,
+    },
+    TrashPurged(Vec<Uuid>),
+    ApiKeyAdded(ApiKeyEntry),
+    ApiKeyUpdated {
+        id: Uuid,
+        patch: ApiKeyEntryPatch,
+    },
+    ApiKeyDeleted(Uuid),
+    ApiKeyTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V3SecretTrashItem {
+    Entry(V4LoginEntry),
+    Folder {
+        folders: Vec<SecretFolder>,
+        entries: Vec<V4LoginEntry>,
+        api_keys: Vec<ApiKeyEntry>,
+    },
+    ApiKey(ApiKeyEntry),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V3SecretSnapshot {
+    pub folders: HashMap<Uuid, SecretFolder>,
+    pub entries: HashMap<Uuid, V4LoginEntry>,
+    pub api_keys: HashMap<Uuid, ApiKeyEntry>,
+    pub trash: HashMap<Uuid, TrashEntry<V3SecretTrashItem>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V3SecretsChange {
+    Snapshot(V3SecretSnapshot),
+    Deltas(Vec<V3SecretDelta>),
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V2SecretTrashItem {
+    Entry(V4LoginEntry),
+    Folder {
+        folders: Vec<SecretFolder>,
+        entries: Vec<V4LoginEntry>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V2SecretSnapshot {
+    pub folders: HashMap<Uuid, SecretFolder>,
+    pub entries: HashMap<Uuid, V4LoginEntry>,
+    pub trash: HashMap<Uuid, TrashEntry<V2SecretTrashItem>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V2SecretsChange {
+    Snapshot(V2SecretSnapshot),
+    Deltas(Vec<V3SecretDelta>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V1SecretSnapshot {
+    pub folders: HashMap<Uuid, SecretFolder>,
+    pub entries: HashMap<Uuid, V4LoginEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum V1SecretsChange {
+    Snapshot(V1SecretSnapshot),
+    Deltas(Vec<V3SecretDelta>),
+}
+
+impl From<V4EncryptedTotp> for EncryptedTotp {
+    fn from(legacy: V4EncryptedTotp) -> Self {
+        Self {
+            secret: legacy.secret,
+            period: legacy.period,
+            digits: legacy.digits,
+            algorithm: TotpAlgorithm::Sha1,
+        }
+    }
+}
+
+impl From<V4LoginEntry> for LoginEntry {
+    fn from(legacy: V4LoginEntry) -> Self {
+        Self {
+            id: legacy.id,
+            folder_id: legacy.folder_id,
+            name: legacy.name,
+            username: legacy.username,
+            password: legacy.password,
+            website: legacy.website,
+            comments: legacy.comments,
+            created_at: legacy.created_at,
+            updated_at: legacy.updated_at,
+            totp: legacy.totp.map(Into::into),
+        }
+    }
+}
+
+impl From<V4LoginEntry> for SecretEntry {
+    fn from(legacy: V4LoginEntry) -> Self {
+        Self::Login(legacy.into())
+    }
+}
+
+impl From<V4LoginEntryPatch> for SecretEntryPatch {
+    fn from(legacy: V4LoginEntryPatch) -> Self {
+        Self::Login(LoginEntryPatch {
+            folder_id: legacy.folder_id,
+            name: legacy.name,
+            username: legacy.username,
+            password: legacy.password,
+            website: legacy.website,
+            comments: legacy.comments,
+            totp: legacy.totp.map(|totp| totp.map(Into::into)),
+            updated_at: legacy.updated_at,
+        })
+    }
+}
+
+impl From<V4SecretEntry> for SecretEntry {
+    fn from(legacy: V4SecretEntry) -> Self {
+        match legacy {
+            V4SecretEntry::Login(entry) => entry.into(),
+            V4SecretEntry::ApiKey(entry) => Self::ApiKey(entry),
+            V4SecretEntry::SecureNote(entry) => Self::SecureNote(entry),
+            V4SecretEntry::PaymentCard(entry) => Self::PaymentCard(entry),
+            V4SecretEntry::SshKey(entry) => Self::SshKey(entry),
+            V4SecretEntry::Identity(entry) => Self::Identity(entry),
+            V4SecretEntry::WifiNetwork(entry) => Self::WifiNetwork(entry),
+            V4SecretEntry::Custom(entry) => Self::Custom(entry),
+        }
+    }
+}
+
+impl From<V4SecretEntryPatch> for SecretEntryPatch {
+    fn from(legacy: V4SecretEntryPatch) -> Self {
+        match legacy {
+            V4SecretEntryPatch::Login(patch) => patch.into(),
+            V4SecretEntryPatch::ApiKey(patch) => Self::ApiKey(patch),
+            V4SecretEntryPatch::SecureNote(patch) => Self::SecureNote(patch),
+            V4SecretEntryPatch::PaymentCard(patch) => Self::PaymentCard(patch),
+            V4SecretEntryPatch::SshKey(patch) => Self::SshKey(patch),
+            V4SecretEntryPatch::Identity(patch) => Self::Identity(patch),
+            V4SecretEntryPatch::WifiNetwork(patch) => Self::WifiNetwork(patch),
+            V4SecretEntryPatch::Custom(patch) => Self::Custom(patch),
+        }
+    }
+}
+
+impl From<V4SecretDelta> for SecretDelta {
+    fn from(legacy: V4SecretDelta) -> Self {
+        match legacy {
+            V4SecretDelta::FolderAdded(folder) => Self::FolderAdded(folder),
+            V4SecretDelta::FolderDeleted(id) => Self::FolderDeleted(id),
+            V4SecretDelta::FolderUpdated { id, patch } => Self::FolderUpdated { id, patch },
+            V4SecretDelta::EntryAdded(entry) => Self::EntryAdded(entry.into()),
+            V4SecretDelta::EntryUpdated { id, patch } => Self::EntryUpdated {
+                id,
+                patch: patch.into(),
+            },
+            V4SecretDelta::EntryDeleted(id) => Self::EntryDeleted(id),
+            V4SecretDelta::EntryTrashed { id, deleted_at } => Self::EntryTrashed { id, deleted_at },
+            V4SecretDelta::FolderTrashed { id, deleted_at } => {
+                Self::FolderTrashed { id, deleted_at }
+            }
+            V4SecretDelta::TrashRestored {
+                id,
+                parent_id,
+                name,
+            } => Self::TrashRestored {
+                id,
+                parent_id,
+                name,
+            },
+            V4SecretDelta::TrashPurged(ids) => Self::TrashPurged(ids),
+        }
+    }
+}
+
+impl From<V4SecretTrashItem> for SecretTrashItem {
+    fn from(legacy: V4SecretTrashItem) -> Self {
+        match legacy {
+            V4SecretTrashItem::Entry(entry) => Self::Entry(entry.into()),
+            V4SecretTrashItem::Folder { folders, entries } => Self::Folder {
+                folders,
+                entries: entries.into_iter().map(SecretEntry::from).collect(),
+            },
+        }
+    }
+}
+
+impl From<V4SecretSnapshot> for SecretSnapshot {
+    fn from(legacy: V4SecretSnapshot) -> Self {
+        Self::new(
+            legacy.folders,
+            convert_entries(legacy.entries),
+            convert_trash(legacy.trash),
+        )
+    }
+}
+
+impl From<V4SecretsChange> for SecretsChange {
+    fn from(legacy: V4SecretsChange) -> Self {
+        match legacy {
+            V4SecretsChange::Snapshot(snapshot) => Self::Snapshot(snapshot.into()),
+            V4SecretsChange::Deltas(deltas) => convert_deltas(deltas),
+        }
+    }
+}
+
+impl From<V3SecretDelta> for SecretDelta {
+    fn from(legacy: V3SecretDelta) -> Self {
+        match legacy {
+            V3SecretDelta::FolderAdded(folder) => Self::FolderAdded(folder),
+            V3SecretDelta::FolderDeleted(id) => Self::FolderDeleted(id),
+            V3SecretDelta::FolderUpdated { id, patch } => Self::FolderUpdated { id, patch },
+            V3SecretDelta::EntryAdded(entry) => Self::EntryAdded(entry.into()),
+            V3SecretDelta::EntryUpdated { id, patch } => Self::EntryUpdated {
+                id,
+                patch: patch.into(),
+            },
+            V3SecretDelta::EntryDeleted(id) | V3SecretDelta::ApiKeyDeleted(id) => {
+                Self::EntryDeleted(id)
+            }
+            V3SecretDelta::EntryTrashed { id, deleted_at }
+            | V3SecretDelta::ApiKeyTrashed { id, deleted_at } => {
+                Self::EntryTrashed { id, deleted_at }
+            }
+            V3SecretDelta::FolderTrashed { id, deleted_at } => {
+                Self::FolderTrashed { id, deleted_at }
+            }
+            V3SecretDelta::TrashRestored {
+                id,
+                parent_id,
+                name,
+            } => Self::TrashRestored {
+                id,
+                parent_id,
+                name,
+            },
+            V3SecretDelta::TrashPurged(ids) => Self::TrashPurged(ids),
+            V3SecretDelta::ApiKeyAdded(api_key) => Self::EntryAdded(api_key.into()),
+            V3SecretDelta::ApiKeyUpdated { id, patch } => Self::EntryUpdated {
+                id,
+                patch: patch.into(),
+            },
+        }
+    }
+}
+
+impl From<V3SecretTrashItem> for SecretTrashItem {
+    fn from(legacy: V3SecretTrashItem) -> Self {
+        match legacy {
+            V3SecretTrashItem::Entry(entry) => Self::Entry(entry.into()),
+            V3SecretTrashItem::ApiKey(api_key) => Self::Entry(api_key.into()),
+            V3SecretTrashItem::Folder {
+                folders,
+                entries,
+                api_keys,
+            } => Self::Folder {
+                folders,
+                entries: entries
+                    .into_iter()
+                    .map(SecretEntry::from)
+                    .chain(api_keys.into_iter().map(SecretEntry::from))
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<V2SecretTrashItem> for SecretTrashItem {
+    fn from(legacy: V2SecretTrashItem) -> Self {
+        match legacy {
+            V2SecretTrashItem::Entry(entry) => Self::Entry(entry.into()),
+            V2SecretTrashItem::Folder { folders, entries } => Self::Folder {
+                folders,
+                entries: entries.into_iter().map(SecretEntry::from).collect(),
+            },
+        }
+    }
+}
+
+impl From<V3SecretSnapshot> for SecretSnapshot {
+    fn from(legacy: V3SecretSnapshot) -> Self {
+        let entries = legacy
+            .entries
+            .into_values()
+            .map(SecretEntry::from)
+            .chain(legacy.api_keys.into_values().map(SecretEntry::from))
+            .map(|entry| (entry.id(), entry))
+            .collect();
+
+        Self::new(legacy.folders, entries, convert_trash(legacy.trash))
+    }
+}
+
+impl From<V2SecretSnapshot> for SecretSnapshot {
+    fn from(legacy: V2SecretSnapshot) -> Self {
+        Self::new(
+            legacy.folders,
+            convert_entries(legacy.entries),
+            convert_trash(legacy.trash),
+        )
+    }
+}
+
+impl From<V3SecretsChange> for SecretsChange {
+    fn from(legacy: V3SecretsChange) -> Self {
+        match legacy {
+            V3SecretsChange::Snapshot(snapshot) => Self::Snapshot(snapshot.into()),
+            V3SecretsChange::Deltas(deltas) => convert_deltas(deltas),
+        }
+    }
+}
+
+impl From<V2SecretsChange> for SecretsChange {
+    fn from(legacy: V2SecretsChange) -> Self {
+        match legacy {
+            V2SecretsChange::Snapshot(snapshot) => Self::Snapshot(snapshot.into()),
+            V2SecretsChange::Deltas(deltas) => convert_deltas(deltas),
+        }
+    }
+}
+
+impl From<V1SecretsChange> for SecretsChange {
+    fn from(legacy: V1SecretsChange) -> Self {
+        match legacy {
+            V1SecretsChange::Snapshot(snapshot) => Self::Snapshot(SecretSnapshot::new(
+                snapshot.folders,
+                convert_entries(snapshot.entries),
+                HashMap::new(),
+            )),
+            V1SecretsChange::Deltas(deltas) => convert_deltas(deltas),
+        }
+    }
+}
+
+fn convert_entries<T: Into<SecretEntry>>(entries: HashMap<Uuid, T>) -> HashMap<Uuid, SecretEntry> {
+    entries
+        .into_iter()
+        .map(|(id, entry)| (id, entry.into()))
+        .collect()
+}
+
+fn convert_trash<T: Into<SecretTrashItem>>(
+    trash: HashMap<Uuid, TrashEntry<T>>,
+) -> HashMap<Uuid, SecretTrashEntry> {
+    trash
+        .into_iter()
+        .map(|(id, entry)| {
+            let item = entry.item.into();
+            let entry =
+                SecretTrashEntry::new(entry.id, entry.original_parent_id, entry.deleted_at, item);
+            (id, entry)
+        })
+        .collect()
+}
+
+fn convert_deltas<T: Into<SecretDelta>>(deltas: Vec<T>) -> SecretsChange {
+    SecretsChange::Deltas(deltas.into_iter().map(Into::into).collect())
+}
@@ -1,18 +1,76 @@
+// Entries, views and patches name their variants after `SecretKind`, so the
+// arms that only forward to the per-kind type are written out once here.
+macro_rules! each_kind {
+    ($enum:ident, $value:expr, $kind:ident, $inner:ident => $body:expr) => {
+        match $value {
+            $enum::Login($inner) => {
+                let $kind = SecretKind::Login;
+                $body
+            }
+            $enum::ApiKey($inner) => {
+                let $kind = SecretKind::ApiKey;
+                $body
+            }
+            $enum::SecureNote($inner) => {
+                let $kind = SecretKind::SecureNote;
+                $body
+            }
+            $enum::PaymentCard($inner) => {
+                let $kind = SecretKind::PaymentCard;
+                $body
+            }
+            $enum::SshKey($inner) => {
+                let $kind = SecretKind::SshKey;
+                $body
+            }
+            $enum::Identity($inner) => {
+                let $kind = SecretKind::Identity;
+                $body
+            }
+            $enum::WifiNetwork($inner) => {
+                let $kind = SecretKind::WifiNetwork;
+                $body
+            }
+            $enum::Custom($inner) => {
+                let $kind = SecretKind::Custom;
+                $body
+            }
+        }
+    };
+}
+
+mod audit;
 mod codec;
 mod error;
 mod indexes;
+mod legacy;
 mod models;
+mod namings;
 mod patch;
 mod records;
 mod store;
+mod trash;
 mod validate;
 
+pub use audit::{AuditFinding, AuditIssue, AuditOptions, AuditReport, BreachIndex, WeakPattern};
 pub use codec::{SECRETS_FEATURE_ID, SecretCodec};
 pub use error::{Result, SecretError};
 pub use models::{
-    EncryptedTotp, LoginEntry, LoginEntryView, NewLoginSecret, SECRETS_ROOT_FOLDER_ID,
-    SECRETS_ROOT_FOLDER_NAME, SealedValue, SecretFolder, TOTP,
+    ApiKeyEntry, ApiKeyEntryView, CustomEntry, CustomEntryView, CustomField, EncryptedTotp,
+    IdentityEntry, IdentityEntryView, LoginEntry, LoginEntryView, NewApiKeySecret, NewCustomSecret,
+    NewIdentitySecret, NewLoginSecret, NewPaymentCardSecret, NewSecretEntry, NewSecureNoteSecret,
+    NewSshKeySecret, NewWifiNetworkSecret, OtpAuthUri, PaymentCardEntry, PaymentCardEntryView,
+    SECRETS_ROOT_FOLDER_ID, SECRETS_ROOT_FOLDER_NAME, SealedValue, SecretEntry, SecretEntryView,
+    SecretFolder, SecretKind, SecureNoteEntry, SecureNoteEntryView, SshKeyEntry, SshKeyEntryView,
+    TOTP, TotpAlgorithm, TotpCode, WifiNetworkEntry, WifiNetworkEntryView, WifiSecurity,
+};
+pub use patch::{
+    ApiKeyEntryPatch, CustomEntryPatch, IdentityEntryPatch, LoginEntryPatch, NewApiKeySecretPatch,
+    NewCustomSecretPatch, NewIdentitySecretPatch, NewLoginSecretPatch, NewPaymentCardSecretPatch,
+    NewSecretEntryPatch, NewSecureNoteSecretPatch, NewSshKeySecretPatch, NewWifiNetworkSecretPatch,
+    PaymentCardEntryPatch, SecretEntryPatch, SecretFolderPatch, SecureNoteEntryPatch,
+    SshKeyEntryPatch, WifiNetworkEntryPatch,
 };
-pub use patch::{LoginEntryPatch, NewLoginSecretPatch, SecretFolderPatch};
 pub use records::{SECRETS_WIRE_VERSION, SecretDelta, SecretSnapshot, SecretsChange};
 pub use store::SecretStore;
+pub use trash::{SecretTrashEntry, SecretTrashItem};
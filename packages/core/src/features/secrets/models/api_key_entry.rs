@@ -9,7 +9,7 @@ use openvault_crypto::keys::derived_key::DerivedKey;
 
 use super::super::error::Result;
 use super::super::patch::ApiKeyEntryPatch;
-use super::EncryptedField;
+use super::SealedValue;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
 pub struct ApiKeyEntry {
@@ -18,9 +18,9 @@ pub struct ApiKeyEntry {
     #[validate(length(min = 1, max = 255))]
     #[validate(custom(function = "super::super::validate::validate_safe_name"))]
     pub name: String,
-    pub api_key: EncryptedField,
-    pub website: EncryptedField,
-    pub comments: EncryptedField,
+    pub api_key: SealedValue,
+    pub website: SealedValue,
+    pub comments: SealedValue,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -37,13 +37,17 @@ pub struct ApiKeyEntryView {
     pub updated_at: DateTime<Utc>,
 }
 
+pub struct NewApiKeySecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub api_key: String,
+    pub website: Option<String>,
+    pub comments: Option<String>,
+}
+
 impl ApiKeyEntry {
     pub fn seal(
-        folder_id: Uuid,
-        name: String,
-        api_key: String,
-        website: Option<String>,
-        comments: Option<String>,
+        input: NewApiKeySecret,
         key: &DerivedKey,
         cipher: EncryptionAlgorithm,
     ) -> Result<Self> {
@@ -51,11 +55,11 @@ impl ApiKeyEntry {
 
         Ok(Self {
             id: Uuid::new_v4(),
-            folder_id,
-            name,
-            api_key: EncryptedField::seal_string(api_key, key, cipher)?,
-            website: EncryptedField::seal_string(website.unwrap_or_default(), key, cipher)?,
-            comments: EncryptedField::seal_string(comments.unwrap_or_default(), key, cipher)?,
+            folder_id: input.folder_id,
+            name: input.name,
+            api_key: SealedValue::seal_string(input.api_key, key, cipher)?,
+            website: SealedValue::seal_string(input.website.unwrap_or_default(), key, cipher)?,
+            comments: SealedValue::seal_string(input.comments.unwrap_or_default(), key, cipher)?,
             created_at: now,
             updated_at: now,
         })
@@ -74,6 +78,19 @@ impl ApiKeyEntry {
         })
     }
 
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.api_key = self.api_key.reseal(current, next, cipher)?;
+        self.website = self.website.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
     pub fn apply_patch(&mut self, update: ApiKeyEntryPatch) -> Result {
         if let Some(folder_id) = update.folder_id {
             self.folder_id = folder_id;
@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::Result;
+use super::super::patch::CustomEntryPatch;
+use super::SealedValue;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CustomField {
+    pub label: String,
+    pub value: String,
+    pub concealed: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct CustomEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    #[validate(length(min = 1, max = 255))]
+    #[validate(custom(function = "super::super::validate::validate_safe_name"))]
+    pub name: String,
+    pub fields: SealedValue,
+    pub comments: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CustomEntryView {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub fields: Vec<CustomField>,
+    pub comments: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewCustomSecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub fields: Vec<CustomField>,
+    pub comments: Option<String>,
+}
+
+impl CustomEntry {
+    pub fn seal(
+        input: NewCustomSecret,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            folder_id: input.folder_id,
+            name: input.name,
+            fields: SealedValue::seal_value(&input.fields, key, cipher)?,
+            comments: SealedValue::seal_string(input.comments.unwrap_or_default(), key, cipher)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn reveal(&self, key: &DerivedKey, cipher: EncryptionAlgorithm) -> Result<CustomEntryView> {
+        Ok(CustomEntryView {
+            id: self.id,
+            folder_id: self.folder_id,
+            name: self.name.clone(),
+            fields: self.fields.reveal_value(key, cipher)?,
+            comments: self.comments.reveal_string(key, cipher)?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.fields = self.fields.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, update: CustomEntryPatch) -> Result {
+        if let Some(folder_id) = update.folder_id {
+            self.folder_id = folder_id;
+        }
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(fields) = update.fields {
+            self.fields = fields;
+        }
+        if let Some(comments) = update.comments {
+            self.comments = comments;
+        }
+
+        self.updated_at = update.updated_at;
+
+        Ok(())
+    }
+}
+
+impl Zeroize for CustomEntry {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.fields.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for CustomEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for CustomEntryView {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.fields.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for CustomEntryView {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for CustomField {
+    fn zeroize(&mut self) {
+        self.label.zeroize();
+        self.value.zeroize();
+    }
+}
+
+impl Drop for CustomField {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
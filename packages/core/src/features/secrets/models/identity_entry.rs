@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::Result;
+use super::super::patch::IdentityEntryPatch;
+use super::SealedValue;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct IdentityEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    #[validate(length(min = 1, max = 255))]
+    #[validate(custom(function = "super::super::validate::validate_safe_name"))]
+    pub name: String,
+    pub full_name: SealedValue,
+    pub email: SealedValue,
+    pub phone: SealedValue,
+    pub address: SealedValue,
+    pub document_number: SealedValue,
+    pub comments: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IdentityEntryView {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub full_name: String,
+    pub email: String,
+    pub phone: String,
+    pub address: String,
+    pub document_number: String,
+    pub comments: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewIdentitySecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub full_name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub document_number: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl IdentityEntry {
+    pub fn seal(
+        input: NewIdentitySecret,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            folder_id: input.folder_id,
+            name: input.name,
+            full_name: SealedValue::seal_string(input.full_name, key, cipher)?,
+            email: SealedValue::seal_string(input.email.unwrap_or_default(), key, cipher)?,
+            phone: SealedValue::seal_string(input.phone.unwrap_or_default(), key, cipher)?,
+            address: SealedValue::seal_string(input.address.unwrap_or_default(), key, cipher)?,
+            document_number: SealedValue::seal_string(
+                input.document_number.unwrap_or_default(),
+                key,
+                cipher,
+            )?,
+            comments: SealedValue::seal_string(input.comments.unwrap_or_default(), key, cipher)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn reveal(
+        &self,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<IdentityEntryView> {
+        Ok(IdentityEntryView {
+            id: self.id,
+            folder_id: self.folder_id,
+            name: self.name.clone(),
+            full_name: self.full_name.reveal_string(key, cipher)?,
+            email: self.email.reveal_string(key, cipher)?,
+            phone: self.phone.reveal_string(key, cipher)?,
+            address: self.address.reveal_string(key, cipher)?,
+            document_number: self.document_number.reveal_string(key, cipher)?,
+            comments: self.comments.reveal_string(key, cipher)?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.full_name = self.full_name.reseal(current, next, cipher)?;
+        self.email = self.email.reseal(current, next, cipher)?;
+        self.phone = self.phone.reseal(current, next, cipher)?;
+        self.address = self.address.reseal(current, next, cipher)?;
+        self.document_number = self.document_number.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, update: IdentityEntryPatch) -> Result {
+        if let Some(folder_id) = update.folder_id {
+            self.folder_id = folder_id;
+        }
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(full_name) = update.full_name {
+            self.full_name = full_name;
+        }
+        if let Some(email) = update.email {
+            self.email = email;
+        }
+        if let Some(phone) = update.phone {
+            self.phone = phone;
+        }
+        if let Some(address) = update.address {
+            self.address = address;
+        }
+        if let Some(document_number) = update.document_number {
+            self.document_number = document_number;
+        }
+        if let Some(comments) = update.comments {
+            self.comments = comments;
+        }
+
+        self.updated_at = update.updated_at;
+
+        Ok(())
+    }
+}
+
+impl Zeroize for IdentityEntry {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.full_name.zeroize();
+        self.email.zeroize();
+        self.phone.zeroize();
+        self.address.zeroize();
+        self.document_number.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for IdentityEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for IdentityEntryView {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.full_name.zeroize();
+        self.email.zeroize();
+        self.phone.zeroize();
+        self.address.zeroize();
+        self.document_number.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for IdentityEntryView {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -96,6 +96,25 @@ impl LoginEntry {
         })
     }
 
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.username = self.username.reseal(current, next, cipher)?;
+        self.password = self.password.reseal(current, next, cipher)?;
+        self.website = self.website.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+        self.totp = self
+            .totp
+            .as_ref()
+            .map(|value| value.reseal(current, next, cipher))
+            .transpose()?;
+
+        Ok(())
+    }
+
     pub fn apply_patch(&mut self, update: LoginEntryPatch) -> Result {
         if let Some(folder_id) = update.folder_id {
             self.folder_id = folder_id;
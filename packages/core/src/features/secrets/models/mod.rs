@@ -1,9 +1,29 @@
+mod api_key_entry;
+mod custom_entry;
 mod folder;
+mod identity_entry;
 mod login_entry;
+mod otpauth;
+mod payment_card_entry;
 mod sealed_value;
+mod secret_entry;
+mod secure_note_entry;
+mod ssh_key_entry;
 mod totp;
+mod wifi_network_entry;
 
+pub use api_key_entry::{ApiKeyEntry, ApiKeyEntryView, NewApiKeySecret};
+pub use custom_entry::{CustomEntry, CustomEntryView, CustomField, NewCustomSecret};
 pub use folder::{SECRETS_ROOT_FOLDER_ID, SECRETS_ROOT_FOLDER_NAME, SecretFolder};
+pub use identity_entry::{IdentityEntry, IdentityEntryView, NewIdentitySecret};
 pub use login_entry::{LoginEntry, LoginEntryView, NewLoginSecret};
+pub use otpauth::OtpAuthUri;
+pub use payment_card_entry::{NewPaymentCardSecret, PaymentCardEntry, PaymentCardEntryView};
 pub use sealed_value::SealedValue;
-pub use totp::{EncryptedTotp, TOTP};
+pub use secret_entry::{NewSecretEntry, SecretEntry, SecretEntryView, SecretKind};
+pub use secure_note_entry::{NewSecureNoteSecret, SecureNoteEntry, SecureNoteEntryView};
+pub use ssh_key_entry::{NewSshKeySecret, SshKeyEntry, SshKeyEntryView};
+pub use totp::{EncryptedTotp, TOTP, TotpAlgorithm, TotpCode};
+pub use wifi_network_entry::{
+    NewWifiNetworkSecret, WifiNetworkEntry, WifiNetworkEntryView, WifiSecurity,
+};
@@ -0,0 +1,145 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::super::error::{Result, SecretError};
+use super::totp::{TOTP, TotpAlgorithm, decode_base32};
+
+const OTPAUTH_PREFIX: &str = "otpauth://totp/";
+
+// Key URI format popularised by Google Authenticator:
+// otpauth://totp/Issuer:account?secret=BASE32&issuer=Issuer&algorithm=SHA1&digits=6&period=30
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct OtpAuthUri {
+    pub issuer: Option<String>,
+    pub account: String,
+    pub totp: TOTP,
+}
+
+impl OtpAuthUri {
+    pub fn new(issuer: Option<String>, account: String, totp: TOTP) -> Self {
+        Self {
+            issuer,
+            account,
+            totp,
+        }
+    }
+
+    pub fn parse(uri: &str) -> Result<Self> {
+        let rest = uri
+            .get(..OTPAUTH_PREFIX.len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case(OTPAUTH_PREFIX))
+            .map(|_| &uri[OTPAUTH_PREFIX.len()..])
+            .ok_or_else(|| invalid("Only otpauth://totp/ URIs are supported"))?;
+
+        let (label, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let label = percent_decode(label, false)?;
+        let (mut issuer, account) = match label.split_once(':') {
+            Some((issuer, account)) => (Some(issuer.to_string()), account.trim_start()),
+            None => (None, label.as_str()),
+        };
+        let account = account.to_string();
+
+        let (mut secret, mut algorithm, mut digits, mut period) = (None, None, None, None);
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value, true)?;
+
+            match key.to_ascii_lowercase().as_str() {
+                "secret" => secret = Some(value),
+                "issuer" => issuer = Some(value),
+                "algorithm" => {
+                    algorithm = Some(
+                        value
+                            .parse::<TotpAlgorithm>()
+                            .map_err(|_| invalid(format!("Unsupported algorithm: {value}")))?,
+                    )
+                }
+                "digits" => digits = Some(parse_number(&value, "digits")?),
+                "period" => period = Some(parse_number(&value, "period")?),
+                _ => {}
+            }
+        }
+
+        let mut secret = secret.ok_or_else(|| invalid("Missing secret parameter"))?;
+        secret.retain(|c| !c.is_whitespace() && c != '=');
+        secret.make_ascii_uppercase();
+        decode_base32(&secret)?;
+
+        let totp = TOTP::new(secret, period, digits)?.with_algorithm(algorithm.unwrap_or_default());
+
+        Ok(Self::new(issuer.filter(|i| !i.is_empty()), account, totp))
+    }
+
+    pub fn to_uri(&self) -> String {
+        let label = match &self.issuer {
+            Some(issuer) => format!(
+                "{}:{}",
+                percent_encode(issuer),
+                percent_encode(&self.account)
+            ),
+            None => percent_encode(&self.account),
+        };
+
+        let mut uri = format!(
+            "{OTPAUTH_PREFIX}{label}?secret={}",
+            percent_encode(&self.totp.secret)
+        );
+        if let Some(issuer) = &self.issuer {
+            uri.push_str(&format!("&issuer={}", percent_encode(issuer)));
+        }
+        uri.push_str(&format!(
+            "&algorithm={}&digits={}&period={}",
+            self.totp.algorithm, self.totp.digits, self.totp.period
+        ));
+
+        uri
+    }
+}
+
+fn invalid(message: impl Into<String>) -> SecretError {
+    SecretError::InvalidInput(message.into())
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| invalid(format!("Invalid {name} parameter: {value}")))
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    encoded
+}
+
+// Query values are sometimes form-encoded by issuers, so `+` also decodes to a space there.
+fn percent_decode(value: &str, plus_as_space: bool) -> Result<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+
+    while let Some(byte) = input.next() {
+        match byte {
+            b'%' => {
+                let hex = [input.next(), input.next()];
+                let decoded = match hex {
+                    [Some(high), Some(low)] => std::str::from_utf8(&[high, low])
+                        .ok()
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+                    _ => None,
+                };
+                bytes.push(decoded.ok_or_else(|| invalid("Invalid percent-encoding"))?);
+            }
+            b'+' if plus_as_space => bytes.push(b' '),
+            _ => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid("URI is not valid UTF-8"))
+}
@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::Result;
+use super::super::patch::PaymentCardEntryPatch;
+use super::SealedValue;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct PaymentCardEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    #[validate(length(min = 1, max = 255))]
+    #[validate(custom(function = "super::super::validate::validate_safe_name"))]
+    pub name: String,
+    pub cardholder_name: SealedValue,
+    pub number: SealedValue,
+    pub expiry: SealedValue,
+    pub security_code: SealedValue,
+    pub comments: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaymentCardEntryView {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub cardholder_name: String,
+    pub number: String,
+    pub expiry: String,
+    pub security_code: String,
+    pub comments: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewPaymentCardSecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub cardholder_name: String,
+    pub number: String,
+    pub expiry: String,
+    pub security_code: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl PaymentCardEntry {
+    pub fn seal(
+        input: NewPaymentCardSecret,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            folder_id: input.folder_id,
+            name: input.name,
+            cardholder_name: SealedValue::seal_string(input.cardholder_name, key, cipher)?,
+            number: SealedValue::seal_string(input.number, key, cipher)?,
+            expiry: SealedValue::seal_string(input.expiry, key, cipher)?,
+            security_code: SealedValue::seal_string(
+                input.security_code.unwrap_or_default(),
+                key,
+                cipher,
+            )?,
+            comments: SealedValue::seal_string(input.comments.unwrap_or_default(), key, cipher)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn reveal(
+        &self,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<PaymentCardEntryView> {
+        Ok(PaymentCardEntryView {
+            id: self.id,
+            folder_id: self.folder_id,
+            name: self.name.clone(),
+            cardholder_name: self.cardholder_name.reveal_string(key, cipher)?,
+            number: self.number.reveal_string(key, cipher)?,
+            expiry: self.expiry.reveal_string(key, cipher)?,
+            security_code: self.security_code.reveal_string(key, cipher)?,
+            comments: self.comments.reveal_string(key, cipher)?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.cardholder_name = self.cardholder_name.reseal(current, next, cipher)?;
+        self.number = self.number.reseal(current, next, cipher)?;
+        self.expiry = self.expiry.reseal(current, next, cipher)?;
+        self.security_code = self.security_code.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, update: PaymentCardEntryPatch) -> Result {
+        if let Some(folder_id) = update.folder_id {
+            self.folder_id = folder_id;
+        }
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(cardholder_name) = update.cardholder_name {
+            self.cardholder_name = cardholder_name;
+        }
+        if let Some(number) = update.number {
+            self.number = number;
+        }
+        if let Some(expiry) = update.expiry {
+            self.expiry = expiry;
+        }
+        if let Some(security_code) = update.security_code {
+            self.security_code = security_code;
+        }
+        if let Some(comments) = update.comments {
+            self.comments = comments;
+        }
+
+        self.updated_at = update.updated_at;
+
+        Ok(())
+    }
+}
+
+impl Zeroize for PaymentCardEntry {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.cardholder_name.zeroize();
+        self.number.zeroize();
+        self.expiry.zeroize();
+        self.security_code.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for PaymentCardEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for PaymentCardEntryView {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.cardholder_name.zeroize();
+        self.number.zeroize();
+        self.expiry.zeroize();
+        self.security_code.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for PaymentCardEntryView {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
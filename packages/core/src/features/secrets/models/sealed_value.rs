@@ -38,6 +38,18 @@ impl SealedValue {
             .map_err(|e| SecretError::CryptoError(e.to_string()))
     }
 
+    pub fn reseal(
+        &self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let mut plaintext = self.reveal_bytes(current, cipher)?;
+        let sealed = Self::seal_bytes(&plaintext, next, cipher);
+        plaintext.zeroize();
+        sealed
+    }
+
     pub fn seal_string(
         plaintext: impl AsRef<str>,
         key: &DerivedKey,
@@ -0,0 +1,255 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationErrors};
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::{Result, SecretError};
+use super::super::patch::SecretEntryPatch;
+use super::{
+    ApiKeyEntry, ApiKeyEntryView, CustomEntry, CustomEntryView, IdentityEntry, IdentityEntryView,
+    LoginEntry, LoginEntryView, NewApiKeySecret, NewCustomSecret, NewIdentitySecret,
+    NewLoginSecret, NewPaymentCardSecret, NewSecureNoteSecret, NewSshKeySecret,
+    NewWifiNetworkSecret, PaymentCardEntry, PaymentCardEntryView, SecureNoteEntry,
+    SecureNoteEntryView, SshKeyEntry, SshKeyEntryView, WifiNetworkEntry, WifiNetworkEntryView,
+};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SecretKind {
+    Login,
+    ApiKey,
+    SecureNote,
+    PaymentCard,
+    SshKey,
+    Identity,
+    WifiNetwork,
+    Custom,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SecretEntry {
+    Login(LoginEntry),
+    ApiKey(ApiKeyEntry),
+    SecureNote(SecureNoteEntry),
+    PaymentCard(PaymentCardEntry),
+    SshKey(SshKeyEntry),
+    Identity(IdentityEntry),
+    WifiNetwork(WifiNetworkEntry),
+    Custom(CustomEntry),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SecretEntryView {
+    Login(LoginEntryView),
+    ApiKey(ApiKeyEntryView),
+    SecureNote(SecureNoteEntryView),
+    PaymentCard(PaymentCardEntryView),
+    SshKey(SshKeyEntryView),
+    Identity(IdentityEntryView),
+    WifiNetwork(WifiNetworkEntryView),
+    Custom(CustomEntryView),
+}
+
+pub enum NewSecretEntry {
+    Login(NewLoginSecret),
+    ApiKey(NewApiKeySecret),
+    SecureNote(NewSecureNoteSecret),
+    PaymentCard(NewPaymentCardSecret),
+    SshKey(NewSshKeySecret),
+    Identity(NewIdentitySecret),
+    WifiNetwork(NewWifiNetworkSecret),
+    Custom(NewCustomSecret),
+}
+
+impl SecretEntry {
+    pub fn seal(
+        input: NewSecretEntry,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(match input {
+            NewSecretEntry::Login(input) => Self::Login(LoginEntry::seal(input, key, cipher)?),
+            NewSecretEntry::ApiKey(input) => Self::ApiKey(ApiKeyEntry::seal(input, key, cipher)?),
+            NewSecretEntry::SecureNote(input) => {
+                Self::SecureNote(SecureNoteEntry::seal(input, key, cipher)?)
+            }
+            NewSecretEntry::PaymentCard(input) => {
+                Self::PaymentCard(PaymentCardEntry::seal(input, key, cipher)?)
+            }
+            NewSecretEntry::SshKey(input) => Self::SshKey(SshKeyEntry::seal(input, key, cipher)?),
+            NewSecretEntry::Identity(input) => {
+                Self::Identity(IdentityEntry::seal(input, key, cipher)?)
+            }
+            NewSecretEntry::WifiNetwork(input) => {
+                Self::WifiNetwork(WifiNetworkEntry::seal(input, key, cipher)?)
+            }
+            NewSecretEntry::Custom(input) => Self::Custom(CustomEntry::seal(input, key, cipher)?),
+        })
+    }
+
+    pub fn kind(&self) -> SecretKind {
+        each_kind!(SecretEntry, self, kind, _entry => kind)
+    }
+
+    pub fn id(&self) -> Uuid {
+        each_kind!(SecretEntry, self, _kind, entry => entry.id)
+    }
+
+    pub fn folder_id(&self) -> Uuid {
+        each_kind!(SecretEntry, self, _kind, entry => entry.folder_id)
+    }
+
+    pub fn name(&self) -> &str {
+        each_kind!(SecretEntry, self, _kind, entry => &entry.name)
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        each_kind!(SecretEntry, self, _kind, entry => entry.created_at)
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        each_kind!(SecretEntry, self, _kind, entry => entry.updated_at)
+    }
+
+    pub fn as_login(&self) -> Option<&LoginEntry> {
+        match self {
+            Self::Login(entry) => Some(entry),
+            _ => None,
+        }
+    }
+
+    pub fn as_api_key(&self) -> Option<&ApiKeyEntry> {
+        match self {
+            Self::ApiKey(entry) => Some(entry),
+            _ => None,
+        }
+    }
+
+    pub fn reveal(&self, key: &DerivedKey, cipher: EncryptionAlgorithm) -> Result<SecretEntryView> {
+        Ok(match self {
+            Self::Login(entry) => SecretEntryView::Login(entry.reveal(key, cipher)?),
+            Self::ApiKey(entry) => SecretEntryView::ApiKey(entry.reveal(key, cipher)?),
+            Self::SecureNote(entry) => SecretEntryView::SecureNote(entry.reveal(key, cipher)?),
+            Self::PaymentCard(entry) => SecretEntryView::PaymentCard(entry.reveal(key, cipher)?),
+            Self::SshKey(entry) => SecretEntryView::SshKey(entry.reveal(key, cipher)?),
+            Self::Identity(entry) => SecretEntryView::Identity(entry.reveal(key, cipher)?),
+            Self::WifiNetwork(entry) => SecretEntryView::WifiNetwork(entry.reveal(key, cipher)?),
+            Self::Custom(entry) => SecretEntryView::Custom(entry.reveal(key, cipher)?),
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        each_kind!(SecretEntry, self, _kind, entry => entry.reseal(current, next, cipher))
+    }
+
+    pub fn apply_patch(&mut self, update: SecretEntryPatch) -> Result {
+        let (id, kind) = (self.id(), update.kind());
+
+        match (self, update) {
+            (Self::Login(entry), SecretEntryPatch::Login(patch)) => entry.apply_patch(patch),
+            (Self::ApiKey(entry), SecretEntryPatch::ApiKey(patch)) => entry.apply_patch(patch),
+            (Self::SecureNote(entry), SecretEntryPatch::SecureNote(patch)) => {
+                entry.apply_patch(patch)
+            }
+            (Self::PaymentCard(entry), SecretEntryPatch::PaymentCard(patch)) => {
+                entry.apply_patch(patch)
+            }
+            (Self::SshKey(entry), SecretEntryPatch::SshKey(patch)) => entry.apply_patch(patch),
+            (Self::Identity(entry), SecretEntryPatch::Identity(patch)) => entry.apply_patch(patch),
+            (Self::WifiNetwork(entry), SecretEntryPatch::WifiNetwork(patch)) => {
+                entry.apply_patch(patch)
+            }
+            (Self::Custom(entry), SecretEntryPatch::Custom(patch)) => entry.apply_patch(patch),
+            _ => Err(SecretError::EntryKindMismatch(id, kind)),
+        }
+    }
+
+    pub(crate) fn relocate(&mut self, folder_id: Uuid, name: &str) {
+        each_kind!(SecretEntry, self, _kind, entry => {
+            entry.folder_id = folder_id;
+            entry.name = name.to_string();
+        })
+    }
+}
+
+impl SecretEntryView {
+    pub fn kind(&self) -> SecretKind {
+        each_kind!(SecretEntryView, self, kind, _view => kind)
+    }
+
+    pub fn id(&self) -> Uuid {
+        each_kind!(SecretEntryView, self, _kind, view => view.id)
+    }
+
+    pub fn name(&self) -> &str {
+        each_kind!(SecretEntryView, self, _kind, view => &view.name)
+    }
+}
+
+impl Validate for SecretEntry {
+    fn validate(&self) -> std::result::Result<(), ValidationErrors> {
+        each_kind!(SecretEntry, self, _kind, entry => entry.validate())
+    }
+}
+
+impl Zeroize for SecretEntry {
+    fn zeroize(&mut self) {
+        each_kind!(SecretEntry, self, _kind, entry => entry.zeroize())
+    }
+}
+
+impl From<LoginEntry> for SecretEntry {
+    fn from(value: LoginEntry) -> Self {
+        Self::Login(value)
+    }
+}
+
+impl From<ApiKeyEntry> for SecretEntry {
+    fn from(value: ApiKeyEntry) -> Self {
+        Self::ApiKey(value)
+    }
+}
+
+impl From<SecureNoteEntry> for SecretEntry {
+    fn from(value: SecureNoteEntry) -> Self {
+        Self::SecureNote(value)
+    }
+}
+
+impl From<PaymentCardEntry> for SecretEntry {
+    fn from(value: PaymentCardEntry) -> Self {
+        Self::PaymentCard(value)
+    }
+}
+
+impl From<SshKeyEntry> for SecretEntry {
+    fn from(value: SshKeyEntry) -> Self {
+        Self::SshKey(value)
+    }
+}
+
+impl From<IdentityEntry> for SecretEntry {
+    fn from(value: IdentityEntry) -> Self {
+        Self::Identity(value)
+    }
+}
+
+impl From<WifiNetworkEntry> for SecretEntry {
+    fn from(value: WifiNetworkEntry) -> Self {
+        Self::WifiNetwork(value)
+    }
+}
+
+impl From<CustomEntry> for SecretEntry {
+    fn from(value: CustomEntry) -> Self {
+        Self::Custom(value)
+    }
+}
@@ -0,0 +1,131 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::Result;
+use super::super::patch::SecureNoteEntryPatch;
+use super::SealedValue;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct SecureNoteEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    #[validate(length(min = 1, max = 255))]
+    #[validate(custom(function = "super::super::validate::validate_safe_name"))]
+    pub name: String,
+    pub content: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SecureNoteEntryView {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewSecureNoteSecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub content: String,
+}
+
+impl SecureNoteEntry {
+    pub fn seal(
+        input: NewSecureNoteSecret,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            folder_id: input.folder_id,
+            name: input.name,
+            content: SealedValue::seal_string(input.content, key, cipher)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn reveal(
+        &self,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<SecureNoteEntryView> {
+        Ok(SecureNoteEntryView {
+            id: self.id,
+            folder_id: self.folder_id,
+            name: self.name.clone(),
+            content: self.content.reveal_string(key, cipher)?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.content = self.content.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, update: SecureNoteEntryPatch) -> Result {
+        if let Some(folder_id) = update.folder_id {
+            self.folder_id = folder_id;
+        }
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(content) = update.content {
+            self.content = content;
+        }
+
+        self.updated_at = update.updated_at;
+
+        Ok(())
+    }
+}
+
+impl Zeroize for SecureNoteEntry {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.content.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for SecureNoteEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for SecureNoteEntryView {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.content.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for SecureNoteEntryView {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
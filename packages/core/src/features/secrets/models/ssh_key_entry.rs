@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::Result;
+use super::super::patch::SshKeyEntryPatch;
+use super::SealedValue;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct SshKeyEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    #[validate(length(min = 1, max = 255))]
+    #[validate(custom(function = "super::super::validate::validate_safe_name"))]
+    pub name: String,
+    pub private_key: SealedValue,
+    pub public_key: SealedValue,
+    pub passphrase: SealedValue,
+    pub comments: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SshKeyEntryView {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub private_key: String,
+    pub public_key: String,
+    pub passphrase: String,
+    pub comments: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewSshKeySecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub private_key: String,
+    pub public_key: Option<String>,
+    pub passphrase: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl SshKeyEntry {
+    pub fn seal(
+        input: NewSshKeySecret,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            folder_id: input.folder_id,
+            name: input.name,
+            private_key: SealedValue::seal_string(input.private_key, key, cipher)?,
+            public_key: SealedValue::seal_string(
+                input.public_key.unwrap_or_default(),
+                key,
+                cipher,
+            )?,
+            passphrase: SealedValue::seal_string(
+                input.passphrase.unwrap_or_default(),
+                key,
+                cipher,
+            )?,
+            comments: SealedValue::seal_string(input.comments.unwrap_or_default(), key, cipher)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn reveal(&self, key: &DerivedKey, cipher: EncryptionAlgorithm) -> Result<SshKeyEntryView> {
+        Ok(SshKeyEntryView {
+            id: self.id,
+            folder_id: self.folder_id,
+            name: self.name.clone(),
+            private_key: self.private_key.reveal_string(key, cipher)?,
+            public_key: self.public_key.reveal_string(key, cipher)?,
+            passphrase: self.passphrase.reveal_string(key, cipher)?,
+            comments: self.comments.reveal_string(key, cipher)?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.private_key = self.private_key.reseal(current, next, cipher)?;
+        self.public_key = self.public_key.reseal(current, next, cipher)?;
+        self.passphrase = self.passphrase.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, update: SshKeyEntryPatch) -> Result {
+        if let Some(folder_id) = update.folder_id {
+            self.folder_id = folder_id;
+        }
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(private_key) = update.private_key {
+            self.private_key = private_key;
+        }
+        if let Some(public_key) = update.public_key {
+            self.public_key = public_key;
+        }
+        if let Some(passphrase) = update.passphrase {
+            self.passphrase = passphrase;
+        }
+        if let Some(comments) = update.comments {
+            self.comments = comments;
+        }
+
+        self.updated_at = update.updated_at;
+
+        Ok(())
+    }
+}
+
+impl Zeroize for SshKeyEntry {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.private_key.zeroize();
+        self.public_key.zeroize();
+        self.passphrase.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for SshKeyEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for SshKeyEntryView {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.private_key.zeroize();
+        self.public_key.zeroize();
+        self.passphrase.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for SshKeyEntryView {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
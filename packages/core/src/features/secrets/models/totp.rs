@@ -1,9 +1,12 @@
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::num::{NonZeroU8, NonZeroU64};
+use strum_macros::{Display, EnumString};
 use validator::Validate;
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::hash::{HmacSha1, HmacSha256, HmacSha512};
 use openvault_crypto::keys::derived_key::DerivedKey;
 
 use super::super::error::{Result, SecretError};
@@ -11,6 +14,31 @@ use super::sealed_value::SealedValue;
 
 const DEFAULT_PERIOD: NonZeroU64 = NonZeroU64::new(30).unwrap();
 const DEFAULT_DIGITS: NonZeroU8 = NonZeroU8::new(6).unwrap();
+const MAX_DIGITS: u8 = 10;
+
+#[derive(
+    Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Display, EnumString,
+)]
+#[strum(serialize_all = "UPPERCASE", ascii_case_insensitive)]
+pub enum TotpAlgorithm {
+    #[default]
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl TotpAlgorithm {
+    fn mac(self, key: &[u8], data: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        let mac = match self {
+            Self::Sha1 => HmacSha1::mac(key, data).map(Vec::from),
+            Self::Sha256 => HmacSha256::mac(key, data).map(Vec::from),
+            Self::Sha512 => HmacSha512::mac(key, data).map(Vec::from),
+        };
+
+        mac.map(Zeroizing::new)
+            .map_err(|e| SecretError::CryptoError(e.to_string()))
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate, Zeroize, ZeroizeOnDrop)]
 pub struct TOTP {
@@ -18,6 +46,15 @@ pub struct TOTP {
     pub secret: String,
     pub period: NonZeroU64,
     pub digits: NonZeroU8,
+    #[zeroize(skip)]
+    pub algorithm: TotpAlgorithm,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct TotpCode {
+    pub code: String,
+    pub period: u64,
+    pub remaining_seconds: u64,
 }
 
 impl TOTP {
@@ -39,6 +76,7 @@ impl TOTP {
             secret,
             period,
             digits,
+            algorithm: TotpAlgorithm::default(),
         };
 
         totp.validate()
@@ -46,6 +84,31 @@ impl TOTP {
 
         Ok(totp)
     }
+
+    pub fn with_algorithm(mut self, algorithm: TotpAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    pub fn current_code(&self) -> Result<TotpCode> {
+        let now = u64::try_from(Utc::now().timestamp()).map_err(|_| {
+            SecretError::InvalidInput("System clock is before the Unix epoch".to_string())
+        })?;
+
+        self.code_at(now)
+    }
+
+    // RFC 6238: the HOTP counter is the number of whole periods since the Unix epoch.
+    pub fn code_at(&self, unix_time: u64) -> Result<TotpCode> {
+        let period = self.period.get();
+        let key = decode_base32(&self.secret)?;
+
+        Ok(TotpCode {
+            code: hotp(&key, unix_time / period, self.digits.get(), self.algorithm)?,
+            period,
+            remaining_seconds: period - unix_time % period,
+        })
+    }
 }
 
 impl Default for TOTP {
@@ -54,6 +117,7 @@ impl Default for TOTP {
             secret: String::new(),
             period: DEFAULT_PERIOD,
             digits: DEFAULT_DIGITS,
+            algorithm: TotpAlgorithm::default(),
         }
     }
 }
@@ -63,6 +127,8 @@ pub struct EncryptedTotp {
     pub secret: SealedValue,
     pub period: NonZeroU64,
     pub digits: NonZeroU8,
+    #[zeroize(skip)]
+    pub algorithm: TotpAlgorithm,
 }
 
 impl EncryptedTotp {
@@ -71,11 +137,83 @@ impl EncryptedTotp {
             secret: SealedValue::seal_string(&totp.secret, key, cipher)?,
             period: totp.period,
             digits: totp.digits,
+            algorithm: totp.algorithm,
         })
     }
 
     pub fn reveal(&self, key: &DerivedKey, cipher: EncryptionAlgorithm) -> Result<TOTP> {
         let secret = self.secret.reveal_string(key, cipher)?;
         TOTP::new(secret, Some(self.period.get()), Some(self.digits.get()))
+            .map(|totp| totp.with_algorithm(self.algorithm))
     }
+
+    pub fn reseal(
+        &self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            secret: self.secret.reseal(current, next, cipher)?,
+            period: self.period,
+            digits: self.digits,
+            algorithm: self.algorithm,
+        })
+    }
+}
+
+// RFC 4226 section 5.3: dynamic truncation of the HMAC over the big-endian counter.
+fn hotp(key: &[u8], counter: u64, digits: u8, algorithm: TotpAlgorithm) -> Result<String> {
+    if digits > MAX_DIGITS {
+        return Err(SecretError::InvalidInput(format!(
+            "TOTP digits must be at most {MAX_DIGITS}"
+        )));
+    }
+
+    let mac = algorithm.mac(key, &counter.to_be_bytes())?;
+    let offset = usize::from(mac[mac.len() - 1] & 0x0f);
+    let binary = u32::from_be_bytes([
+        mac[offset],
+        mac[offset + 1],
+        mac[offset + 2],
+        mac[offset + 3],
+    ]) & 0x7fff_ffff;
+
+    let code = u64::from(binary) % 10u64.pow(u32::from(digits));
+    Ok(format!("{code:0width$}", width = usize::from(digits)))
+}
+
+// RFC 4648 base32, lenient about case, whitespace and padding as authenticator apps are.
+pub(super) fn decode_base32(input: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let mut bytes = Zeroizing::new(Vec::with_capacity(input.len() * 5 / 8));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for c in input.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = match c.to_ascii_uppercase() {
+            upper @ 'A'..='Z' => upper as u32 - 'A' as u32,
+            digit @ '2'..='7' => digit as u32 - '2' as u32 + 26,
+            _ => {
+                return Err(SecretError::InvalidInput(
+                    "TOTP secret is not valid base32".to_string(),
+                ));
+            }
+        };
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    buffer.zeroize();
+
+    if bytes.is_empty() {
+        return Err(SecretError::InvalidInput(
+            "TOTP secret is empty".to_string(),
+        ));
+    }
+
+    Ok(bytes)
 }
@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use zeroize::Zeroize;
+
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::super::error::Result;
+use super::super::patch::WifiNetworkEntryPatch;
+use super::SealedValue;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WifiSecurity {
+    Open,
+    Wep,
+    #[default]
+    WpaPersonal,
+    Wpa3Personal,
+    Enterprise,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct WifiNetworkEntry {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    #[validate(length(min = 1, max = 255))]
+    #[validate(custom(function = "super::super::validate::validate_safe_name"))]
+    pub name: String,
+    pub ssid: SealedValue,
+    pub password: SealedValue,
+    pub security: WifiSecurity,
+    pub comments: SealedValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WifiNetworkEntryView {
+    pub id: Uuid,
+    pub folder_id: Uuid,
+    pub name: String,
+    pub ssid: String,
+    pub password: String,
+    pub security: WifiSecurity,
+    pub comments: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewWifiNetworkSecret {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub ssid: String,
+    pub password: Option<String>,
+    pub security: WifiSecurity,
+    pub comments: Option<String>,
+}
+
+impl WifiNetworkEntry {
+    pub fn seal(
+        input: NewWifiNetworkSecret,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            folder_id: input.folder_id,
+            name: input.name,
+            ssid: SealedValue::seal_string(input.ssid, key, cipher)?,
+            password: SealedValue::seal_string(input.password.unwrap_or_default(), key, cipher)?,
+            security: input.security,
+            comments: SealedValue::seal_string(input.comments.unwrap_or_default(), key, cipher)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn reveal(
+        &self,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<WifiNetworkEntryView> {
+        Ok(WifiNetworkEntryView {
+            id: self.id,
+            folder_id: self.folder_id,
+            name: self.name.clone(),
+            ssid: self.ssid.reveal_string(key, cipher)?,
+            password: self.password.reveal_string(key, cipher)?,
+            security: self.security,
+            comments: self.comments.reveal_string(key, cipher)?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        self.ssid = self.ssid.reseal(current, next, cipher)?;
+        self.password = self.password.reseal(current, next, cipher)?;
+        self.comments = self.comments.reseal(current, next, cipher)?;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, update: WifiNetworkEntryPatch) -> Result {
+        if let Some(folder_id) = update.folder_id {
+            self.folder_id = folder_id;
+        }
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(ssid) = update.ssid {
+            self.ssid = ssid;
+        }
+        if let Some(password) = update.password {
+            self.password = password;
+        }
+        if let Some(security) = update.security {
+            self.security = security;
+        }
+        if let Some(comments) = update.comments {
+            self.comments = comments;
+        }
+
+        self.updated_at = update.updated_at;
+
+        Ok(())
+    }
+}
+
+impl Zeroize for WifiNetworkEntry {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.ssid.zeroize();
+        self.password.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for WifiNetworkEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for WifiNetworkEntryView {
+    fn zeroize(&mut self) {
+        self.name.zeroize();
+        self.ssid.zeroize();
+        self.password.zeroize();
+        self.comments.zeroize();
+        self.created_at = DateTime::default();
+        self.updated_at = DateTime::default();
+    }
+}
+
+impl Drop for WifiNetworkEntryView {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
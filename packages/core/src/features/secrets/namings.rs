@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+use super::error::{Result, SecretError};
+use super::indexes::SecretIndex;
+
+const MAX_NAME_ATTEMPTS: u32 = 1000;
+
+pub fn generate_name(index: &SecretIndex, parent_id: Uuid, name: &str) -> Result<String> {
+    if index
+        .ensure_entry_name_available(parent_id, name, None)
+        .is_ok()
+    {
+        return Ok(name.to_string());
+    }
+
+    for i in 1..MAX_NAME_ATTEMPTS {
+        let candidate = format!("{name} ({i})");
+
+        if index
+            .ensure_entry_name_available(parent_id, &candidate, None)
+            .is_ok()
+        {
+            return Ok(candidate);
+        }
+    }
+
+    Err(SecretError::name_exhausted(parent_id, name))
+}
@@ -7,71 +7,7 @@ use openvault_crypto::encryption::EncryptionAlgorithm;
 use openvault_crypto::keys::derived_key::DerivedKey;
 
 use super::error::Result;
-use super::models::{EncryptedTotp, SealedValue, TOTP};
-
-// #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-// pub struct ApiKeyEntryPatch {
-//     pub folder_id: Option<Uuid>,
-//     pub name: Option<String>,
-//     pub api_key: Option<EncryptedField>,
-//     pub website: Option<EncryptedField>,
-//     pub comments: Option<EncryptedField>,
-//     pub updated_at: DateTime<Utc>,
-// }
-
-// impl Default for ApiKeyEntryPatch {
-//     fn default() -> Self {
-//         Self {
-//             folder_id: None,
-//             name: None,
-//             api_key: None,
-//             website: None,
-//             comments: None,
-//             updated_at: Utc::now(),
-//         }
-//     }
-// }
-
-// impl ApiKeyEntryPatch {
-//     pub fn rename(name: impl Into<String>) -> Self {
-//         Self {
-//             name: Some(name.into()),
-//             ..Default::default()
-//         }
-//     }
-
-//     pub fn move_to(folder_id: Uuid) -> Self {
-//         Self {
-//             folder_id: Some(folder_id),
-//             ..Default::default()
-//         }
-//     }
-
-//     pub fn from_plaintext(
-//         folder_id: Option<Uuid>,
-//         name: Option<String>,
-//         api_key: Option<String>,
-//         website: Option<String>,
-//         comments: Option<String>,
-//         key: &DerivedKey,
-//         cipher: EncryptionAlgorithm,
-//     ) -> Result<Self> {
-//         Ok(Self {
-//             folder_id,
-//             name,
-//             api_key: api_key
-//                 .map(|value| EncryptedField::seal_string(value, key, cipher))
-//                 .transpose()?,
-//             website: website
-//                 .map(|value| EncryptedField::seal_string(value, key, cipher))
-//                 .transpose()?,
-//             comments: comments
-//                 .map(|value| EncryptedField::seal_string(value, key, cipher))
-//                 .transpose()?,
-//             updated_at: Utc::now(),
-//         })
-//     }
-// }
+use super::models::{CustomField, EncryptedTotp, SealedValue, SecretKind, TOTP, WifiSecurity};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct LoginEntryPatch {
@@ -160,6 +96,625 @@ impl LoginEntryPatch {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApiKeyEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub api_key: Option<SealedValue>,
+    pub website: Option<SealedValue>,
+    pub comments: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for ApiKeyEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            api_key: None,
+            website: None,
+            comments: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewApiKeySecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub api_key: Option<String>,
+    pub website: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl ApiKeyEntryPatch {
+    pub fn rename(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewApiKeySecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            api_key: input
+                .api_key
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            website: input
+                .website
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            comments: input
+                .comments
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SecureNoteEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub content: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for SecureNoteEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            content: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewSecureNoteSecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub content: Option<String>,
+}
+
+impl SecureNoteEntryPatch {
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewSecureNoteSecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            content: input
+                .content
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaymentCardEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub cardholder_name: Option<SealedValue>,
+    pub number: Option<SealedValue>,
+    pub expiry: Option<SealedValue>,
+    pub security_code: Option<SealedValue>,
+    pub comments: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for PaymentCardEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            cardholder_name: None,
+            number: None,
+            expiry: None,
+            security_code: None,
+            comments: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewPaymentCardSecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub cardholder_name: Option<String>,
+    pub number: Option<String>,
+    pub expiry: Option<String>,
+    pub security_code: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl PaymentCardEntryPatch {
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewPaymentCardSecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            cardholder_name: input
+                .cardholder_name
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            number: input
+                .number
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            expiry: input
+                .expiry
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            security_code: input
+                .security_code
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            comments: input
+                .comments
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SshKeyEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub private_key: Option<SealedValue>,
+    pub public_key: Option<SealedValue>,
+    pub passphrase: Option<SealedValue>,
+    pub comments: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for SshKeyEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            private_key: None,
+            public_key: None,
+            passphrase: None,
+            comments: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewSshKeySecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub private_key: Option<String>,
+    pub public_key: Option<String>,
+    pub passphrase: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl SshKeyEntryPatch {
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewSshKeySecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            private_key: input
+                .private_key
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            public_key: input
+                .public_key
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            passphrase: input
+                .passphrase
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            comments: input
+                .comments
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IdentityEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub full_name: Option<SealedValue>,
+    pub email: Option<SealedValue>,
+    pub phone: Option<SealedValue>,
+    pub address: Option<SealedValue>,
+    pub document_number: Option<SealedValue>,
+    pub comments: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for IdentityEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            full_name: None,
+            email: None,
+            phone: None,
+            address: None,
+            document_number: None,
+            comments: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewIdentitySecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub full_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub document_number: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl IdentityEntryPatch {
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewIdentitySecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            full_name: input
+                .full_name
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            email: input
+                .email
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            phone: input
+                .phone
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            address: input
+                .address
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            document_number: input
+                .document_number
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            comments: input
+                .comments
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WifiNetworkEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub ssid: Option<SealedValue>,
+    pub password: Option<SealedValue>,
+    pub security: Option<WifiSecurity>,
+    pub comments: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for WifiNetworkEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            ssid: None,
+            password: None,
+            security: None,
+            comments: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewWifiNetworkSecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub ssid: Option<String>,
+    pub password: Option<String>,
+    pub security: Option<WifiSecurity>,
+    pub comments: Option<String>,
+}
+
+impl WifiNetworkEntryPatch {
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewWifiNetworkSecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            ssid: input
+                .ssid
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            password: input
+                .password
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            security: input.security,
+            comments: input
+                .comments
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CustomEntryPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub fields: Option<SealedValue>,
+    pub comments: Option<SealedValue>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for CustomEntryPatch {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            name: None,
+            fields: None,
+            comments: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewCustomSecretPatch {
+    pub folder_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub fields: Option<Vec<CustomField>>,
+    pub comments: Option<String>,
+}
+
+impl CustomEntryPatch {
+    pub fn move_to(folder_id: Uuid) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewCustomSecretPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(Self {
+            folder_id: input.folder_id,
+            name: input.name,
+            fields: input
+                .fields
+                .map(|value| SealedValue::seal_value(&value, key, cipher))
+                .transpose()?,
+            comments: input
+                .comments
+                .map(|value| SealedValue::seal_string(value, key, cipher))
+                .transpose()?,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SecretEntryPatch {
+    Login(LoginEntryPatch),
+    ApiKey(ApiKeyEntryPatch),
+    SecureNote(SecureNoteEntryPatch),
+    PaymentCard(PaymentCardEntryPatch),
+    SshKey(SshKeyEntryPatch),
+    Identity(IdentityEntryPatch),
+    WifiNetwork(WifiNetworkEntryPatch),
+    Custom(CustomEntryPatch),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NewSecretEntryPatch {
+    Login(NewLoginSecretPatch),
+    ApiKey(NewApiKeySecretPatch),
+    SecureNote(NewSecureNoteSecretPatch),
+    PaymentCard(NewPaymentCardSecretPatch),
+    SshKey(NewSshKeySecretPatch),
+    Identity(NewIdentitySecretPatch),
+    WifiNetwork(NewWifiNetworkSecretPatch),
+    Custom(NewCustomSecretPatch),
+}
+
+impl SecretEntryPatch {
+    pub fn move_to(kind: SecretKind, folder_id: Uuid) -> Self {
+        match kind {
+            SecretKind::Login => Self::Login(LoginEntryPatch::move_to(folder_id)),
+            SecretKind::ApiKey => Self::ApiKey(ApiKeyEntryPatch::move_to(folder_id)),
+            SecretKind::SecureNote => Self::SecureNote(SecureNoteEntryPatch::move_to(folder_id)),
+            SecretKind::PaymentCard => Self::PaymentCard(PaymentCardEntryPatch::move_to(folder_id)),
+            SecretKind::SshKey => Self::SshKey(SshKeyEntryPatch::move_to(folder_id)),
+            SecretKind::Identity => Self::Identity(IdentityEntryPatch::move_to(folder_id)),
+            SecretKind::WifiNetwork => Self::WifiNetwork(WifiNetworkEntryPatch::move_to(folder_id)),
+            SecretKind::Custom => Self::Custom(CustomEntryPatch::move_to(folder_id)),
+        }
+    }
+
+    pub fn from_plaintext(
+        input: NewSecretEntryPatch,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        Ok(match input {
+            NewSecretEntryPatch::Login(input) => {
+                Self::Login(LoginEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::ApiKey(input) => {
+                Self::ApiKey(ApiKeyEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::SecureNote(input) => {
+                Self::SecureNote(SecureNoteEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::PaymentCard(input) => {
+                Self::PaymentCard(PaymentCardEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::SshKey(input) => {
+                Self::SshKey(SshKeyEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::Identity(input) => {
+                Self::Identity(IdentityEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::WifiNetwork(input) => {
+                Self::WifiNetwork(WifiNetworkEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+            NewSecretEntryPatch::Custom(input) => {
+                Self::Custom(CustomEntryPatch::from_plaintext(input, key, cipher)?)
+            }
+        })
+    }
+
+    pub fn kind(&self) -> SecretKind {
+        each_kind!(SecretEntryPatch, self, kind, _patch => kind)
+    }
+
+    pub fn folder_id(&self) -> Option<Uuid> {
+        each_kind!(SecretEntryPatch, self, _kind, patch => patch.folder_id)
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        each_kind!(SecretEntryPatch, self, _kind, patch => patch.name.as_deref())
+    }
+}
+
+impl From<LoginEntryPatch> for SecretEntryPatch {
+    fn from(value: LoginEntryPatch) -> Self {
+        Self::Login(value)
+    }
+}
+
+impl From<ApiKeyEntryPatch> for SecretEntryPatch {
+    fn from(value: ApiKeyEntryPatch) -> Self {
+        Self::ApiKey(value)
+    }
+}
+
+impl From<SecureNoteEntryPatch> for SecretEntryPatch {
+    fn from(value: SecureNoteEntryPatch) -> Self {
+        Self::SecureNote(value)
+    }
+}
+
+impl From<PaymentCardEntryPatch> for SecretEntryPatch {
+    fn from(value: PaymentCardEntryPatch) -> Self {
+        Self::PaymentCard(value)
+    }
+}
+
+impl From<SshKeyEntryPatch> for SecretEntryPatch {
+    fn from(value: SshKeyEntryPatch) -> Self {
+        Self::SshKey(value)
+    }
+}
+
+impl From<IdentityEntryPatch> for SecretEntryPatch {
+    fn from(value: IdentityEntryPatch) -> Self {
+        Self::Identity(value)
+    }
+}
+
+impl From<WifiNetworkEntryPatch> for SecretEntryPatch {
+    fn from(value: WifiNetworkEntryPatch) -> Self {
+        Self::WifiNetwork(value)
+    }
+}
+
+impl From<CustomEntryPatch> for SecretEntryPatch {
+    fn from(value: CustomEntryPatch) -> Self {
+        Self::Custom(value)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SecretFolderPatch {
     pub name: Option<String>,
@@ -207,22 +762,139 @@ impl Zeroize for LoginEntryPatch {
     }
 }
 
-// impl Zeroize for ApiKeyEntryPatch {
-//     fn zeroize(&mut self) {
-//         if let Some(name) = &mut self.name {
-//             name.zeroize();
-//         }
-//         if let Some(api_key) = &mut self.api_key {
-//             api_key.zeroize();
-//         }
-//         if let Some(website) = &mut self.website {
-//             website.zeroize();
-//         }
-//         if let Some(comments) = &mut self.comments {
-//             comments.zeroize();
-//         }
-//     }
-// }
+impl Zeroize for ApiKeyEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(api_key) = &mut self.api_key {
+            api_key.zeroize();
+        }
+        if let Some(website) = &mut self.website {
+            website.zeroize();
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.zeroize();
+        }
+    }
+}
+
+impl Zeroize for SecureNoteEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(content) = &mut self.content {
+            content.zeroize();
+        }
+    }
+}
+
+impl Zeroize for PaymentCardEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(cardholder_name) = &mut self.cardholder_name {
+            cardholder_name.zeroize();
+        }
+        if let Some(number) = &mut self.number {
+            number.zeroize();
+        }
+        if let Some(expiry) = &mut self.expiry {
+            expiry.zeroize();
+        }
+        if let Some(security_code) = &mut self.security_code {
+            security_code.zeroize();
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.zeroize();
+        }
+    }
+}
+
+impl Zeroize for SshKeyEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(private_key) = &mut self.private_key {
+            private_key.zeroize();
+        }
+        if let Some(public_key) = &mut self.public_key {
+            public_key.zeroize();
+        }
+        if let Some(passphrase) = &mut self.passphrase {
+            passphrase.zeroize();
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.zeroize();
+        }
+    }
+}
+
+impl Zeroize for IdentityEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(full_name) = &mut self.full_name {
+            full_name.zeroize();
+        }
+        if let Some(email) = &mut self.email {
+            email.zeroize();
+        }
+        if let Some(phone) = &mut self.phone {
+            phone.zeroize();
+        }
+        if let Some(address) = &mut self.address {
+            address.zeroize();
+        }
+        if let Some(document_number) = &mut self.document_number {
+            document_number.zeroize();
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.zeroize();
+        }
+    }
+}
+
+impl Zeroize for WifiNetworkEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(ssid) = &mut self.ssid {
+            ssid.zeroize();
+        }
+        if let Some(password) = &mut self.password {
+            password.zeroize();
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.zeroize();
+        }
+    }
+}
+
+impl Zeroize for CustomEntryPatch {
+    fn zeroize(&mut self) {
+        if let Some(name) = &mut self.name {
+            name.zeroize();
+        }
+        if let Some(fields) = &mut self.fields {
+            fields.zeroize();
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.zeroize();
+        }
+    }
+}
+
+impl Zeroize for SecretEntryPatch {
+    fn zeroize(&mut self) {
+        each_kind!(SecretEntryPatch, self, _kind, patch => patch.zeroize())
+    }
+}
 
 impl Zeroize for SecretFolderPatch {
     fn zeroize(&mut self) {
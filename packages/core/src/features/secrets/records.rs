@@ -1,24 +1,35 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use zeroize::Zeroize;
 
 use super::error::SecretError;
-use super::models::{LoginEntry, SecretFolder};
-use super::patch::{LoginEntryPatch, SecretFolderPatch};
+use super::models::{SecretEntry, SecretFolder};
+use super::patch::{SecretEntryPatch, SecretFolderPatch};
+use super::trash::SecretTrashEntry;
 
-pub const SECRETS_WIRE_VERSION: u16 = 1;
+pub const SECRETS_WIRE_VERSION: u16 = 5;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct SecretSnapshot {
     pub folders: HashMap<Uuid, SecretFolder>,
-    pub entries: HashMap<Uuid, LoginEntry>,
+    pub entries: HashMap<Uuid, SecretEntry>,
+    pub trash: HashMap<Uuid, SecretTrashEntry>,
 }
 
 impl SecretSnapshot {
-    pub fn new(folders: HashMap<Uuid, SecretFolder>, entries: HashMap<Uuid, LoginEntry>) -> Self {
-        Self { folders, entries }
+    pub fn new(
+        folders: HashMap<Uuid, SecretFolder>,
+        entries: HashMap<Uuid, SecretEntry>,
+        trash: HashMap<Uuid, SecretTrashEntry>,
+    ) -> Self {
+        Self {
+            folders,
+            entries,
+            trash,
+        }
     }
 }
 
@@ -26,10 +37,30 @@ impl SecretSnapshot {
 pub enum SecretDelta {
     FolderAdded(SecretFolder),
     FolderDeleted(Uuid),
-    FolderUpdated { id: Uuid, patch: SecretFolderPatch },
-    EntryAdded(LoginEntry),
-    EntryUpdated { id: Uuid, patch: LoginEntryPatch },
+    FolderUpdated {
+        id: Uuid,
+        patch: SecretFolderPatch,
+    },
+    EntryAdded(SecretEntry),
+    EntryUpdated {
+        id: Uuid,
+        patch: SecretEntryPatch,
+    },
     EntryDeleted(Uuid),
+    EntryTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    FolderTrashed {
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+    TrashRestored {
+        id: Uuid,
+        parent_id: Uuid,
+        name: String,
+    },
+    TrashPurged(Vec<Uuid>),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -62,6 +93,7 @@ impl Zeroize for SecretDelta {
             SecretDelta::FolderUpdated { patch, .. } => patch.zeroize(),
             SecretDelta::EntryAdded(entry) => entry.zeroize(),
             SecretDelta::EntryUpdated { patch, .. } => patch.zeroize(),
+            SecretDelta::TrashRestored { name, .. } => name.zeroize(),
             _ => {}
         }
     }
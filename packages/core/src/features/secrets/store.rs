@@ -1,21 +1,29 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, TimeDelta, Utc};
 use uuid::Uuid;
 use validator::Validate;
 use zeroize::Zeroize;
 
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+use super::audit::{AuditOptions, AuditReport, audit_logins};
 use super::error::{Result, SecretError};
 use super::indexes::SecretIndex;
-use super::models::{LoginEntry, SECRETS_ROOT_FOLDER_ID, SecretFolder};
-use super::patch::{LoginEntryPatch, SecretFolderPatch};
+use super::models::{SECRETS_ROOT_FOLDER_ID, SecretEntry, SecretFolder};
+use super::namings::generate_name;
+use super::patch::{SecretEntryPatch, SecretFolderPatch};
 use super::records::{SecretDelta, SecretSnapshot, SecretsChange};
+use super::trash::{SecretTrashEntry, SecretTrashItem};
 use super::validate;
-use crate::features::shared::DEFAULT_SNAPSHOT_THRESHOLD;
+use crate::features::shared::{DEFAULT_SNAPSHOT_THRESHOLD, TrashEntry};
 
 #[derive(Clone, Debug)]
 pub struct SecretStore {
     pub(crate) folders: HashMap<Uuid, SecretFolder>,
-    pub(crate) entries: HashMap<Uuid, LoginEntry>,
+    pub(crate) entries: HashMap<Uuid, SecretEntry>,
+    pub(crate) trash: HashMap<Uuid, SecretTrashEntry>,
     pub(crate) index: SecretIndex,
     pub(crate) deltas: Vec<SecretDelta>,
 }
@@ -35,6 +43,7 @@ impl SecretStore {
         Self {
             folders,
             entries: HashMap::new(),
+            trash: HashMap::new(),
             index: SecretIndex::new(),
             deltas: Vec::new(),
         }
@@ -46,6 +55,7 @@ impl SecretStore {
         let mut store = Self {
             folders: snapshot.folders,
             entries: snapshot.entries,
+            trash: snapshot.trash,
             index,
             deltas: Vec::new(),
         };
@@ -65,10 +75,20 @@ impl SecretStore {
         self.folders.get(id)
     }
 
-    pub fn entry(&self, id: &Uuid) -> Option<&LoginEntry> {
+    pub fn entry(&self, id: &Uuid) -> Option<&SecretEntry> {
         self.entries.get(id)
     }
 
+    pub fn trashed(&self, id: &Uuid) -> Option<&SecretTrashEntry> {
+        self.trash.get(id)
+    }
+
+    pub fn trash(&self) -> Vec<SecretTrashEntry> {
+        let mut entries: Vec<_> = self.trash.values().cloned().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.deleted_at));
+        entries
+    }
+
     pub fn folders(&self, parent_id: Uuid) -> Vec<SecretFolder> {
         let folder_ids = self.index.folders(&parent_id);
 
@@ -79,7 +99,7 @@ impl SecretStore {
             .collect()
     }
 
-    pub fn entries(&self, parent_id: Uuid) -> Vec<LoginEntry> {
+    pub fn entries(&self, parent_id: Uuid) -> Vec<SecretEntry> {
         let entry_ids = self.index.entries(&parent_id);
 
         entry_ids
@@ -89,7 +109,7 @@ impl SecretStore {
             .collect()
     }
 
-    pub fn browse(&self, parent_id: &Uuid) -> Result<(Vec<SecretFolder>, Vec<LoginEntry>)> {
+    pub fn browse(&self, parent_id: &Uuid) -> Result<(Vec<SecretFolder>, Vec<SecretEntry>)> {
         if !self.folders.contains_key(parent_id) {
             return Err(SecretError::FolderNotFound(*parent_id));
         }
@@ -97,6 +117,10 @@ impl SecretStore {
         Ok((self.folders(*parent_id), self.entries(*parent_id)))
     }
 
+    pub fn available_name(&self, parent_id: Uuid, name: &str) -> Result<String> {
+        generate_name(&self.index, parent_id, name)
+    }
+
     pub fn add_folder(&mut self, parent_id: Uuid, name: String) -> Result<Uuid> {
         let folder = SecretFolder::new(Some(parent_id), name);
         let folder_id = folder.id;
@@ -115,18 +139,26 @@ impl SecretStore {
         self.commit_delta(&SecretDelta::FolderDeleted(id))
     }
 
-    pub fn add_entry(&mut self, entry: LoginEntry) -> Result<Uuid> {
-        let id = entry.id;
+    pub fn add_entry(&mut self, entry: impl Into<SecretEntry>) -> Result<Uuid> {
+        let entry = entry.into();
+        let id = entry.id();
         self.commit_delta(&SecretDelta::EntryAdded(entry))?;
         Ok(id)
     }
 
-    pub fn update_entry(&mut self, id: Uuid, patch: LoginEntryPatch) -> Result {
+    pub fn update_entry(&mut self, id: Uuid, patch: impl Into<SecretEntryPatch>) -> Result {
+        let patch = patch.into();
         self.commit_delta(&SecretDelta::EntryUpdated { id, patch })
     }
 
     pub fn move_entry(&mut self, id: Uuid, new_folder_id: Uuid) -> Result {
-        let patch = LoginEntryPatch::move_to(new_folder_id);
+        let kind = self
+            .entries
+            .get(&id)
+            .map(SecretEntry::kind)
+            .ok_or(SecretError::EntryNotFound(id))?;
+
+        let patch = SecretEntryPatch::move_to(kind, new_folder_id);
         self.commit_delta(&SecretDelta::EntryUpdated { id, patch })
     }
 
@@ -134,8 +166,92 @@ impl SecretStore {
         self.commit_delta(&SecretDelta::EntryDeleted(id))
     }
 
+    pub fn trash_entry(&mut self, id: Uuid) -> Result {
+        let deleted_at = Utc::now();
+        self.commit_delta(&SecretDelta::EntryTrashed { id, deleted_at })
+    }
+
+    pub fn trash_folder(&mut self, id: Uuid) -> Result {
+        let deleted_at = Utc::now();
+        self.commit_delta(&SecretDelta::FolderTrashed { id, deleted_at })
+    }
+
+    pub fn restore_from_trash(&mut self, id: Uuid) -> Result {
+        let entry = self
+            .trash
+            .get(&id)
+            .ok_or(SecretError::TrashItemNotFound(id))?;
+
+        let parent_id = if self.folders.contains_key(&entry.original_parent_id) {
+            entry.original_parent_id
+        } else {
+            SECRETS_ROOT_FOLDER_ID
+        };
+        let item_name = entry.item.name().ok_or(SecretError::EmptyTrashItem(id))?;
+        let name = generate_name(&self.index, parent_id, item_name)?;
+
+        self.commit_delta(&SecretDelta::TrashRestored {
+            id,
+            parent_id,
+            name,
+        })
+    }
+
+    pub fn purge_from_trash(&mut self, id: Uuid) -> Result {
+        self.purge_trash_entries(vec![id])
+    }
+
+    pub fn empty_trash(&mut self) -> Result {
+        let ids = self.trash.keys().copied().collect();
+        self.purge_trash_entries(ids)
+    }
+
+    pub fn purge_expired_trash(&mut self, retention: TimeDelta) -> Result {
+        let now = Utc::now();
+        let ids = self
+            .trash
+            .values()
+            .filter(|entry| entry.is_expired(retention, now))
+            .map(|entry| entry.id)
+            .collect();
+
+        self.purge_trash_entries(ids)
+    }
+
+    pub fn reseal(
+        &mut self,
+        current: &DerivedKey,
+        next: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+    ) -> Result {
+        let trashed = self
+            .trash
+            .values_mut()
+            .flat_map(|entry| entry.item.entries_mut());
+
+        for entry in self.entries.values_mut().chain(trashed) {
+            entry.reseal(current, next, cipher)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn audit(
+        &self,
+        key: &DerivedKey,
+        cipher: EncryptionAlgorithm,
+        options: &AuditOptions,
+    ) -> Result<AuditReport> {
+        let logins = self.entries.values().filter_map(SecretEntry::as_login);
+        audit_logins(logins, key, cipher, options)
+    }
+
     pub fn snapshot(&self) -> SecretSnapshot {
-        SecretSnapshot::new(self.folders.clone(), self.entries.clone())
+        SecretSnapshot::new(
+            self.folders.clone(),
+            self.entries.clone(),
+            self.trash.clone(),
+        )
     }
 
     pub fn pending_changes(&self) -> Option<SecretsChange> {
@@ -171,10 +287,46 @@ impl SecretStore {
     fn replace_snapshot(&mut self, snapshot: SecretSnapshot) -> Result {
         self.folders = snapshot.folders;
         self.entries = snapshot.entries;
+        self.trash = snapshot.trash;
         self.index = SecretIndex::build(&self.folders, &self.entries)?;
         Ok(())
     }
 
+    fn purge_trash_entries(&mut self, ids: Vec<Uuid>) -> Result {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        self.commit_delta(&SecretDelta::TrashPurged(ids))
+    }
+
+    fn collect_subtree(
+        &self,
+        id: Uuid,
+        folders: &mut Vec<SecretFolder>,
+        entries: &mut Vec<SecretEntry>,
+    ) -> Result {
+        let folder = self
+            .folders
+            .get(&id)
+            .ok_or(SecretError::FolderNotFound(id))?;
+        folders.push(folder.clone());
+
+        entries.extend(
+            self.index
+                .entries(&id)
+                .iter()
+                .filter_map(|entry_id| self.entries.get(entry_id))
+                .cloned(),
+        );
+
+        for subfolder_id in self.index.folders(&id) {
+            self.collect_subtree(*subfolder_id, folders, entries)?;
+        }
+
+        Ok(())
+    }
+
     fn commit_delta(&mut self, delta: &SecretDelta) -> Result {
         self.apply_delta(delta, true)
     }
@@ -191,6 +343,18 @@ impl SecretStore {
             SecretDelta::EntryAdded(entry) => self.apply_entry_added(entry.clone()),
             SecretDelta::EntryUpdated { id, patch } => self.apply_entry_updated(*id, patch),
             SecretDelta::EntryDeleted(id) => self.apply_entry_deleted(*id),
+            SecretDelta::EntryTrashed { id, deleted_at } => {
+                self.apply_entry_trashed(*id, *deleted_at)
+            }
+            SecretDelta::FolderTrashed { id, deleted_at } => {
+                self.apply_folder_trashed(*id, *deleted_at)
+            }
+            SecretDelta::TrashRestored {
+                id,
+                parent_id,
+                name,
+            } => self.apply_trash_restored(*id, *parent_id, name),
+            SecretDelta::TrashPurged(ids) => self.apply_trash_purged(ids),
         }?;
 
         if track_delta {
@@ -299,40 +463,46 @@ impl SecretStore {
         Ok(())
     }
 
-    fn apply_entry_added(&mut self, entry: LoginEntry) -> Result {
-        if self.entries.contains_key(&entry.id) || self.folders.contains_key(&entry.id) {
-            return Err(SecretError::DuplicateId(entry.id));
+    fn apply_entry_added(&mut self, entry: SecretEntry) -> Result {
+        let id = entry.id();
+
+        if self.entries.contains_key(&id) || self.folders.contains_key(&id) {
+            return Err(SecretError::DuplicateId(id));
         }
 
-        if !self.folders.contains_key(&entry.folder_id) {
-            return Err(SecretError::ParentFolderNotFound(entry.folder_id));
+        if !self.folders.contains_key(&entry.folder_id()) {
+            return Err(SecretError::ParentFolderNotFound(entry.folder_id()));
         }
 
         self.index
-            .ensure_entry_name_available(entry.folder_id, &entry.name, None)?;
+            .ensure_entry_name_available(entry.folder_id(), entry.name(), None)?;
 
         entry.validate()?;
 
         self.index.track_entry(&entry)?;
-        self.entries.insert(entry.id, entry);
+        self.entries.insert(id, entry);
 
         Ok(())
     }
 
-    fn apply_entry_updated(&mut self, id: Uuid, patch: &LoginEntryPatch) -> Result {
+    fn apply_entry_updated(&mut self, id: Uuid, patch: &SecretEntryPatch) -> Result {
         let current = self
             .entries
             .get(&id)
             .ok_or(SecretError::EntryNotFound(id))?;
 
-        let target_folder = patch.folder_id.unwrap_or(current.folder_id);
-        let target_name = patch.name.clone().unwrap_or(current.name.clone());
+        if current.kind() != patch.kind() {
+            return Err(SecretError::EntryKindMismatch(id, patch.kind()));
+        }
+
+        let target_folder = patch.folder_id().unwrap_or(current.folder_id());
+        let target_name = patch.name().unwrap_or(current.name()).to_string();
 
         if !self.folders.contains_key(&target_folder) {
             return Err(SecretError::ParentFolderNotFound(target_folder));
         }
 
-        if target_folder != current.folder_id || target_name != current.name {
+        if target_folder != current.folder_id() || target_name != current.name() {
             self.index
                 .ensure_entry_name_available(target_folder, &target_name, Some(id))?;
         }
@@ -346,7 +516,7 @@ impl SecretStore {
         entry.apply_patch(patch.clone())?;
         entry.validate()?;
 
-        if target_folder != old_entry.folder_id || target_name != old_entry.name {
+        if target_folder != old_entry.folder_id() || target_name != old_entry.name() {
             self.index.untrack_entry(&old_entry);
             self.index.track_entry(entry)?;
         }
@@ -364,6 +534,118 @@ impl SecretStore {
 
         Ok(())
     }
+
+    fn apply_entry_trashed(&mut self, id: Uuid, deleted_at: DateTime<Utc>) -> Result {
+        let entry = self
+            .entries
+            .get(&id)
+            .cloned()
+            .ok_or(SecretError::EntryNotFound(id))?;
+
+        self.apply_entry_deleted(id)?;
+
+        let parent_id = entry.folder_id();
+        let item = SecretTrashItem::Entry(entry);
+        self.trash
+            .insert(id, TrashEntry::new(id, parent_id, deleted_at, item));
+
+        Ok(())
+    }
+
+    fn apply_folder_trashed(&mut self, id: Uuid, deleted_at: DateTime<Utc>) -> Result {
+        if id == SECRETS_ROOT_FOLDER_ID {
+            return Err(SecretError::CannotDeleteRootFolder);
+        }
+
+        let mut folders = Vec::new();
+        let mut entries = Vec::new();
+        self.collect_subtree(id, &mut folders, &mut entries)?;
+
+        let parent_id = folders[0]
+            .parent_id
+            .ok_or(SecretError::FolderMissingParent(id))?;
+
+        self.apply_folder_deleted(id)?;
+
+        let item = SecretTrashItem::Folder { folders, entries };
+        self.trash
+            .insert(id, TrashEntry::new(id, parent_id, deleted_at, item));
+
+        Ok(())
+    }
+
+    fn apply_trash_restored(&mut self, id: Uuid, parent_id: Uuid, name: &str) -> Result {
+        let mut item = self
+            .trash
+            .get(&id)
+            .ok_or(SecretError::TrashItemNotFound(id))?
+            .item
+            .clone();
+
+        match &mut item {
+            SecretTrashItem::Entry(entry) => entry.relocate(parent_id, name),
+            SecretTrashItem::Folder { folders, .. } => {
+                let root = folders.first_mut().ok_or(SecretError::EmptyTrashItem(id))?;
+                root.parent_id = Some(parent_id);
+                root.name = name.to_string();
+            }
+        }
+
+        // The trash entry is only dropped once every item is back in place.
+        self.restore_trash_item(item)?;
+        self.trash.remove(&id);
+
+        Ok(())
+    }
+
+    fn restore_trash_item(&mut self, item: SecretTrashItem) -> Result {
+        let (folders, entries) = match item {
+            SecretTrashItem::Entry(entry) => return self.apply_entry_added(entry),
+            SecretTrashItem::Folder { folders, entries } => (folders, entries),
+        };
+
+        let mut restored_folders = Vec::with_capacity(folders.len());
+        let mut restored_entries = Vec::with_capacity(entries.len());
+
+        let result = folders
+            .into_iter()
+            .try_for_each(|folder| {
+                let id = folder.id;
+                self.apply_folder_added(folder)?;
+                restored_folders.push(id);
+                Ok(())
+            })
+            .and_then(|()| {
+                entries.into_iter().try_for_each(|entry| {
+                    let id = entry.id();
+                    self.apply_entry_added(entry)?;
+                    restored_entries.push(id);
+                    Ok(())
+                })
+            });
+
+        if result.is_err() {
+            // Undo in reverse so every folder is empty by the time it is removed.
+            for id in restored_entries.into_iter().rev() {
+                let _ = self.apply_entry_deleted(id);
+            }
+            for id in restored_folders.into_iter().rev() {
+                let _ = self.apply_folder_deleted(id);
+            }
+        }
+
+        result
+    }
+
+    fn apply_trash_purged(&mut self, ids: &[Uuid]) -> Result {
+        for id in ids {
+            self.trash
+                .remove(id)
+                .ok_or(SecretError::TrashItemNotFound(*id))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Zeroize for SecretStore {
@@ -378,6 +660,11 @@ impl Zeroize for SecretStore {
         }
         self.entries.clear();
 
+        for entry in self.trash.values_mut() {
+            entry.item.zeroize();
+        }
+        self.trash.clear();
+
         for delta in &mut self.deltas {
             delta.zeroize();
         }
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use super::models::{SecretEntry, SecretFolder};
+use crate::features::shared::TrashEntry;
+
+pub type SecretTrashEntry = TrashEntry<SecretTrashItem>;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SecretTrashItem {
+    Entry(SecretEntry),
+    Folder {
+        folders: Vec<SecretFolder>,
+        entries: Vec<SecretEntry>,
+    },
+}
+
+impl SecretTrashItem {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Entry(entry) => Some(entry.name()),
+            Self::Folder { folders, .. } => folders.first().map(|folder| folder.name.as_str()),
+        }
+    }
+
+    pub fn entries_mut(&mut self) -> &mut [SecretEntry] {
+        match self {
+            Self::Entry(entry) => std::slice::from_mut(entry),
+            Self::Folder { entries, .. } => entries,
+        }
+    }
+}
+
+impl Zeroize for SecretTrashItem {
+    fn zeroize(&mut self) {
+        match self {
+            Self::Entry(entry) => entry.zeroize(),
+            Self::Folder { folders, entries } => {
+                folders.iter_mut().for_each(Zeroize::zeroize);
+                entries.iter_mut().for_each(Zeroize::zeroize);
+            }
+        }
+    }
+}
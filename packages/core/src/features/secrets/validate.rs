@@ -3,11 +3,11 @@ use uuid::Uuid;
 use validator::ValidationError;
 
 use super::error::{Result, SecretError};
-use super::models::{LoginEntry, SECRETS_ROOT_FOLDER_ID, SECRETS_ROOT_FOLDER_NAME, SecretFolder};
+use super::models::{SECRETS_ROOT_FOLDER_ID, SECRETS_ROOT_FOLDER_NAME, SecretEntry, SecretFolder};
 
 pub fn validate_snapshot(
     folders: &HashMap<Uuid, SecretFolder>,
-    entries: &HashMap<Uuid, LoginEntry>,
+    entries: &HashMap<Uuid, SecretEntry>,
 ) -> Result {
     validate_root(folders)?;
 
@@ -26,7 +26,7 @@ pub fn validate_snapshot(
 
 fn validate_unique_names(
     folders: &HashMap<Uuid, SecretFolder>,
-    entries: &HashMap<Uuid, LoginEntry>,
+    entries: &HashMap<Uuid, SecretEntry>,
 ) -> Result {
     let mut occupied = HashSet::<(Uuid, &str)>::new();
 
@@ -43,11 +43,8 @@ fn validate_unique_names(
     }
 
     for entry in entries.values() {
-        if !occupied.insert((entry.folder_id, &entry.name)) {
-            return Err(SecretError::name_conflict(
-                entry.folder_id,
-                entry.name.as_str(),
-            ));
+        if !occupied.insert((entry.folder_id(), entry.name())) {
+            return Err(SecretError::name_conflict(entry.folder_id(), entry.name()));
         }
     }
 
@@ -107,9 +104,9 @@ fn validate_folder_invariants(
     Ok(())
 }
 
-fn validate_entry_invariants(entry: &LoginEntry, folders: &HashMap<Uuid, SecretFolder>) -> Result {
-    if !folders.contains_key(&entry.folder_id) {
-        return Err(SecretError::ParentFolderNotFound(entry.folder_id));
+fn validate_entry_invariants(entry: &SecretEntry, folders: &HashMap<Uuid, SecretFolder>) -> Result {
+    if !folders.contains_key(&entry.folder_id()) {
+        return Err(SecretError::ParentFolderNotFound(entry.folder_id()));
     }
 
     Ok(())
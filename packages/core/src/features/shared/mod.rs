@@ -1,7 +1,9 @@
 pub mod blob_ref;
 pub mod codec;
+pub mod trash;
 
 pub use blob_ref::BlobRef;
 pub use codec::FeatureCodec;
+pub use trash::{DEFAULT_TRASH_RETENTION, TrashEntry};
 
 pub const DEFAULT_SNAPSHOT_THRESHOLD: usize = 64;
@@ -0,0 +1,28 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const DEFAULT_TRASH_RETENTION: TimeDelta = TimeDelta::days(30);
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrashEntry<T> {
+    pub id: Uuid,
+    pub original_parent_id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+    pub item: T,
+}
+
+impl<T> TrashEntry<T> {
+    pub fn new(id: Uuid, original_parent_id: Uuid, deleted_at: DateTime<Utc>, item: T) -> Self {
+        Self {
+            id,
+            original_parent_id,
+            deleted_at,
+            item,
+        }
+    }
+
+    pub fn is_expired(&self, retention: TimeDelta, now: DateTime<Utc>) -> bool {
+        self.deleted_at + retention <= now
+    }
+}
@@ -31,6 +31,23 @@ pub fn remove_if_exists(path: &Path) -> Result {
     Ok(())
 }
 
+pub fn sync_parent_dir(path: &Path) -> Result {
+    #[cfg(unix)]
+    {
+        let parent = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+
+        File::open(parent)?.sync_all()?;
+    }
+
+    #[cfg(not(unix))]
+    let _ = path;
+
+    Ok(())
+}
+
 pub fn resolve_path(path: &Path, filename: &str, extension: &str) -> std::path::PathBuf {
     let mut resolved = path.to_path_buf();
 
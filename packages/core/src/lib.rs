@@ -1,3 +1,4 @@
+pub mod archive;
 pub mod errors;
 pub mod features;
 pub mod internal;
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use uuid::Uuid;
+
+use crate::archive::{
+    ArchiveApiKey, ArchiveCredential, ArchiveCustom, ArchiveFile, ArchiveFolder, ArchiveIdentity,
+    ArchiveLogin, ArchiveManifest, ArchivePaymentCard, ArchiveReader, ArchiveRecipient,
+    ArchiveSecret, ArchiveSecureNote, ArchiveSshKey, ArchiveWifiNetwork, ArchiveWriter,
+};
+use crate::errors::{Error, Result};
+use crate::features::chunks::ChunksStore;
+use crate::features::filesystem::{FILESYSTEM_ROOT_FOLDER_ID, FilesystemError, FilesystemStore};
+use crate::features::secrets::{
+    NewApiKeySecret, NewCustomSecret, NewIdentitySecret, NewLoginSecret, NewPaymentCardSecret,
+    NewSecretEntry, NewSecureNoteSecret, NewSshKeySecret, NewWifiNetworkSecret, SECRETS_FEATURE_ID,
+    SECRETS_ROOT_FOLDER_ID, SecretEntry, SecretEntryView, SecretError, SecretStore,
+};
+use crate::features::shared::BlobRef;
+use crate::operations::blob::{open_blob, write_blob};
+use crate::vault::runtime::VaultSession;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchiveSelection {
+    pub filesystem_folder: Option<Uuid>,
+    pub secrets_folder: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveDestination {
+    pub filesystem_folder: Uuid,
+    pub secrets_folder: Uuid,
+}
+
+impl Default for ArchiveDestination {
+    fn default() -> Self {
+        Self {
+            filesystem_folder: FILESYSTEM_ROOT_FOLDER_ID,
+            secrets_folder: SECRETS_ROOT_FOLDER_ID,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchiveImport {
+    pub folders: usize,
+    pub files: usize,
+    pub secret_folders: usize,
+    pub secrets: usize,
+}
+
+pub fn export_archive(
+    session: &mut VaultSession,
+    filesystem: &FilesystemStore,
+    secrets: &SecretStore,
+    selection: &ArchiveSelection,
+    recipient: &ArchiveRecipient,
+    destination: &mut dyn Write,
+) -> Result {
+    let mut manifest = ArchiveManifest::new();
+    let mut blobs = Vec::new();
+
+    if let Some(folder_id) = selection.filesystem_folder {
+        collect_filesystem(filesystem, folder_id, &mut manifest, &mut blobs)?;
+    }
+    if let Some(folder_id) = selection.secrets_folder {
+        collect_secrets(session, secrets, folder_id, &mut manifest)?;
+    }
+
+    let mut writer = ArchiveWriter::create(
+        destination,
+        recipient,
+        session.compressor(),
+        session.cipher(),
+        &manifest,
+    )?;
+
+    for blob_ref in &blobs {
+        let mut content = open_blob(session, blob_ref)?;
+        writer.write_content(&mut content, blob_ref.size_bytes)?;
+    }
+
+    writer.finish()
+}
+
+pub fn import_archive(
+    session: &mut VaultSession,
+    chunks: &mut ChunksStore,
+    filesystem: &mut FilesystemStore,
+    secrets: &mut SecretStore,
+    destination: &ArchiveDestination,
+    credential: &ArchiveCredential,
+    source: &mut dyn Read,
+) -> Result<ArchiveImport> {
+    let (mut reader, manifest) = ArchiveReader::open(source, credential)?;
+    let mut summary = ArchiveImport::default();
+
+    let mut folder_ids = HashMap::new();
+    for folder in &manifest.folders {
+        let parent_id =
+            resolve_parent(&folder_ids, folder.parent_id, destination.filesystem_folder)?;
+        let id = filesystem.add_folder(parent_id, folder.name.clone())?;
+
+        folder_ids.insert(folder.id, id);
+        summary.folders += 1;
+    }
+
+    for file in &manifest.files {
+        let parent_id = resolve_parent(&folder_ids, file.folder_id, destination.filesystem_folder)?;
+
+        let mut content = reader.content(file.size_bytes);
+        let blob_ref = write_blob(session, chunks, &mut content)?;
+        if blob_ref.size_bytes != file.size_bytes {
+            return Err(Error::InvalidArchiveFormat);
+        }
+
+        filesystem.add_file(
+            parent_id,
+            file.name.clone(),
+            file.extension.clone(),
+            blob_ref,
+        )?;
+        summary.files += 1;
+    }
+
+    reader.finish()?;
+
+    let mut secret_folder_ids = HashMap::new();
+    for folder in &manifest.secret_folders {
+        let parent_id = resolve_parent(
+            &secret_folder_ids,
+            folder.parent_id,
+            destination.secrets_folder,
+        )?;
+        let name = secrets.available_name(parent_id, &folder.name)?;
+        let id = secrets.add_folder(parent_id, name)?;
+
+        secret_folder_ids.insert(folder.id, id);
+        summary.secret_folders += 1;
+    }
+
+    let key = session
+        .keyring()
+        .derive_feature_key(session.version(), SECRETS_FEATURE_ID)?;
+
+    for secret in &manifest.secrets {
+        let folder_id = resolve_parent(
+            &secret_folder_ids,
+            secret.folder_id(),
+            destination.secrets_folder,
+        )?;
+        let name = secrets.available_name(folder_id, secret.name())?;
+
+        let entry = SecretEntry::seal(new_secret(secret, folder_id, name), &key, session.cipher())?;
+        secrets.add_entry(entry)?;
+        summary.secrets += 1;
+    }
+
+    Ok(summary)
+}
+
+fn resolve_parent(
+    ids: &HashMap<Uuid, Uuid>,
+    parent_id: Option<Uuid>,
+    destination: Uuid,
+) -> Result<Uuid> {
+    match parent_id {
+        None => Ok(destination),
+        Some(parent_id) => ids
+            .get(&parent_id)
+            .copied()
+            .ok_or(Error::InvalidArchiveFormat),
+    }
+}
+
+// Exporting a store root places its children directly under the import destination,
+// any other folder travels along with its subtree.
+fn collect_filesystem(
+    store: &FilesystemStore,
+    folder_id: Uuid,
+    manifest: &mut ArchiveManifest,
+    blobs: &mut Vec<BlobRef>,
+) -> Result {
+    let parent = if folder_id == FILESYSTEM_ROOT_FOLDER_ID {
+        None
+    } else {
+        let folder = store
+            .folder(&folder_id)
+            .ok_or(FilesystemError::FolderNotFound(folder_id))?;
+        manifest.folders.push(ArchiveFolder {
+            id: folder.id,
+            parent_id: None,
+            name: folder.name.clone(),
+        });
+        Some(folder_id)
+    };
+
+    let mut pending = vec![(folder_id, parent)];
+    while let Some((folder_id, parent)) = pending.pop() {
+        let (folders, files) = store.browse(&folder_id)?;
+
+        for file in files {
+            manifest.files.push(ArchiveFile {
+                folder_id: parent,
+                name: file.name.clone(),
+                extension: file.extension.clone(),
+                size_bytes: file.blob.size_bytes,
+            });
+            blobs.push(file.blob.clone());
+        }
+
+        for folder in folders {
+            manifest.folders.push(ArchiveFolder {
+                id: folder.id,
+                parent_id: parent,
+                name: folder.name.clone(),
+            });
+            pending.push((folder.id, Some(folder.id)));
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_secrets(
+    session: &VaultSession,
+    store: &SecretStore,
+    folder_id: Uuid,
+    manifest: &mut ArchiveManifest,
+) -> Result {
+    let parent = if folder_id == SECRETS_ROOT_FOLDER_ID {
+        None
+    } else {
+        let folder = store
+            .folder(&folder_id)
+            .ok_or(SecretError::FolderNotFound(folder_id))?;
+        manifest.secret_folders.push(ArchiveFolder {
+            id: folder.id,
+            parent_id: None,
+            name: folder.name.clone(),
+        });
+        Some(folder_id)
+    };
+
+    let key = session
+        .keyring()
+        .derive_feature_key(session.version(), SECRETS_FEATURE_ID)?;
+
+    let mut pending = vec![(folder_id, parent)];
+    while let Some((folder_id, parent)) = pending.pop() {
+        let (folders, entries) = store.browse(&folder_id)?;
+
+        for entry in entries {
+            let view = entry.reveal(&key, session.cipher())?;
+            manifest.secrets.push(archive_secret(parent, &view));
+        }
+
+        for folder in folders {
+            manifest.secret_folders.push(ArchiveFolder {
+                id: folder.id,
+                parent_id: parent,
+                name: folder.name.clone(),
+            });
+            pending.push((folder.id, Some(folder.id)));
+        }
+    }
+
+    Ok(())
+}
+
+fn archive_secret(folder_id: Option<Uuid>, view: &SecretEntryView) -> ArchiveSecret {
+    match view {
+        SecretEntryView::Login(view) => ArchiveSecret::Login(ArchiveLogin {
+            folder_id,
+            name: view.name.clone(),
+            username: view.username.clone(),
+            password: view.password.clone(),
+            website: view.website.clone(),
+            comments: view.comments.clone(),
+            totp: view.totp.clone(),
+        }),
+        SecretEntryView::ApiKey(view) => ArchiveSecret::ApiKey(ArchiveApiKey {
+            folder_id,
+            name: view.name.clone(),
+            api_key: view.api_key.clone(),
+            website: view.website.clone(),
+            comments: view.comments.clone(),
+        }),
+        SecretEntryView::SecureNote(view) => ArchiveSecret::SecureNote(ArchiveSecureNote {
+            folder_id,
+            name: view.name.clone(),
+            content: view.content.clone(),
+        }),
+        SecretEntryView::PaymentCard(view) => ArchiveSecret::PaymentCard(ArchivePaymentCard {
+            folder_id,
+            name: view.name.clone(),
+            cardholder_name: view.cardholder_name.clone(),
+            number: view.number.clone(),
+            expiry: view.expiry.clone(),
+            security_code: view.security_code.clone(),
+            comments: view.comments.clone(),
+        }),
+        SecretEntryView::SshKey(view) => ArchiveSecret::SshKey(ArchiveSshKey {
+            folder_id,
+            name: view.name.clone(),
+            private_key: view.private_key.clone(),
+            public_key: view.public_key.clone(),
+            passphrase: view.passphrase.clone(),
+            comments: view.comments.clone(),
+        }),
+        SecretEntryView::Identity(view) => ArchiveSecret::Identity(ArchiveIdentity {
+            folder_id,
+            name: view.name.clone(),
+            full_name: view.full_name.clone(),
+            email: view.email.clone(),
+            phone: view.phone.clone(),
+            address: view.address.clone(),
+            document_number: view.document_number.clone(),
+            comments: view.comments.clone(),
+        }),
+        SecretEntryView::WifiNetwork(view) => ArchiveSecret::WifiNetwork(ArchiveWifiNetwork {
+            folder_id,
+            name: view.name.clone(),
+            ssid: view.ssid.clone(),
+            password: view.password.clone(),
+            security: view.security,
+            comments: view.comments.clone(),
+        }),
+        SecretEntryView::Custom(view) => ArchiveSecret::Custom(ArchiveCustom {
+            folder_id,
+            name: view.name.clone(),
+            fields: view.fields.clone(),
+            comments: view.comments.clone(),
+        }),
+    }
+}
+
+fn new_secret(secret: &ArchiveSecret, folder_id: Uuid, name: String) -> NewSecretEntry {
+    match secret {
+        ArchiveSecret::Login(secret) => NewSecretEntry::Login(NewLoginSecret {
+            folder_id,
+            name,
+            username: secret.username.clone(),
+            password: secret.password.clone(),
+            website: Some(secret.website.clone()),
+            comments: Some(secret.comments.clone()),
+            totp: secret.totp.clone(),
+        }),
+        ArchiveSecret::ApiKey(secret) => NewSecretEntry::ApiKey(NewApiKeySecret {
+            folder_id,
+            name,
+            api_key: secret.api_key.clone(),
+            website: Some(secret.website.clone()),
+            comments: Some(secret.comments.clone()),
+        }),
+        ArchiveSecret::SecureNote(secret) => NewSecretEntry::SecureNote(NewSecureNoteSecret {
+            folder_id,
+            name,
+            content: secret.content.clone(),
+        }),
+        ArchiveSecret::PaymentCard(secret) => NewSecretEntry::PaymentCard(NewPaymentCardSecret {
+            folder_id,
+            name,
+            cardholder_name: secret.cardholder_name.clone(),
+            number: secret.number.clone(),
+            expiry: secret.expiry.clone(),
+            security_code: Some(secret.security_code.clone()),
+            comments: Some(secret.comments.clone()),
+        }),
+        ArchiveSecret::SshKey(secret) => NewSecretEntry::SshKey(NewSshKeySecret {
+            folder_id,
+            name,
+            private_key: secret.private_key.clone(),
+            public_key: Some(secret.public_key.clone()),
+            passphrase: Some(secret.passphrase.clone()),
+            comments: Some(secret.comments.clone()),
+        }),
+        ArchiveSecret::Identity(secret) => NewSecretEntry::Identity(NewIdentitySecret {
+            folder_id,
+            name,
+            full_name: secret.full_name.clone(),
+            email: Some(secret.email.clone()),
+            phone: Some(secret.phone.clone()),
+            address: Some(secret.address.clone()),
+            document_number: Some(secret.document_number.clone()),
+            comments: Some(secret.comments.clone()),
+        }),
+        ArchiveSecret::WifiNetwork(secret) => NewSecretEntry::WifiNetwork(NewWifiNetworkSecret {
+            folder_id,
+            name,
+            ssid: secret.ssid.clone(),
+            password: Some(secret.password.clone()),
+            security: secret.security,
+            comments: Some(secret.comments.clone()),
+        }),
+        ArchiveSecret::Custom(secret) => NewSecretEntry::Custom(NewCustomSecret {
+            folder_id,
+            name,
+            fields: secret.fields.clone(),
+            comments: Some(secret.comments.clone()),
+        }),
+    }
+}
@@ -1,13 +1,50 @@
+use std::collections::HashSet;
 use std::io::Read;
 
 use crate::errors::Result;
+use crate::features::chunks::ChunksStore;
 use crate::features::shared::BlobRef;
 use crate::vault::runtime::VaultSession;
+use crate::vault::versions::shared::format::BlobStream;
+use crate::vault::versions::shared::frame::read_frame_size;
 
-pub fn write_blob(session: &mut VaultSession, source: &mut dyn Read) -> Result<BlobRef> {
+pub fn write_blob(
+    session: &mut VaultSession,
+    chunks: &mut ChunksStore,
+    source: &mut dyn Read,
+) -> Result<BlobRef> {
     let format = session.format();
 
-    session.with_format_context(|file, context| format.write_blob(file, source, context))
+    session.with_format_context(|file, context| format.write_blob(file, source, context, chunks))
+}
+
+pub fn release_blob(
+    session: &mut VaultSession,
+    chunks: &mut ChunksStore,
+    blob_ref: &BlobRef,
+) -> Result {
+    let format = session.format();
+
+    session.with_format_context(|file, context| {
+        let mut released = read_frame_size(file, blob_ref.manifest_offset)?;
+        let mut freed = HashSet::new();
+
+        for (offset, hash) in format.read_blob_chunks(file, blob_ref, context)? {
+            if let Some(hash) = hash {
+                chunks.release(hash);
+                if chunks.find(&hash).is_some() {
+                    continue;
+                }
+            }
+            if freed.insert(offset) {
+                released += read_frame_size(file, offset)?;
+            }
+        }
+
+        chunks.add_released_bytes(released);
+
+        Ok(())
+    })
 }
 
 pub fn read_blob(session: &mut VaultSession, blob_ref: &BlobRef) -> Result<Vec<u8>> {
@@ -15,3 +52,10 @@ pub fn read_blob(session: &mut VaultSession, blob_ref: &BlobRef) -> Result<Vec<u
 
     session.with_format_context(|file, context| format.read_blob(file, blob_ref, context))
 }
+
+pub fn open_blob<'a>(session: &'a mut VaultSession, blob_ref: &BlobRef) -> Result<BlobStream<'a>> {
+    let format = session.format();
+    let (file, context) = session.split_format_context();
+
+    format.open_blob(file, blob_ref, context)
+}
@@ -1,28 +1,38 @@
-use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 
+use openvault_crypto::keys::kdf_params::KdfParams;
+use openvault_crypto::keys::master_key::MasterKey;
+use openvault_crypto::keys::salt::Salt;
 use uuid::Uuid;
 
-use crate::errors::Result;
+use crate::errors::{Error, Result};
 use crate::features::FeatureType;
+use crate::features::chunks::ChunksStore;
 use crate::features::shared::BlobRef;
-use crate::internal::fs::open_with_read_write;
+use crate::internal::fs::{
+    create_new_file, open_with_read_write, remove_if_exists, sync_parent_dir,
+};
 use crate::internal::io_ext::SeekExt;
 use crate::operations::replay::replay_since_checkpoint;
-use crate::repositories::FeatureRepository;
+use crate::operations::vault::verify_credential;
+use crate::repositories::{ChunksRepository, FeatureRepository};
+use crate::vault::crypto::credential::Credential;
+use crate::vault::crypto::keyring::{KeyRotation, Keyring};
 use crate::vault::runtime::VaultSession;
+use crate::vault::versions::factory::FormatRef;
 use crate::vault::versions::shared::boot_header::BootHeader;
 use crate::vault::versions::shared::checkpoint::{Checkpoint, CheckpointFeature};
 use crate::vault::versions::shared::format::FormatContext;
+use crate::vault::versions::shared::key_slot::{KeySlot, KeySlotTable};
 use crate::vault::versions::shared::replay::ReplayState;
 
 const COMPACT_TEMP_SUFFIX: &str = ".compact-tmp";
+const COMPACT_BACKUP_SUFFIX: &str = ".bak";
 
-// @todo-soon clean this up & change salt on compact
-
-type RemapFn = Box<dyn FnOnce(&HashMap<BlobRef, BlobRef>) -> Result<CheckpointFeature>>;
+type RemapFn =
+    Box<dyn FnOnce(&HashMap<BlobRef, BlobRef>, Option<&KeyRotation>) -> Result<CheckpointFeature>>;
 
 pub struct CompactionBundle {
     pub feature_type: FeatureType,
@@ -30,9 +40,115 @@ pub struct CompactionBundle {
     remap_fn: RemapFn,
 }
 
+pub struct RekeyTarget {
+    pub format: FormatRef,
+    pub salt: Salt,
+    pub kdf: KdfParams,
+    pub keyring: Keyring,
+    pub key_slots: Option<KeySlotTable>,
+}
+
+impl RekeyTarget {
+    pub fn derive(format: FormatRef, password: &[u8], kdf: KdfParams) -> Result<Self> {
+        let salt = Salt::random();
+        let keyring = Keyring::derive(password, &salt, &kdf)?;
+
+        Ok(Self {
+            format,
+            salt,
+            kdf,
+            keyring,
+            key_slots: None,
+        })
+    }
+
+    pub fn rotate(session: &mut VaultSession, credentials: &[Credential]) -> Result<Self> {
+        let kdf = BootHeader::read_from(session.file_mut())?.kdf;
+        Self::migrate(session, credentials, session.format(), kdf)
+    }
+
+    // Every slot of the current vault needs its credential, otherwise it would be dropped.
+    pub fn migrate(
+        session: &mut VaultSession,
+        credentials: &[Credential],
+        format: FormatRef,
+        kdf: KdfParams,
+    ) -> Result<Self> {
+        let [first, ..] = credentials else {
+            return Err(Error::UnlockFailed);
+        };
+
+        if !session.format().has_key_slots() {
+            verify_credential(session, first)?;
+        }
+        if !format.has_key_slots() {
+            return Self::derive(format, first.secret(), kdf);
+        }
+
+        let cipher = session.cipher();
+        let unlocked = if session.format().has_key_slots() {
+            let version = session.version();
+            let current = KeySlotTable::read_from(session.file_mut(), version)?;
+
+            let mut unlocked = BTreeMap::new();
+            for credential in credentials {
+                let (index, _) = current.unlock(credential, cipher)?;
+                unlocked.entry(index).or_insert(credential);
+            }
+
+            if let Some((index, _)) = current
+                .slots()
+                .find(|(index, _)| !unlocked.contains_key(index))
+            {
+                return Err(Error::KeySlotCredentialMissing(index));
+            }
+
+            unlocked.into_values().collect()
+        } else {
+            vec![first]
+        };
+
+        let master = MasterKey::generate()?;
+        let mut key_slots = KeySlotTable::new(format.version());
+
+        for credential in unlocked {
+            key_slots.insert(KeySlot::wrap(&master, credential, &kdf, cipher)?)?;
+        }
+
+        Ok(Self {
+            format,
+            salt: Salt::random(),
+            kdf,
+            keyring: Keyring::new(master),
+            key_slots: Some(key_slots),
+        })
+    }
+}
+
 pub fn compact_vault(session: &mut VaultSession) -> Result {
+    let rebuilt = rebuild_vault(session, None)?;
+    swap_vault_file(session, rebuilt, None)
+}
+
+pub fn compact_and_rotate_keys(session: &mut VaultSession, credentials: &[Credential]) -> Result {
+    let target = RekeyTarget::rotate(session, credentials)?;
+    let rebuilt = rebuild_vault(session, Some(&target))?;
+
+    swap_vault_file(session, rebuilt, Some(&target))?;
+    session.replace_keyring(target.keyring);
+
+    Ok(())
+}
+
+pub(crate) fn rebuild_vault(
+    session: &mut VaultSession,
+    target: Option<&RekeyTarget>,
+) -> Result<TempVault> {
     let replay = replay_since_checkpoint(session)?;
-    let feature_types = collect_present_features(&replay);
+    let feature_types: Vec<_> = collect_present_features(&replay)
+        .into_iter()
+        .filter(|feature_type| *feature_type != FeatureType::Chunks)
+        .collect();
 
     let bundles: Vec<_> = feature_types
         .iter()
@@ -41,36 +157,64 @@ pub fn compact_vault(session: &mut VaultSession) -> Result {
 
     let blob_refs = collect_unique_blob_refs_from_bundles(&bundles);
 
-    let format = session.format();
-    let vault_path = session.file_path();
-    let temp_path = temp_compact_path(vault_path);
-
-    let mut temp_file = File::options()
-        .read(true)
-        .write(true)
-        .create_new(true)
-        .open(&temp_path)?;
-
-    let _ = TempFileGuard(temp_path.clone());
+    let source_format = session.format();
+    let format = target.map_or(source_format, |t| t.format);
+    let mut temp = TempVault::create(temp_compact_path(session.file_path()))?;
+    let mut chunks = ChunksStore::new();
 
     let remap = session.with_format_context(|source_file, context| {
-        rewrite_vault(format, source_file, &mut temp_file, context, &blob_refs)
+        let target_keyring = target.map_or(context.keyring, |t| &t.keyring);
+        let target_context = FormatContext::new(target_keyring, context.compressor, context.cipher);
+
+        let mut boot_header = BootHeader::read_from(source_file)?;
+        if let Some(target) = target {
+            boot_header.version = target.format.version();
+            boot_header.salt = *target.salt.as_bytes();
+            boot_header.kdf = target.kdf;
+        }
+        boot_header.write_to(temp.file_mut())?;
+
+        if format.has_key_slots() {
+            match target.and_then(|t| t.key_slots.as_ref()) {
+                Some(key_slots) => key_slots.write_to(temp.file_mut())?,
+                None => KeySlotTable::read_from(source_file, source_format.version())?
+                    .write_to(temp.file_mut())?,
+            }
+        }
+
+        rewrite_vault(
+            VaultFile::new(source_format, source_file, context),
+            VaultFile::new(format, temp.file_mut(), &target_context),
+            &blob_refs,
+            &mut chunks,
+        )
     })?;
 
-    let checkpoint_features: Vec<_> = bundles
+    let rotation = target.map(|t| {
+        KeyRotation::new(
+            session.keyring(),
+            &t.keyring,
+            session.version(),
+            t.format.version(),
+            session.cipher(),
+        )
+    });
+
+    let mut checkpoint_features: Vec<_> = bundles
         .into_iter()
-        .map(|bundle| (bundle.remap_fn)(&remap))
+        .map(|bundle| (bundle.remap_fn)(&remap, rotation.as_ref()))
         .collect::<Result<_>>()?;
+    checkpoint_features.push(ChunksRepository::create_checkpoint(&chunks)?);
 
-    session.with_format_context(|_, context| {
-        let mut checkpoint = Checkpoint::new(checkpoint_features);
-        format.write_checkpoint(&mut temp_file, &mut checkpoint, context)
-    })?;
+    let target_keyring = target.map_or(session.keyring(), |t| &t.keyring);
+    let target_context = FormatContext::new(target_keyring, session.compressor(), session.cipher());
 
-    temp_file.sync_all()?;
-    rewrite_current_vault(session, &temp_path)?;
+    let mut checkpoint = Checkpoint::new(checkpoint_features);
+    format.write_checkpoint(temp.file_mut(), &mut checkpoint, &target_context)?;
 
-    Ok(())
+    temp.file_mut().sync_all()?;
+
+    Ok(temp)
 }
 
 pub fn build_bundle_for<R: FeatureRepository>(
@@ -83,10 +227,17 @@ where
     let mut store = R::load(session)?;
     let blob_refs = R::referenced_blobs(&store);
 
-    let remap_fn = Box::new(move |remap: &HashMap<BlobRef, BlobRef>| {
-        R::rewrite_blob_refs(&mut store, remap)?;
-        R::create_checkpoint(&store)
-    });
+    let remap_fn = Box::new(
+        move |remap: &HashMap<BlobRef, BlobRef>, rotation: Option<&KeyRotation>| {
+            R::rewrite_blob_refs(&mut store, remap)?;
+
+            if let Some(rotation) = rotation {
+                R::rekey(&mut store, rotation)?;
+            }
+
+            R::create_checkpoint(&store)
+        },
+    );
 
     Ok(CompactionBundle {
         feature_type,
@@ -119,43 +270,140 @@ fn collect_unique_blob_refs_from_bundles(bundles: &[CompactionBundle]) -> Vec<Bl
     refs
 }
 
+struct VaultFile<'a, 'k> {
+    format: FormatRef,
+    file: &'a mut File,
+    context: &'a FormatContext<'k>,
+}
+
+impl<'a, 'k> VaultFile<'a, 'k> {
+    fn new(format: FormatRef, file: &'a mut File, context: &'a FormatContext<'k>) -> Self {
+        Self {
+            format,
+            file,
+            context,
+        }
+    }
+}
+
 fn rewrite_vault(
-    format: crate::vault::versions::factory::FormatRef,
-    source: &mut File,
-    target: &mut File,
-    context: &FormatContext<'_>,
+    source: VaultFile<'_, '_>,
+    target: VaultFile<'_, '_>,
     blob_refs: &[BlobRef],
+    chunks: &mut ChunksStore,
 ) -> Result<HashMap<BlobRef, BlobRef>> {
-    let boot_header = BootHeader::read_from(source)?;
-    boot_header.write_to(target)?;
-
-    format.init_layout(target, context)?;
+    // Sequence numbers carry over so history positions stay stable across compaction.
+    let mut subheader = target.format.init_layout(target.file, target.context)?;
+    subheader.last_sequence = source
+        .format
+        .read_subheader(source.file, source.context)?
+        .last_sequence;
+    target
+        .format
+        .write_subheader(target.file, &subheader, target.context)?;
 
     let mut remap = HashMap::with_capacity(blob_refs.len());
 
     for blob in blob_refs {
-        let bytes = format.read_blob(source, blob, context)?;
-        let mut cursor = io::Cursor::new(bytes);
-
-        let new_ref = format.write_blob(target, &mut cursor, context)?;
+        let mut content = source
+            .format
+            .open_blob(source.file, blob, *source.context)?;
+
+        let new_ref =
+            target
+                .format
+                .write_blob(target.file, &mut content, target.context, chunks)?;
         remap.insert(blob.clone(), new_ref);
     }
 
     Ok(remap)
 }
 
-fn rewrite_current_vault(session: &mut VaultSession, temp_path: &Path) -> Result {
-    let mut compacted = open_with_read_write(temp_path)?;
-    compacted.seek_to_start()?;
+pub(crate) fn swap_vault_file(
+    session: &mut VaultSession,
+    temp: TempVault,
+    target: Option<&RekeyTarget>,
+) -> Result {
+    let vault_path = session.file_path().clone();
+    let mut backup = VaultBackup::create(&vault_path)?;
+
+    temp.persist(&vault_path)?;
+    backup.swapped = true;
+
+    let format = target.map_or(session.format(), |t| t.format);
+    let keyring = target.map_or(session.keyring(), |t| &t.keyring);
+    let context = FormatContext::new(keyring, session.compressor(), session.cipher());
+
+    match reopen_vault_file(&vault_path, format, &context) {
+        Ok(file) => {
+            session.replace_file(file);
+            backup.discard()
+        }
+        Err(error) => {
+            backup.restore()?;
+            Err(error)
+        }
+    }
+}
+
+// The backup only goes once the swapped file reads back under the keys the session
+// is about to switch to.
+fn reopen_vault_file(
+    vault_path: &Path,
+    format: FormatRef,
+    context: &FormatContext,
+) -> Result<File> {
+    let mut file = open_with_read_write(vault_path)?;
+
+    let boot_header = BootHeader::read_from(&mut file)?;
+    if boot_header.version != format.version() {
+        return Err(Error::InvalidVaultFormat);
+    }
+    if format.has_key_slots() {
+        KeySlotTable::read_from(&mut file, format.version())?;
+    }
 
-    let current = session.file_mut();
-    current.set_len(0)?;
-    current.seek_to_start()?;
+    let subheader = format.read_subheader(&mut file, context)?;
+    format.read_checkpoint(&mut file, subheader.checkpoint_offset, context)?;
+    file.seek_to_start()?;
 
-    io::copy(&mut compacted, current)?;
-    current.sync_all()?;
+    Ok(file)
+}
 
-    Ok(())
+// A crash mid-compaction leaves the temp file or the backup next to the vault. Both
+// are safe to drop once the vault itself has unlocked.
+pub(crate) fn remove_stale_siblings(vault_path: &Path) -> Result {
+    let dir = vault_path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let filename = vault_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("vault");
+    let temp_prefix = format!("{filename}{COMPACT_TEMP_SUFFIX}-");
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|name| name.starts_with(&temp_prefix))
+        {
+            remove_if_exists(&path)?;
+        }
+    }
+
+    remove_if_exists(&backup_path(vault_path))
+}
+
+fn backup_path(vault_path: &Path) -> PathBuf {
+    let filename = vault_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("vault");
+
+    vault_path.with_file_name(format!("{filename}{COMPACT_BACKUP_SUFFIX}"))
 }
 
 fn temp_compact_path(vault_path: &Path) -> PathBuf {
@@ -170,10 +418,84 @@ fn temp_compact_path(vault_path: &Path) -> PathBuf {
     ))
 }
 
-struct TempFileGuard(PathBuf);
+pub(crate) struct TempVault {
+    path: PathBuf,
+    file: File,
+    persisted: bool,
+}
+
+impl TempVault {
+    fn create(path: PathBuf) -> Result<Self> {
+        let file = create_new_file(&path)?;
+
+        Ok(Self {
+            path,
+            file,
+            persisted: false,
+        })
+    }
+
+    fn file_mut(&mut self) -> &mut File {
+        &mut self.file
+    }
+
+    fn persist(mut self, target: &Path) -> Result {
+        self.file.sync_all()?;
+        sync_parent_dir(&self.path)?;
+
+        fs::rename(&self.path, target)?;
+        self.persisted = true;
+
+        sync_parent_dir(target)
+    }
+}
+
+impl Drop for TempVault {
+    fn drop(&mut self) {
+        if !self.persisted {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+struct VaultBackup {
+    path: PathBuf,
+    original: PathBuf,
+    swapped: bool,
+}
+
+impl VaultBackup {
+    fn create(original: &Path) -> Result<Self> {
+        let path = backup_path(original);
+        remove_if_exists(&path)?;
+
+        if fs::hard_link(original, &path).is_err() {
+            fs::copy(original, &path)?;
+        }
+        sync_parent_dir(&path)?;
+
+        Ok(Self {
+            path,
+            original: original.to_path_buf(),
+            swapped: false,
+        })
+    }
+
+    fn restore(self) -> Result {
+        fs::rename(&self.path, &self.original)?;
+        sync_parent_dir(&self.original)
+    }
+
+    fn discard(self) -> Result {
+        fs::remove_file(&self.path)?;
+        sync_parent_dir(&self.original)
+    }
+}
 
-impl Drop for TempFileGuard {
+impl Drop for VaultBackup {
     fn drop(&mut self) {
-        let _ = std::fs::remove_file(&self.0);
+        if !self.swapped {
+            let _ = fs::remove_file(&self.path);
+        }
     }
 }
@@ -1,13 +1,43 @@
+use std::time::Duration;
+
 use openvault_crypto::compression::CompressionAlgorithm;
 use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::errors::Error as CryptoError;
+use openvault_crypto::keys::kdf_params::KdfParams;
 
+use crate::errors::Result;
 use crate::vault::versions::factory::LATEST_FORMAT_VERSION;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfConfig {
+    Params(KdfParams),
+    Calibrate(Duration),
+}
+
+impl KdfConfig {
+    pub fn resolve(&self) -> Result<KdfParams> {
+        match self {
+            Self::Params(params) if !KdfParams::MAX.is_at_least(params) => {
+                Err(CryptoError::InvalidKdfParams.into())
+            }
+            Self::Params(params) => Ok(*params),
+            Self::Calibrate(target) => KdfParams::calibrate(*target).map_err(Into::into),
+        }
+    }
+}
+
+impl Default for KdfConfig {
+    fn default() -> Self {
+        Self::Params(KdfParams::default())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateConfig {
     pub version: u16,
     pub compression: CompressionAlgorithm,
     pub cipher: EncryptionAlgorithm,
+    pub kdf: KdfConfig,
     pub filename: String,
     pub overwrite: bool,
 }
@@ -32,6 +62,16 @@ impl CreateConfig {
         self
     }
 
+    pub fn with_kdf_params(mut self, params: KdfParams) -> Self {
+        self.kdf = KdfConfig::Params(params);
+        self
+    }
+
+    pub fn with_kdf_target(mut self, target: Duration) -> Self {
+        self.kdf = KdfConfig::Calibrate(target);
+        self
+    }
+
     pub fn with_filename(mut self, filename: String) -> Self {
         self.filename = filename;
         self
@@ -49,6 +89,7 @@ impl Default for CreateConfig {
             version: LATEST_FORMAT_VERSION,
             compression: CompressionAlgorithm::default(),
             cipher: EncryptionAlgorithm::default(),
+            kdf: KdfConfig::default(),
             filename: String::new(),
             overwrite: false,
         }
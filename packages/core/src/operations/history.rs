@@ -7,9 +7,19 @@ use crate::vault::versions::shared::record::Record;
 const CHECKPOINT_INTERVAL: u64 = 400;
 
 pub fn append_record(session: &mut VaultSession, record: &mut Record) -> Result<Offset> {
+    append_record_releasing(session, record, 0)
+}
+
+pub(crate) fn append_record_releasing(
+    session: &mut VaultSession,
+    record: &mut Record,
+    released_bytes: u64,
+) -> Result<Offset> {
     let format = session.format();
 
-    session.with_format_context(|file, context| format.append_record(file, record, context))
+    session.with_format_context(|file, context| {
+        format.append_record(file, record, released_bytes, context)
+    })
 }
 
 pub fn create_checkpoint(session: &mut VaultSession, cp: &mut Checkpoint) -> Result<Offset> {
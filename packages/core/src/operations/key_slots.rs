@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+
+use openvault_crypto::keys::kdf_params::KdfParams;
+
+use crate::errors::{Error, Result};
+use crate::vault::crypto::credential::Credential;
+use crate::vault::runtime::VaultSession;
+use crate::vault::versions::shared::boot_header::BootHeader;
+use crate::vault::versions::shared::key_slot::{KeySlot, KeySlotKind, KeySlotTable};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeySlotInfo {
+    pub index: usize,
+    pub kind: KeySlotKind,
+}
+
+pub fn list_key_slots(session: &mut VaultSession) -> Result<Vec<KeySlotInfo>> {
+    let slots = read_key_slots(session)?;
+
+    Ok(slots
+        .slots()
+        .map(|(index, slot)| KeySlotInfo {
+            index,
+            kind: slot.kind,
+        })
+        .collect())
+}
+
+pub fn add_key_slot(session: &mut VaultSession, credential: &Credential) -> Result<usize> {
+    let mut slots = read_key_slots(session)?;
+    let kdf = read_kdf_params(session)?;
+
+    let slot = KeySlot::wrap(
+        session.keyring().master(),
+        credential,
+        &kdf,
+        session.cipher(),
+    )?;
+    let index = slots.insert(slot)?;
+
+    let file = session.file_mut();
+    slots.write_slot(file, index)?;
+    file.sync_all()?;
+
+    Ok(index)
+}
+
+pub fn revoke_key_slot(session: &mut VaultSession, index: usize) -> Result {
+    let mut slots = read_key_slots(session)?;
+
+    if slots.slot(index).is_some() && slots.len() == 1 {
+        return Err(Error::CannotRevokeLastKeySlot);
+    }
+
+    slots.remove(index)?;
+
+    let file = session.file_mut();
+    slots.write_slot(file, index)?;
+    file.sync_all()?;
+
+    Ok(())
+}
+
+pub fn replace_key_slot(
+    session: &mut VaultSession,
+    current: &Credential,
+    next: &Credential,
+) -> Result {
+    let mut slots = read_key_slots(session)?;
+    let kdf = read_kdf_params(session)?;
+
+    let cipher = session.cipher();
+    let (current_index, master) = slots.unlock(current, cipher)?;
+
+    let slot = KeySlot::wrap(&master, next, &kdf, cipher)?;
+    write_rewrapped_slot(session.file_mut(), &mut slots, current_index, slot)
+}
+
+// Every slot is re-derived so none is left behind on weaker parameters.
+pub(crate) fn rewrap_key_slots(
+    session: &mut VaultSession,
+    credentials: &[Credential],
+    kdf: &KdfParams,
+) -> Result {
+    let mut slots = read_key_slots(session)?;
+    let cipher = session.cipher();
+
+    let mut unlocked = BTreeMap::new();
+    for credential in credentials {
+        let (index, master) = slots.unlock(credential, cipher)?;
+        unlocked.entry(index).or_insert((credential, master));
+    }
+
+    if let Some((index, _)) = slots
+        .slots()
+        .find(|(index, _)| !unlocked.contains_key(index))
+    {
+        return Err(Error::KeySlotCredentialMissing(index));
+    }
+
+    for (index, (credential, master)) in unlocked {
+        let slot = KeySlot::wrap(&master, credential, kdf, cipher)?;
+        write_rewrapped_slot(session.file_mut(), &mut slots, index, slot)?;
+    }
+
+    Ok(())
+}
+
+fn write_rewrapped_slot(
+    file: &mut File,
+    slots: &mut KeySlotTable,
+    index: usize,
+    slot: KeySlot,
+) -> Result {
+    if slots.is_full() {
+        // No spare slot to stage the new wrap in, a torn write here loses this credential's slot.
+        slots.replace(index, slot)?;
+    } else {
+        let next_index = slots.insert(slot)?;
+        slots.write_slot(file, next_index)?;
+        file.sync_all()?;
+
+        slots.remove(index)?;
+    }
+
+    slots.write_slot(file, index)?;
+    file.sync_all()?;
+
+    Ok(())
+}
+
+fn read_key_slots(session: &mut VaultSession) -> Result<KeySlotTable> {
+    if !session.format().has_key_slots() {
+        return Err(Error::KeySlotsUnsupported);
+    }
+
+    let version = session.version();
+    KeySlotTable::read_from(session.file_mut(), version)
+}
+
+fn read_kdf_params(session: &mut VaultSession) -> Result<KdfParams> {
+    Ok(BootHeader::read_from(session.file_mut())?.kdf)
+}
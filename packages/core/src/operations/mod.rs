@@ -1,6 +1,11 @@
+pub mod archive;
 pub mod blob;
 pub mod compact;
 pub mod config;
 pub mod history;
+pub mod key_slots;
 pub mod replay;
+pub mod share;
+pub mod space;
 pub mod vault;
+pub mod verify;
@@ -1,9 +1,15 @@
 use crate::errors::Result;
 use crate::vault::runtime::VaultSession;
-use crate::vault::versions::shared::replay::ReplayState;
+use crate::vault::versions::shared::replay::{ReplayState, ReplayTarget};
 
 pub fn replay_since_checkpoint(session: &mut VaultSession) -> Result<ReplayState> {
     let format = session.format();
 
     session.with_format_context(|file, context| format.replay(file, context))
 }
+
+pub fn replay_at(session: &mut VaultSession, target: ReplayTarget) -> Result<ReplayState> {
+    let format = session.format();
+
+    session.with_format_context(|file, context| format.replay_at(file, target, context))
+}
@@ -0,0 +1,64 @@
+use std::io::{Read, Write};
+
+use uuid::Uuid;
+
+use crate::errors::Result;
+use crate::features::chunks::ChunksStore;
+use crate::features::filesystem::{FilesystemError, FilesystemStore};
+use crate::features::messages::MessagesStore;
+use crate::operations::blob::{open_blob, release_blob, write_blob};
+use crate::vault::runtime::VaultSession;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedFileDestination {
+    pub folder_id: Uuid,
+    pub name: String,
+    pub extension: String,
+}
+
+pub fn share_file(
+    session: &mut VaultSession,
+    filesystem: &FilesystemStore,
+    messages: &MessagesStore,
+    file_id: Uuid,
+    contact_id: Uuid,
+    destination: &mut dyn Write,
+) -> Result<u64> {
+    let file = filesystem
+        .file(&file_id)
+        .ok_or(FilesystemError::FileNotFound(file_id))?;
+
+    let mut content = open_blob(session, &file.blob)?;
+    let written = messages.encrypt_stream_for_contact(contact_id, &mut content, destination)?;
+
+    Ok(written)
+}
+
+// The signature only covers the complete stream, so the blob is released again when it
+// does not verify rather than being attached to the filesystem. A stream that breaks off
+// earlier is rolled back by `write_blob` itself.
+pub fn import_shared_file(
+    session: &mut VaultSession,
+    chunks: &mut ChunksStore,
+    filesystem: &mut FilesystemStore,
+    messages: &MessagesStore,
+    contact_id: Uuid,
+    destination: SharedFileDestination,
+    source: &mut dyn Read,
+) -> Result<Uuid> {
+    let mut content = messages.open_stream_from_contact(contact_id, source)?;
+    let blob_ref = write_blob(session, chunks, &mut content)?;
+
+    if let Err(err) = content.finish() {
+        release_blob(session, chunks, &blob_ref)?;
+        return Err(err.into());
+    }
+
+    let id = filesystem.add_file(
+        destination.folder_id,
+        destination.name,
+        destination.extension,
+        blob_ref,
+    )?;
+    Ok(id)
+}
@@ -0,0 +1,94 @@
+use crate::errors::Result;
+use crate::vault::runtime::VaultSession;
+
+const DEFAULT_MIN_RECLAIMABLE_RATIO: f64 = 0.4;
+const DEFAULT_MIN_TOTAL_BYTES: u64 = 100 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpaceReport {
+    pub total_bytes: u64,
+    pub reclaimable_bytes: u64,
+}
+
+impl SpaceReport {
+    pub fn live_bytes(&self) -> u64 {
+        self.total_bytes.saturating_sub(self.reclaimable_bytes)
+    }
+
+    pub fn reclaimable_ratio(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 0.0;
+        }
+
+        self.reclaimable_bytes.min(self.total_bytes) as f64 / self.total_bytes as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompactionPolicy {
+    pub min_reclaimable_ratio: f64,
+    pub min_total_bytes: u64,
+}
+
+impl CompactionPolicy {
+    pub fn new(min_reclaimable_ratio: f64, min_total_bytes: u64) -> Self {
+        Self {
+            min_reclaimable_ratio,
+            min_total_bytes,
+        }
+    }
+
+    pub fn should_compact(&self, report: &SpaceReport) -> bool {
+        report.reclaimable_bytes > 0
+            && report.total_bytes > self.min_total_bytes
+            && report.reclaimable_ratio() > self.min_reclaimable_ratio
+    }
+}
+
+impl Default for CompactionPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_RECLAIMABLE_RATIO, DEFAULT_MIN_TOTAL_BYTES)
+    }
+}
+
+pub fn space_report(session: &mut VaultSession) -> Result<SpaceReport> {
+    let total_bytes = session.size()?;
+    let format = session.format();
+
+    let subheader =
+        session.with_format_context(|file, context| format.read_subheader(file, context))?;
+
+    Ok(SpaceReport {
+        total_bytes,
+        reclaimable_bytes: subheader.reclaimable_bytes,
+    })
+}
+
+pub(crate) fn add_reclaimable_bytes(session: &mut VaultSession, bytes: u64) -> Result {
+    let format = session.format();
+
+    session.with_format_context(|file, context| {
+        let mut subheader = format.read_subheader(file, context)?;
+        subheader.reclaimable_bytes += bytes;
+        format.write_subheader(file, &subheader, context)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompactionTrigger {
+    OnCommit,
+    #[default]
+    OnLock,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AutoCompaction {
+    pub policy: CompactionPolicy,
+    pub trigger: CompactionTrigger,
+}
+
+impl AutoCompaction {
+    pub fn new(policy: CompactionPolicy, trigger: CompactionTrigger) -> Self {
+        Self { policy, trigger }
+    }
+}
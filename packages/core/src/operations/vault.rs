@@ -1,26 +1,44 @@
+use openvault_crypto::errors::Error as CryptoError;
+use openvault_crypto::keys::kdf_params::KdfParams;
 use openvault_crypto::keys::salt::Salt;
+use std::fs::{self, File};
 use std::path::Path;
 
+use uuid::Uuid;
+
 use crate::VAULT_EXTENSION;
 use crate::errors::{Error, Result};
-use crate::internal::fs::{create_new_file, open_with_read_write, remove_if_exists, resolve_path};
+use crate::internal::fs::{
+    create_new_file, open_with_read_write, remove_if_exists, resolve_path, sync_parent_dir,
+};
+use crate::operations::compact::{
+    RekeyTarget, rebuild_vault, remove_stale_siblings, swap_vault_file,
+};
 use crate::operations::config::CreateConfig;
-use crate::vault::crypto::keyring::Keyring;
+use crate::operations::key_slots::{replace_key_slot, rewrap_key_slots};
+use crate::vault::crypto::credential::Credential;
 use crate::vault::runtime::VaultSession;
-use crate::vault::versions::resolve_format;
 use crate::vault::versions::shared::boot_header::BootHeader;
 use crate::vault::versions::shared::format::FormatContext;
+use crate::vault::versions::{latest_format, resolve_format};
+
+pub const RECOVERY_COPY_SUFFIX: &str = ".pre-recovery";
 
 pub fn create_vault_with(path: &Path, password: &[u8], config: CreateConfig) -> Result {
     let path = resolve_path(path, &config.filename, VAULT_EXTENSION);
 
-    let salt = Salt::random();
-    let keyring = Keyring::derive(password, &salt)?;
+    let kdf = config.kdf.resolve()?;
+
+    if !BootHeader::stores_kdf_params(config.version) && kdf != KdfParams::LEGACY {
+        return Err(Error::KdfParamsUnsupported);
+    }
+
     let boot_header = BootHeader::new(
-        salt,
+        Salt::random(),
         Some(config.version),
         Some(config.cipher),
         Some(config.compression),
+        Some(kdf),
     );
 
     if config.overwrite {
@@ -31,6 +49,9 @@ pub fn create_vault_with(path: &Path, password: &[u8], config: CreateConfig) ->
     boot_header.write_to(&mut file)?;
 
     let format = resolve_format(config.version)?;
+    let keyring =
+        format.create_keyring(&mut file, &boot_header, &Credential::password(password))?;
+
     let context = FormatContext::new(&keyring, config.compression, config.cipher);
 
     format.init_layout(&mut file, &context)?;
@@ -43,19 +64,36 @@ pub fn create_vault(path: &Path, password: &[u8]) -> Result {
 }
 
 pub fn open_vault(path: &Path, password: &[u8]) -> Result<VaultSession> {
+    unlock_vault(path, &Credential::password(password))
+}
+
+pub fn unlock_vault(path: &Path, credential: &Credential) -> Result<VaultSession> {
     let mut file = open_with_read_write(path)?;
 
     let boot_header = BootHeader::read_from(&mut file)?;
-    let keyring = Keyring::derive(password, &Salt::from(boot_header.salt))?;
 
     let format = resolve_format(boot_header.version)?;
+    let keyring = format.unlock_keyring(&mut file, &boot_header, credential)?;
 
     let context = FormatContext::new(&keyring, boot_header.compressor, boot_header.cipher);
 
-    format
-        .read_subheader(&mut file, &context)
+    let recovery = format
+        .recover(&mut file, &context)
         .map_err(Error::map_unlock_error)?;
 
+    if let Some(offset) = recovery.truncated_at {
+        // A rebuilt subheader no longer records where the committed tail ended, so the
+        // bytes about to be cut are kept next to the vault.
+        if recovery.subheader_rebuilt {
+            keep_recovery_copy(path)?;
+        }
+        file.set_len(offset)?;
+    }
+    if !recovery.is_clean() {
+        file.sync_all()?;
+    }
+    remove_stale_siblings(path)?;
+
     Ok(VaultSession::new(
         file,
         path.to_path_buf(),
@@ -66,6 +104,19 @@ pub fn open_vault(path: &Path, password: &[u8]) -> Result<VaultSession> {
     ))
 }
 
+fn keep_recovery_copy(path: &Path) -> Result {
+    let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("vault");
+    let copy = path.with_file_name(format!(
+        "{filename}{RECOVERY_COPY_SUFFIX}-{}",
+        Uuid::new_v4()
+    ));
+
+    fs::copy(path, &copy)?;
+    File::open(&copy)?.sync_all()?;
+
+    sync_parent_dir(&copy)
+}
+
 pub fn create_and_open_vault(
     path: &Path,
     password: &[u8],
@@ -76,6 +127,88 @@ pub fn create_and_open_vault(
     open_vault(&resolved_path, password)
 }
 
-pub fn change_password() {
-    todo!()
+pub fn verify_credential(session: &mut VaultSession, credential: &Credential) -> Result {
+    let format = session.format();
+    let compressor = session.compressor();
+    let cipher = session.cipher();
+
+    let file = session.file_mut();
+    let boot_header = BootHeader::read_from(file)?;
+    let keyring = format.unlock_keyring(file, &boot_header, credential)?;
+
+    let context = FormatContext::new(&keyring, compressor, cipher);
+
+    format
+        .read_subheader(file, &context)
+        .map_err(Error::map_unlock_error)?;
+
+    Ok(())
+}
+
+pub fn change_password(
+    session: &mut VaultSession,
+    current_password: &[u8],
+    new_password: &[u8],
+) -> Result {
+    let current = Credential::password(current_password);
+    verify_credential(session, &current)?;
+
+    if session.format().has_key_slots() {
+        return replace_key_slot(session, &current, &Credential::password(new_password));
+    }
+
+    let kdf = BootHeader::read_from(session.file_mut())?.kdf;
+    let target = RekeyTarget::derive(session.format(), new_password, kdf)?;
+    let rebuilt = rebuild_vault(session, Some(&target))?;
+
+    swap_vault_file(session, rebuilt, Some(&target))?;
+    session.replace_keyring(target.keyring);
+
+    Ok(())
+}
+
+pub fn kdf_params(session: &mut VaultSession) -> Result<KdfParams> {
+    Ok(BootHeader::read_from(session.file_mut())?.kdf)
+}
+
+pub fn upgrade_kdf_params(
+    session: &mut VaultSession,
+    credentials: &[Credential],
+    params: KdfParams,
+) -> Result {
+    let [first, ..] = credentials else {
+        return Err(Error::UnlockFailed);
+    };
+    verify_credential(session, first)?;
+
+    let current = BootHeader::read_from(session.file_mut())?.kdf;
+
+    if !params.is_at_least(&current) {
+        return Err(Error::KdfParamsDowngrade);
+    }
+    if !KdfParams::MAX.is_at_least(&params) {
+        return Err(CryptoError::InvalidKdfParams.into());
+    }
+
+    // Older layouts have nowhere to store the params, so the vault moves to the latest format.
+    if !BootHeader::stores_kdf_params(session.version()) {
+        let target = RekeyTarget::migrate(session, credentials, latest_format(), params)?;
+        let rebuilt = rebuild_vault(session, Some(&target))?;
+
+        swap_vault_file(session, rebuilt, Some(&target))?;
+        session.replace_format(target.format);
+        session.replace_keyring(target.keyring);
+
+        return Ok(());
+    }
+
+    rewrap_key_slots(session, credentials, &params)?;
+
+    let file = session.file_mut();
+    let mut boot_header = BootHeader::read_from(file)?;
+    boot_header.kdf = params;
+    boot_header.write_to(file)?;
+    file.sync_all()?;
+
+    Ok(())
 }
@@ -0,0 +1,64 @@
+use std::fs::File;
+use std::path::Path;
+
+use crate::errors::{Error, Result};
+use crate::features::shared::BlobRef;
+use crate::repositories::{
+    FeatureRepository, FilesystemRepository, MessagesRepository, SecretsRepository,
+};
+use crate::vault::crypto::credential::Credential;
+use crate::vault::versions::resolve_format;
+use crate::vault::versions::shared::boot_header::BootHeader;
+use crate::vault::versions::shared::format::FormatContext;
+use crate::vault::versions::shared::replay::ReplayState;
+use crate::vault::versions::shared::verify::VerifyReport;
+
+pub fn verify_vault(path: &Path, credential: &Credential) -> Result<VerifyReport> {
+    let mut file = File::open(path)?;
+
+    let Ok(boot_header) = BootHeader::read_from(&mut file) else {
+        return Ok(VerifyReport::default());
+    };
+
+    let format = resolve_format(boot_header.version)?;
+    let keyring = format
+        .unlock_keyring(&mut file, &boot_header, credential)
+        .map_err(Error::map_unlock_error)?;
+
+    let context = FormatContext::new(&keyring, boot_header.compressor, boot_header.cipher);
+
+    // Without key slots the subheader is the only thing that proves the credential.
+    if !format.has_key_slots() {
+        format
+            .read_subheader(&mut file, &context)
+            .map_err(Error::map_unlock_error)?;
+    }
+
+    let blob_refs = format
+        .replay(&mut file, &context)
+        .and_then(|replay| referenced_blobs(&replay));
+
+    let mut report = format.verify(
+        &mut file,
+        blob_refs.as_deref().unwrap_or_default(),
+        &context,
+    )?;
+
+    report.version = boot_header.version;
+    report.boot_header_valid = true;
+    report.replay_error = blob_refs.err().map(|error| error.to_string());
+
+    Ok(report)
+}
+
+fn referenced_blobs(replay: &ReplayState) -> Result<Vec<BlobRef>> {
+    let filesystem = FilesystemRepository::restore_from_replay(replay)?;
+    let messages = MessagesRepository::restore_from_replay(replay)?;
+    let secrets = SecretsRepository::restore_from_replay(replay)?;
+
+    let mut blobs = FilesystemRepository::referenced_blobs(&filesystem);
+    blobs.extend(MessagesRepository::referenced_blobs(&messages));
+    blobs.extend(SecretsRepository::referenced_blobs(&secrets));
+
+    Ok(blobs)
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use crate::errors::Result;
+use crate::features::FeatureType;
+use crate::features::chunks::{ChunksChange, ChunksCodec, ChunksStore};
+use crate::features::shared::{BlobRef, FeatureCodec};
+use crate::operations::history::append_record_releasing;
+use crate::operations::space::add_reclaimable_bytes;
+use crate::repositories::{CommitOutcome, FeatureRepository};
+use crate::vault::runtime::VaultSession;
+use crate::vault::versions::shared::checkpoint::CheckpointFeature;
+use crate::vault::versions::shared::record::Record;
+use crate::vault::versions::shared::replay::ReplayState;
+
+pub struct ChunksRepository;
+
+impl FeatureRepository for ChunksRepository {
+    type Store = ChunksStore;
+    type Change = ChunksChange;
+    type Codec = ChunksCodec;
+
+    fn restore_from_replay(state: &ReplayState) -> Result<Self::Store> {
+        let mut latest_snapshot = state
+            .checkpoint
+            .as_ref()
+            .and_then(|checkpoint| checkpoint.find_feature(FeatureType::Chunks))
+            .map(|feature| ChunksCodec::decode_snapshot(feature.version, &feature.payload))
+            .transpose()?;
+
+        let mut deltas = Vec::new();
+
+        for record in state
+            .records
+            .iter()
+            .filter(|r| r.header.feature_type == FeatureType::Chunks)
+        {
+            let change = ChunksCodec::decode_change(record.header.version, &record.payload)?;
+
+            match change {
+                ChunksChange::Snapshot(snapshot) => {
+                    latest_snapshot = Some(snapshot);
+                    deltas.clear();
+                }
+                ChunksChange::Deltas(batch) => deltas.extend(batch),
+            }
+        }
+
+        let snapshot = latest_snapshot.unwrap_or_default();
+
+        Ok(ChunksStore::restore(snapshot, deltas))
+    }
+
+    fn commit(session: &mut VaultSession, store: &mut Self::Store) -> Result<CommitOutcome> {
+        let feature_type = FeatureType::Chunks;
+
+        let released_bytes = store.released_bytes();
+        let Some(change) = store.pending_changes() else {
+            if released_bytes > 0 {
+                add_reclaimable_bytes(session, released_bytes)?;
+                store.clear_deltas();
+            }
+            return Ok(CommitOutcome::no_change(feature_type));
+        };
+
+        let encoded = ChunksCodec::encode_change(change)?;
+
+        let mut record = Record::new(feature_type, ChunksCodec::wire_version(), encoded);
+        append_record_releasing(session, &mut record, released_bytes)?;
+
+        store.clear_deltas();
+
+        Ok(CommitOutcome::persisted(feature_type))
+    }
+
+    fn create_checkpoint(store: &Self::Store) -> Result<CheckpointFeature> {
+        let checkpoint_payload = ChunksCodec::encode_snapshot(store.snapshot())?;
+
+        Ok(CheckpointFeature {
+            feature_type: FeatureType::Chunks,
+            version: ChunksCodec::wire_version(),
+            payload: checkpoint_payload,
+        })
+    }
+
+    fn referenced_blobs(_store: &Self::Store) -> Vec<BlobRef> {
+        Vec::new()
+    }
+
+    fn rewrite_blob_refs(_store: &mut Self::Store, _remap: &HashMap<BlobRef, BlobRef>) -> Result {
+        Ok(())
+    }
+}
@@ -83,20 +83,30 @@ impl FeatureRepository for FilesystemRepository {
     }
 
     fn referenced_blobs(store: &Self::Store) -> Vec<BlobRef> {
+        let trashed = store.trash.values().flat_map(|entry| entry.item.files());
+
         store
-            .snapshot()
             .files
             .values()
-            .map(|file| file.blob.clone())
+            .chain(trashed)
+            .flat_map(|file| file.blobs().cloned())
             .collect()
     }
 
     fn rewrite_blob_refs(store: &mut Self::Store, remap: &HashMap<BlobRef, BlobRef>) -> Result {
-        for file in store.files.values_mut() {
-            file.blob = remap
-                .get(&file.blob)
-                .cloned()
-                .ok_or(Error::InvalidVaultFormat)?;
+        let remap_blob = |blob: &BlobRef| remap.get(blob).cloned().ok_or(Error::InvalidVaultFormat);
+
+        let trashed = store
+            .trash
+            .values_mut()
+            .flat_map(|entry| entry.item.files_mut());
+
+        for file in store.files.values_mut().chain(trashed) {
+            file.blob = remap_blob(&file.blob)?;
+
+            for revision in &mut file.revisions {
+                revision.blob = remap_blob(&revision.blob)?;
+            }
         }
 
         Ok(())
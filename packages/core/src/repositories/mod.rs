@@ -1,7 +1,9 @@
+pub mod chunks;
 pub mod filesystem;
 pub mod messages;
 pub mod secrets;
 
+pub use chunks::ChunksRepository;
 pub use filesystem::FilesystemRepository;
 pub use messages::MessagesRepository;
 pub use secrets::SecretsRepository;
@@ -12,6 +14,7 @@ use crate::errors::Result;
 use crate::features::FeatureType;
 use crate::features::shared::{BlobRef, FeatureCodec};
 use crate::operations::replay::replay_since_checkpoint;
+use crate::vault::crypto::keyring::KeyRotation;
 use crate::vault::runtime::VaultSession;
 use crate::vault::versions::shared::checkpoint::CheckpointFeature;
 use crate::vault::versions::shared::replay::ReplayState;
@@ -28,6 +31,10 @@ pub trait FeatureRepository {
     fn referenced_blobs(store: &Self::Store) -> Vec<BlobRef>;
     fn rewrite_blob_refs(store: &mut Self::Store, remap: &HashMap<BlobRef, BlobRef>) -> Result;
 
+    fn rekey(_store: &mut Self::Store, _rotation: &KeyRotation) -> Result {
+        Ok(())
+    }
+
     fn load(session: &mut VaultSession) -> Result<Self::Store> {
         let replay = replay_since_checkpoint(session)?;
         Self::restore_from_replay(&replay)
@@ -2,11 +2,12 @@ use std::collections::HashMap;
 
 use crate::errors::Result;
 use crate::features::FeatureType;
-use crate::features::secrets::{SecretCodec, SecretStore, SecretsChange};
+use crate::features::secrets::{SECRETS_FEATURE_ID, SecretCodec, SecretStore, SecretsChange};
 use crate::features::shared::{BlobRef, FeatureCodec};
 use crate::operations::history::append_record;
 use crate::operations::replay::replay_since_checkpoint;
 use crate::repositories::{CommitOutcome, FeatureRepository};
+use crate::vault::crypto::keyring::KeyRotation;
 use crate::vault::runtime::VaultSession;
 use crate::vault::versions::shared::checkpoint::CheckpointFeature;
 use crate::vault::versions::shared::record::Record;
@@ -89,4 +90,17 @@ impl FeatureRepository for SecretsRepository {
     fn rewrite_blob_refs(_store: &mut Self::Store, _remap: &HashMap<BlobRef, BlobRef>) -> Result {
         Ok(())
     }
+
+    fn rekey(store: &mut Self::Store, rotation: &KeyRotation) -> Result {
+        let current = rotation
+            .current
+            .derive_feature_key(rotation.current_version, SECRETS_FEATURE_ID)?;
+        let next = rotation
+            .next
+            .derive_feature_key(rotation.next_version, SECRETS_FEATURE_ID)?;
+
+        store
+            .reseal(&current, &next, rotation.cipher)
+            .map_err(Into::into)
+    }
 }
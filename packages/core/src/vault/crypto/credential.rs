@@ -0,0 +1,53 @@
+use openvault_crypto::hash::{Hasher, Sha256Hasher};
+use openvault_crypto::keys::recovery_key::RecoveryKey;
+use zeroize::Zeroize;
+
+use crate::vault::versions::shared::key_slot::KeySlotKind;
+
+pub struct Credential {
+    kind: KeySlotKind,
+    secret: Vec<u8>,
+}
+
+impl Credential {
+    pub fn password(password: &[u8]) -> Self {
+        Self {
+            kind: KeySlotKind::Password,
+            secret: password.to_vec(),
+        }
+    }
+
+    pub fn recovery_key(key: &RecoveryKey) -> Self {
+        Self {
+            kind: KeySlotKind::RecoveryKey,
+            secret: key.secret_bytes(),
+        }
+    }
+
+    pub fn keyfile(contents: &[u8]) -> Self {
+        Self {
+            kind: KeySlotKind::Keyfile,
+            secret: Sha256Hasher::hash(contents).to_vec(),
+        }
+    }
+
+    pub fn kind(&self) -> KeySlotKind {
+        self.kind
+    }
+
+    pub fn secret(&self) -> &[u8] {
+        &self.secret
+    }
+}
+
+impl Zeroize for Credential {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl Drop for Credential {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -52,6 +52,23 @@ impl Envelope {
         Ok(plaintext)
     }
 
+    pub fn open_bytes_bounded(
+        &self,
+        ciphertext: &[u8],
+        key: &[u8],
+        nonce: &Nonce,
+        aad: &[u8],
+        limit: usize,
+    ) -> Result<Vec<u8>> {
+        let cipher = self.encryption.resolve();
+        let compressed = cipher.decrypt(key, nonce, ciphertext, aad)?;
+
+        let compressor = self.compression.resolve();
+        let plaintext = compressor.decompress_bounded(&compressed, limit)?;
+
+        Ok(plaintext)
+    }
+
     pub fn seal<T: Serialize>(
         &self,
         value: &T,
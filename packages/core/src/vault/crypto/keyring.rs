@@ -1,4 +1,6 @@
+use openvault_crypto::encryption::EncryptionAlgorithm;
 use openvault_crypto::keys::derived_key::DerivedKey;
+use openvault_crypto::keys::kdf_params::KdfParams;
 use openvault_crypto::keys::master_key::MasterKey;
 use openvault_crypto::keys::salt::Salt;
 use zeroize::Zeroize;
@@ -17,11 +19,15 @@ impl Keyring {
         Self { master }
     }
 
-    pub fn derive(password: &[u8], salt: &Salt) -> Result<Self> {
-        let master = MasterKey::derive(password, salt)?;
+    pub fn derive(password: &[u8], salt: &Salt, params: &KdfParams) -> Result<Self> {
+        let master = MasterKey::derive_with_params(password, salt, params)?;
         Ok(Self { master })
     }
 
+    pub(crate) fn master(&self) -> &MasterKey {
+        &self.master
+    }
+
     pub fn derive_meta_key(&self, version: u16) -> Result<DerivedKey> {
         let context = format!("{}/v{}/meta", CONTEXT_PREFIX, version);
         self.expand_context(&context)
@@ -42,6 +48,11 @@ impl Keyring {
         self.expand_context(&context)
     }
 
+    pub fn derive_chunk_hash_key(&self, version: u16) -> Result<DerivedKey> {
+        let context = format!("{}/v{}/blob/chunk-hash", CONTEXT_PREFIX, version);
+        self.expand_context(&context)
+    }
+
     pub fn derive_feature_key(&self, version: u16, feature: &str) -> Result<DerivedKey> {
         let context = format!("{}/v{}/feature/{}", CONTEXT_PREFIX, version, feature);
         self.expand_context(&context)
@@ -52,3 +63,29 @@ impl Keyring {
         self.master.expand(context.as_bytes()).map_err(Into::into)
     }
 }
+
+pub struct KeyRotation<'a> {
+    pub current: &'a Keyring,
+    pub next: &'a Keyring,
+    pub current_version: u16,
+    pub next_version: u16,
+    pub cipher: EncryptionAlgorithm,
+}
+
+impl<'a> KeyRotation<'a> {
+    pub fn new(
+        current: &'a Keyring,
+        next: &'a Keyring,
+        current_version: u16,
+        next_version: u16,
+        cipher: EncryptionAlgorithm,
+    ) -> Self {
+        Self {
+            current,
+            next,
+            current_version,
+            next_version,
+            cipher,
+        }
+    }
+}
@@ -1,2 +1,3 @@
+pub mod credential;
 pub mod envelope;
 pub mod keyring;
@@ -54,6 +54,10 @@ impl VaultSession {
         &mut self.file
     }
 
+    pub fn replace_file(&mut self, file: File) {
+        self.file = file;
+    }
+
     pub fn file_path(&self) -> &PathBuf {
         &self.file_path
     }
@@ -70,6 +74,10 @@ impl VaultSession {
         &self.keyring
     }
 
+    pub fn replace_keyring(&mut self, keyring: Keyring) {
+        self.keyring = keyring;
+    }
+
     pub fn compressor(&self) -> CompressionAlgorithm {
         self.compressor
     }
@@ -82,6 +90,15 @@ impl VaultSession {
         self.format
     }
 
+    pub fn replace_format(&mut self, format: FormatRef) {
+        self.format = format;
+    }
+
+    pub fn split_format_context(&mut self) -> (&mut File, FormatContext<'_>) {
+        let context = FormatContext::new(&self.keyring, self.compressor, self.cipher);
+        (&mut self.file, context)
+    }
+
     pub fn with_format_context<T>(
         &mut self,
         callback: impl FnOnce(&mut File, &FormatContext<'_>) -> Result<T>,
@@ -1,18 +1,22 @@
 use crate::errors::{Error, Result};
 use crate::vault::versions::shared::format::FormatHandler;
 use crate::vault::versions::v1::{V1_FORMAT_VERSION, V1FormatHandler};
+use crate::vault::versions::v2::{V2_FORMAT, V2_FORMAT_VERSION};
+use crate::vault::versions::v3::{V3_FORMAT, V3_FORMAT_VERSION};
 
-pub const LATEST_FORMAT_VERSION: u16 = V1_FORMAT_VERSION;
+pub const LATEST_FORMAT_VERSION: u16 = V3_FORMAT_VERSION;
 
 pub type FormatRef = &'static dyn FormatHandler;
 
 pub fn resolve_format(version: u16) -> Result<FormatRef> {
     match version {
         V1_FORMAT_VERSION => Ok(&V1FormatHandler),
+        V2_FORMAT_VERSION => Ok(&V2_FORMAT),
+        V3_FORMAT_VERSION => Ok(&V3_FORMAT),
         _ => Err(Error::UnsupportedVaultVersion(version)),
     }
 }
 
 pub fn latest_format() -> FormatRef {
-    &V1FormatHandler
+    &V3_FORMAT
 }
@@ -1,5 +1,7 @@
 pub mod factory;
 pub mod shared;
 pub mod v1;
+pub mod v2;
+pub mod v3;
 
 pub use factory::{latest_format, resolve_format};
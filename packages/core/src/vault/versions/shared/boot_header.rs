@@ -4,6 +4,7 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use openvault_crypto::compression::CompressionAlgorithm;
 use openvault_crypto::encryption::EncryptionAlgorithm;
 use openvault_crypto::hash::Crc32Hasher;
+use openvault_crypto::keys::kdf_params::{KDF_PARAMS_SIZE, KdfParams};
 use openvault_crypto::keys::salt::{SALT_SIZE, Salt};
 
 use crate::errors::{Error, Result};
@@ -14,7 +15,11 @@ const VAULT_MAGIC: &[u8; 6] = b"OPENV0";
 const VAULT_MAGIC_SIZE: usize = VAULT_MAGIC.len();
 const CRC_SIZE: usize = 4;
 
-const VAULT_PAYLOAD_SIZE: usize = 26;
+const LEGACY_PAYLOAD_SIZE: usize = 26;
+const VAULT_PAYLOAD_SIZE: usize = LEGACY_PAYLOAD_SIZE + KDF_PARAMS_SIZE;
+const PREFIX_SIZE: usize = VAULT_MAGIC_SIZE + 2;
+
+const KDF_PARAMS_MIN_VERSION: u16 = 3;
 
 #[derive(Clone, Debug)]
 pub struct BootHeader {
@@ -23,16 +28,19 @@ pub struct BootHeader {
     pub salt: [u8; SALT_SIZE],
     pub cipher: EncryptionAlgorithm,
     pub compressor: CompressionAlgorithm,
+    pub kdf: KdfParams,
 }
 
 impl BootHeader {
     pub const SIZE: usize = VAULT_PAYLOAD_SIZE + CRC_SIZE;
+    pub const LEGACY_SIZE: usize = LEGACY_PAYLOAD_SIZE + CRC_SIZE;
 
     pub fn new(
         salt: Salt,
         version: Option<u16>,
         cipher: Option<EncryptionAlgorithm>,
         compressor: Option<CompressionAlgorithm>,
+        kdf: Option<KdfParams>,
     ) -> Self {
         Self {
             magic: *VAULT_MAGIC,
@@ -40,34 +48,77 @@ impl BootHeader {
             salt: salt.into_bytes(),
             cipher: cipher.unwrap_or(EncryptionAlgorithm::XChaCha20Poly1305),
             compressor: compressor.unwrap_or(CompressionAlgorithm::Zstd),
+            kdf: kdf.unwrap_or_default(),
         }
     }
 
-    pub fn to_bytes(&self) -> Result<[u8; Self::SIZE]> {
-        let mut bytes = [0u8; Self::SIZE];
+    pub fn stores_kdf_params(version: u16) -> bool {
+        version >= KDF_PARAMS_MIN_VERSION
+    }
+
+    // Stored params are only guarded by a checksum, they must not pick an unbounded derivation.
+    pub(crate) fn decode_kdf_params(bytes: [u8; KDF_PARAMS_SIZE]) -> Result<KdfParams> {
+        let kdf = KdfParams::from_bytes(bytes)?;
+
+        if !KdfParams::MAX.is_at_least(&kdf) {
+            return Err(Error::InvalidVaultFormat);
+        }
+
+        Ok(kdf)
+    }
+
+    pub fn size_for(version: u16) -> usize {
+        if Self::stores_kdf_params(version) {
+            Self::SIZE
+        } else {
+            Self::LEGACY_SIZE
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        Self::size_for(self.version)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload_size = self.size() - CRC_SIZE;
+        let mut bytes = vec![0u8; self.size()];
+
+        if !Self::stores_kdf_params(self.version) && self.kdf != KdfParams::LEGACY {
+            return Err(Error::KdfParamsUnsupported);
+        }
 
         {
-            let mut cursor = Cursor::new(&mut bytes[..VAULT_PAYLOAD_SIZE]);
+            let mut cursor = Cursor::new(&mut bytes[..payload_size]);
 
             cursor.write_all(&self.magic)?;
             cursor.write_all(&self.version.to_le_bytes())?;
             cursor.write_all(&self.salt)?;
             cursor.write_u8(self.cipher as u8)?;
             cursor.write_u8(self.compressor as u8)?;
+
+            if Self::stores_kdf_params(self.version) {
+                cursor.write_all(&self.kdf.to_bytes())?;
+            }
         }
 
-        let crc = Crc32Hasher::checksum(&bytes[..VAULT_PAYLOAD_SIZE]);
-        bytes[VAULT_PAYLOAD_SIZE..].copy_from_slice(&crc.to_le_bytes());
+        let crc = Crc32Hasher::checksum(&bytes[..payload_size]);
+        bytes[payload_size..].copy_from_slice(&crc.to_le_bytes());
 
         Ok(bytes)
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
-        if bytes.len() != Self::SIZE {
+        if bytes.len() < PREFIX_SIZE {
             return Err(Error::InvalidVaultFormat);
         }
 
-        let (payload, crc_bytes) = bytes.split_at(VAULT_PAYLOAD_SIZE);
+        let version = version_from_prefix(bytes);
+
+        if bytes.len() != Self::size_for(version) {
+            return Err(Error::InvalidVaultFormat);
+        }
+
+        let (payload, crc_bytes) = bytes.split_at(bytes.len() - CRC_SIZE);
 
         let stored_crc = u32::from_le_bytes(
             crc_bytes
@@ -93,18 +144,29 @@ impl BootHeader {
         let cipher = cursor.read_u8()?;
         let compressor = cursor.read_u8()?;
 
+        let kdf = if Self::stores_kdf_params(version) {
+            Self::decode_kdf_params(cursor.read_exact_arr::<KDF_PARAMS_SIZE>()?)?
+        } else {
+            KdfParams::LEGACY
+        };
+
         Ok(Self {
             magic,
             version,
             salt,
             cipher: EncryptionAlgorithm::try_from(cipher)?,
             compressor: CompressionAlgorithm::try_from(compressor)?,
+            kdf,
         })
     }
 
     pub fn read_from(reader: &mut Reader) -> Result<Self> {
         reader.seek_to_start()?;
-        let buffer = reader.read_exact_arr::<{ Self::SIZE }>()?;
+        let prefix = reader.read_exact_arr::<PREFIX_SIZE>()?;
+        let version = version_from_prefix(&prefix);
+
+        reader.seek_to_start()?;
+        let buffer = reader.read_exact_vec(Self::size_for(version))?;
         Self::from_bytes(&buffer)
     }
 
@@ -114,3 +176,7 @@ impl BootHeader {
         Ok(())
     }
 }
+
+fn version_from_prefix(prefix: &[u8]) -> u16 {
+    u16::from_le_bytes([prefix[VAULT_MAGIC_SIZE], prefix[VAULT_MAGIC_SIZE + 1]])
+}
@@ -1,11 +1,28 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::features::FeatureType;
+use crate::vault::versions::shared::Offset;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Checkpoint {
     pub features: Vec<CheckpointFeature>,
     pub sequence: u64,
+    pub previous_offset: Option<Offset>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UndatedCheckpoint {
+    pub features: Vec<CheckpointFeature>,
+    pub sequence: u64,
+    pub previous_offset: Option<Offset>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LegacyCheckpoint {
+    pub features: Vec<CheckpointFeature>,
+    pub sequence: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +37,8 @@ impl Checkpoint {
         Self {
             features,
             sequence: 0,
+            previous_offset: None,
+            created_at: None,
         }
     }
 
@@ -30,3 +49,25 @@ impl Checkpoint {
             .cloned()
     }
 }
+
+impl From<UndatedCheckpoint> for Checkpoint {
+    fn from(undated: UndatedCheckpoint) -> Self {
+        Self {
+            features: undated.features,
+            sequence: undated.sequence,
+            previous_offset: undated.previous_offset,
+            created_at: None,
+        }
+    }
+}
+
+impl From<LegacyCheckpoint> for Checkpoint {
+    fn from(legacy: LegacyCheckpoint) -> Self {
+        Self {
+            features: legacy.features,
+            sequence: legacy.sequence,
+            previous_offset: None,
+            created_at: None,
+        }
+    }
+}
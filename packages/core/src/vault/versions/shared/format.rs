@@ -4,14 +4,20 @@ use openvault_crypto::compression::CompressionAlgorithm;
 use openvault_crypto::encryption::EncryptionAlgorithm;
 
 use crate::errors::Result;
+use crate::features::chunks::{ChunkHash, ChunksStore};
 use crate::features::shared::BlobRef;
-use crate::internal::io_ext::{ReadWriter, Reader};
+use crate::internal::io_ext::{ReadSeek, ReadWriter, Reader};
+use crate::vault::crypto::credential::Credential;
 use crate::vault::crypto::keyring::Keyring;
+use crate::vault::versions::shared::boot_header::BootHeader;
 use crate::vault::versions::shared::checkpoint::Checkpoint;
 use crate::vault::versions::shared::record::Record;
-use crate::vault::versions::shared::replay::ReplayState;
+use crate::vault::versions::shared::recovery::RecoveryReport;
+use crate::vault::versions::shared::replay::{ReplayState, ReplayTarget};
 use crate::vault::versions::shared::subheader::Subheader;
+use crate::vault::versions::shared::verify::VerifyReport;
 
+#[derive(Clone, Copy)]
 pub struct FormatContext<'a> {
     pub keyring: &'a Keyring,
     pub compressor: CompressionAlgorithm,
@@ -34,9 +40,29 @@ impl<'a> FormatContext<'a> {
 
 pub type Offset = u64;
 
+pub type BlobStream<'a> = Box<dyn ReadSeek + 'a>;
+
 pub trait FormatHandler: Sync + Send {
     fn version(&self) -> u16;
 
+    fn subheader_offset(&self) -> Offset;
+
+    fn has_key_slots(&self) -> bool;
+
+    fn create_keyring(
+        &self,
+        rw: &mut ReadWriter,
+        boot_header: &BootHeader,
+        credential: &Credential,
+    ) -> Result<Keyring>;
+
+    fn unlock_keyring(
+        &self,
+        reader: &mut Reader,
+        boot_header: &BootHeader,
+        credential: &Credential,
+    ) -> Result<Keyring>;
+
     fn init_layout(&self, rw: &mut ReadWriter, context: &FormatContext) -> Result<Subheader>;
 
     fn read_subheader(&self, reader: &mut Reader, context: &FormatContext) -> Result<Subheader>;
@@ -48,6 +74,8 @@ pub trait FormatHandler: Sync + Send {
         context: &FormatContext,
     ) -> Result;
 
+    fn recover(&self, rw: &mut ReadWriter, context: &FormatContext) -> Result<RecoveryReport>;
+
     fn read_blob(
         &self,
         reader: &mut Reader,
@@ -55,13 +83,28 @@ pub trait FormatHandler: Sync + Send {
         context: &FormatContext,
     ) -> Result<Vec<u8>>;
 
+    fn open_blob<'a>(
+        &self,
+        reader: &'a mut Reader,
+        blob_ref: &BlobRef,
+        context: FormatContext<'a>,
+    ) -> Result<BlobStream<'a>>;
+
     fn write_blob(
         &self,
         rw: &mut ReadWriter,
         reader: &mut dyn Read,
         context: &FormatContext,
+        chunks: &mut ChunksStore,
     ) -> Result<BlobRef>;
 
+    fn read_blob_chunks(
+        &self,
+        reader: &mut Reader,
+        blob_ref: &BlobRef,
+        context: &FormatContext,
+    ) -> Result<Vec<(Offset, Option<ChunkHash>)>>;
+
     fn read_checkpoint(
         &self,
         reader: &mut Reader,
@@ -83,12 +126,28 @@ pub trait FormatHandler: Sync + Send {
         context: &FormatContext,
     ) -> Result<Record>;
 
+    // Bytes the record releases are counted in the same subheader write that adopts it.
     fn append_record(
         &self,
         rw: &mut ReadWriter,
         record: &mut Record,
+        released_bytes: u64,
         context: &FormatContext,
     ) -> Result<Offset>;
 
     fn replay(&self, reader: &mut Reader, context: &FormatContext) -> Result<ReplayState>;
+
+    fn replay_at(
+        &self,
+        reader: &mut Reader,
+        target: ReplayTarget,
+        context: &FormatContext,
+    ) -> Result<ReplayState>;
+
+    fn verify(
+        &self,
+        reader: &mut Reader,
+        blob_refs: &[BlobRef],
+        context: &FormatContext,
+    ) -> Result<VerifyReport>;
 }
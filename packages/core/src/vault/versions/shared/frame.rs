@@ -1,6 +1,6 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use openvault_crypto::encryption::Nonce;
-use std::io::{Read, Write};
+use std::io::{Read, Seek, SeekFrom, Write};
 
 use crate::errors::{Error, Result};
 use crate::internal::io_ext::ReadExt;
@@ -53,6 +53,12 @@ pub fn read_frame<R: Read + ?Sized>(reader: &mut R) -> Result<(FrameHeader, Vec<
     Ok((header, payload))
 }
 
+pub fn read_frame_size<R: Read + Seek + ?Sized>(reader: &mut R, offset: u64) -> Result<u64> {
+    reader.seek(SeekFrom::Start(offset))?;
+    let header = FrameHeader::read_from(reader)?;
+    Ok(FrameHeader::SIZE as u64 + header.size as u64)
+}
+
 pub fn write_frame<W: Write + ?Sized>(writer: &mut W, nonce: &Nonce, payload: &[u8]) -> Result {
     let header = FrameHeader::new(payload.len() as u32, *nonce);
     header.write_to(writer)?;
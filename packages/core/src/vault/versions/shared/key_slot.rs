@@ -0,0 +1,307 @@
+use std::io::{Cursor, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use openvault_crypto::encryption::{EncryptionAlgorithm, NONCE_SIZE, Nonce};
+use openvault_crypto::keys::derived_key::DerivedKey;
+use openvault_crypto::keys::kdf_params::{KDF_PARAMS_SIZE, KdfParams};
+use openvault_crypto::keys::master_key::{MKEY_SIZE, MasterKey};
+use openvault_crypto::keys::salt::{SALT_SIZE, Salt};
+use strum_macros::Display;
+use zeroize::Zeroize;
+
+use crate::errors::{Error, Result};
+use crate::internal::io_ext::{ReadExt, Reader, SeekExt, Writer};
+use crate::vault::crypto::credential::Credential;
+use crate::vault::versions::shared::Offset;
+use crate::vault::versions::shared::boot_header::BootHeader;
+
+pub const KEY_SLOT_COUNT: usize = 8;
+
+const KEY_WRAP_CONTEXT: &[u8] = b"openvault/keyslot/wrap";
+const KEY_WRAP_TAG_SIZE: usize = 16;
+const WRAPPED_KEY_SIZE: usize = MKEY_SIZE + KEY_WRAP_TAG_SIZE;
+
+const EMPTY_SLOT: u8 = 0;
+
+#[repr(u8)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum KeySlotKind {
+    Password = 1,
+    RecoveryKey = 2,
+    Keyfile = 3,
+}
+
+impl TryFrom<u8> for KeySlotKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::Password),
+            2 => Ok(Self::RecoveryKey),
+            3 => Ok(Self::Keyfile),
+            _ => Err(Error::InvalidVaultFormat),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct KeySlot {
+    pub kind: KeySlotKind,
+    pub salt: [u8; SALT_SIZE],
+    pub kdf: KdfParams,
+    pub nonce: Nonce,
+    pub wrapped_key: [u8; WRAPPED_KEY_SIZE],
+}
+
+impl KeySlot {
+    pub const SIZE: usize = Self::LEGACY_SIZE + KDF_PARAMS_SIZE;
+    pub const LEGACY_SIZE: usize = 1 + SALT_SIZE + NONCE_SIZE + WRAPPED_KEY_SIZE;
+
+    pub fn size_for(version: u16) -> usize {
+        if BootHeader::stores_kdf_params(version) {
+            Self::SIZE
+        } else {
+            Self::LEGACY_SIZE
+        }
+    }
+
+    pub fn wrap(
+        master: &MasterKey,
+        credential: &Credential,
+        kdf: &KdfParams,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let kind = credential.kind();
+        let salt = Salt::random();
+        let nonce = Nonce::random();
+
+        let wrapping_key = derive_wrapping_key(credential.secret(), &salt, kdf)?;
+
+        let wrapped = cipher.resolve().encrypt(
+            wrapping_key.as_bytes(),
+            &nonce,
+            master.as_bytes(),
+            &wrap_aad(kind),
+        )?;
+
+        let wrapped_key = wrapped.try_into().map_err(|_| Error::InvalidVaultFormat)?;
+
+        Ok(Self {
+            kind,
+            salt: salt.into_bytes(),
+            kdf: *kdf,
+            nonce,
+            wrapped_key,
+        })
+    }
+
+    pub fn unwrap(
+        &self,
+        credential: &Credential,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<MasterKey> {
+        if credential.kind() != self.kind {
+            return Err(Error::UnlockFailed);
+        }
+
+        let wrapping_key =
+            derive_wrapping_key(credential.secret(), &Salt::from(self.salt), &self.kdf)?;
+
+        let mut raw = cipher.resolve().decrypt(
+            wrapping_key.as_bytes(),
+            &self.nonce,
+            &self.wrapped_key,
+            &wrap_aad(self.kind),
+        )?;
+
+        let master = raw
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::InvalidVaultFormat)
+            .and_then(|bytes| MasterKey::new(bytes).map_err(Into::into));
+        raw.zeroize();
+
+        master
+    }
+
+    fn to_bytes(&self, version: u16) -> Result<Vec<u8>> {
+        let stores_kdf = BootHeader::stores_kdf_params(version);
+        if !stores_kdf && self.kdf != KdfParams::LEGACY {
+            return Err(Error::KdfParamsUnsupported);
+        }
+
+        let mut bytes = vec![0u8; Self::size_for(version)];
+        let mut cursor = Cursor::new(&mut bytes[..]);
+
+        cursor.write_u8(self.kind as u8)?;
+        cursor.write_all(&self.salt)?;
+        if stores_kdf {
+            cursor.write_all(&self.kdf.to_bytes())?;
+        }
+        cursor.write_all(self.nonce.as_bytes())?;
+        cursor.write_all(&self.wrapped_key)?;
+
+        Ok(bytes)
+    }
+
+    fn from_bytes(bytes: &[u8], version: u16) -> Result<Option<Self>> {
+        let mut cursor = Cursor::new(bytes);
+
+        let kind = cursor.read_u8()?;
+
+        if kind == EMPTY_SLOT {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            kind: KeySlotKind::try_from(kind)?,
+            salt: cursor.read_exact_arr::<SALT_SIZE>()?,
+            kdf: if BootHeader::stores_kdf_params(version) {
+                BootHeader::decode_kdf_params(cursor.read_exact_arr::<KDF_PARAMS_SIZE>()?)?
+            } else {
+                KdfParams::LEGACY
+            },
+            nonce: Nonce::read_from(&mut cursor)?,
+            wrapped_key: cursor.read_exact_arr::<WRAPPED_KEY_SIZE>()?,
+        }))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct KeySlotTable {
+    version: u16,
+    slots: [Option<KeySlot>; KEY_SLOT_COUNT],
+}
+
+impl KeySlotTable {
+    pub fn new(version: u16) -> Self {
+        Self {
+            version,
+            slots: Default::default(),
+        }
+    }
+
+    pub fn offset_for(version: u16) -> Offset {
+        BootHeader::size_for(version) as Offset
+    }
+
+    pub fn size_for(version: u16) -> usize {
+        KEY_SLOT_COUNT * KeySlot::size_for(version)
+    }
+
+    pub fn end_offset_for(version: u16) -> Offset {
+        Self::offset_for(version) + Self::size_for(version) as Offset
+    }
+
+    pub fn slots(&self) -> impl Iterator<Item = (usize, &KeySlot)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.as_ref().map(|slot| (index, slot)))
+    }
+
+    pub fn slot(&self, index: usize) -> Option<&KeySlot> {
+        self.slots.get(index).and_then(Option::as_ref)
+    }
+
+    pub fn insert(&mut self, slot: KeySlot) -> Result<usize> {
+        let index = self
+            .slots
+            .iter()
+            .position(Option::is_none)
+            .ok_or(Error::KeySlotsFull)?;
+
+        self.slots[index] = Some(slot);
+
+        Ok(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.slots.iter().all(Option::is_some)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<KeySlot> {
+        self.slots
+            .get_mut(index)
+            .and_then(Option::take)
+            .ok_or(Error::KeySlotNotFound(index))
+    }
+
+    pub fn replace(&mut self, index: usize, slot: KeySlot) -> Result<KeySlot> {
+        self.slots
+            .get_mut(index)
+            .and_then(|current| current.replace(slot))
+            .ok_or(Error::KeySlotNotFound(index))
+    }
+
+    pub fn unlock(
+        &self,
+        credential: &Credential,
+        cipher: EncryptionAlgorithm,
+    ) -> Result<(usize, MasterKey)> {
+        for (index, slot) in self.slots() {
+            if slot.kind != credential.kind() {
+                continue;
+            }
+
+            if let Ok(master) = slot.unwrap(credential, cipher) {
+                return Ok((index, master));
+            }
+        }
+
+        Err(Error::UnlockFailed)
+    }
+
+    pub fn read_from(reader: &mut Reader, version: u16) -> Result<Self> {
+        reader.seek_from_start(Self::offset_for(version))?;
+        let buffer = reader.read_exact_vec(Self::size_for(version))?;
+
+        let mut table = Self::new(version);
+
+        for (index, chunk) in buffer.chunks_exact(KeySlot::size_for(version)).enumerate() {
+            table.slots[index] = KeySlot::from_bytes(chunk, version)?;
+        }
+
+        Ok(table)
+    }
+
+    pub fn write_to(&self, writer: &mut Writer) -> Result {
+        for index in 0..KEY_SLOT_COUNT {
+            self.write_slot(writer, index)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_slot(&self, writer: &mut Writer, index: usize) -> Result {
+        let slot_size = KeySlot::size_for(self.version);
+        let bytes = match self.slot(index) {
+            Some(slot) => slot.to_bytes(self.version)?,
+            None => vec![EMPTY_SLOT; slot_size],
+        };
+
+        writer.seek_from_start(Self::offset_for(self.version) + (index * slot_size) as Offset)?;
+        writer.write_all(&bytes)?;
+
+        Ok(())
+    }
+}
+
+fn derive_wrapping_key(secret: &[u8], salt: &Salt, kdf: &KdfParams) -> Result<DerivedKey> {
+    let kek = MasterKey::derive_with_params(secret, salt, kdf)?;
+    kek.expand(KEY_WRAP_CONTEXT).map_err(Into::into)
+}
+
+fn wrap_aad(kind: KeySlotKind) -> Vec<u8> {
+    let mut aad = KEY_WRAP_CONTEXT.to_vec();
+    aad.push(kind as u8);
+    aad
+}
@@ -2,8 +2,11 @@ pub mod boot_header;
 pub mod checkpoint;
 pub mod format;
 pub mod frame;
+pub mod key_slot;
 pub mod record;
+pub mod recovery;
 pub mod replay;
 pub mod subheader;
+pub mod verify;
 
 pub use format::Offset;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::features::FeatureType;
@@ -27,6 +28,13 @@ impl RecordHeader {
 pub struct Record {
     pub header: RecordHeader,
     pub payload: Vec<u8>,
+    pub recorded_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LegacyRecord {
+    pub header: RecordHeader,
+    pub payload: Vec<u8>,
 }
 
 impl Record {
@@ -34,6 +42,17 @@ impl Record {
         Self {
             header: RecordHeader::new(feature_type, version),
             payload,
+            recorded_at: None,
+        }
+    }
+}
+
+impl From<LegacyRecord> for Record {
+    fn from(legacy: LegacyRecord) -> Self {
+        Self {
+            header: legacy.header,
+            payload: legacy.payload,
+            recorded_at: None,
         }
     }
 }
@@ -0,0 +1,14 @@
+use crate::vault::versions::shared::Offset;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecoveryReport {
+    pub subheader_rebuilt: bool,
+    pub adopted_frames: usize,
+    pub truncated_at: Option<Offset>,
+}
+
+impl RecoveryReport {
+    pub fn is_clean(&self) -> bool {
+        !self.subheader_rebuilt && self.adopted_frames == 0 && self.truncated_at.is_none()
+    }
+}
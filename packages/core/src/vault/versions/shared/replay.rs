@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use crate::vault::versions::shared::checkpoint::Checkpoint;
 use crate::vault::versions::shared::record::RecordHeader;
 use crate::vault::versions::shared::subheader::Subheader;
@@ -7,18 +9,31 @@ pub struct ReplayRecord {
     pub offset: u64,
     pub header: RecordHeader,
     pub payload: Vec<u8>,
+    pub recorded_at: Option<DateTime<Utc>>,
 }
 
 impl ReplayRecord {
-    pub fn new(offset: u64, header: RecordHeader, payload: Vec<u8>) -> Self {
+    pub fn new(
+        offset: u64,
+        header: RecordHeader,
+        payload: Vec<u8>,
+        recorded_at: Option<DateTime<Utc>>,
+    ) -> Self {
         Self {
             offset,
             header,
             payload,
+            recorded_at,
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayTarget {
+    Sequence(u64),
+    Timestamp(DateTime<Utc>),
+}
+
 #[derive(Debug)]
 pub struct ReplayState {
     pub subheader: Subheader,
@@ -9,10 +9,12 @@ pub struct Subheader {
     pub tail_record_offset: u64,
     pub reclaimable_bytes: u64,
     pub last_sequence: u64,
+    pub generation: u64,
 }
 
 impl Subheader {
-    pub const SIZE: usize = 32;
+    pub const SIZE: usize = 40;
+    pub const LEGACY_SIZE: usize = 32;
 
     pub fn new(checkpoint_offset: u64, tail_record_offset: u64) -> Self {
         Self {
@@ -20,20 +22,34 @@ impl Subheader {
             tail_record_offset,
             reclaimable_bytes: 0,
             last_sequence: 0,
+            generation: 0,
         }
     }
 
     pub fn read_from<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut subheader = Self::read_legacy_from(reader)?;
+        subheader.generation = reader.read_u64::<LittleEndian>()?;
+        Ok(subheader)
+    }
+
+    pub fn read_legacy_from<R: Read>(reader: &mut R) -> Result<Self> {
         let subheader = Self {
             checkpoint_offset: reader.read_u64::<LittleEndian>()?,
             tail_record_offset: reader.read_u64::<LittleEndian>()?,
             reclaimable_bytes: reader.read_u64::<LittleEndian>()?,
             last_sequence: reader.read_u64::<LittleEndian>()?,
+            generation: 0,
         };
         Ok(subheader)
     }
 
     pub fn write_to<W: Write>(self, writer: &mut W) -> Result {
+        self.write_legacy_to(writer)?;
+        writer.write_u64::<LittleEndian>(self.generation)?;
+        Ok(())
+    }
+
+    pub fn write_legacy_to<W: Write>(self, writer: &mut W) -> Result {
         writer.write_u64::<LittleEndian>(self.checkpoint_offset)?;
         writer.write_u64::<LittleEndian>(self.tail_record_offset)?;
         writer.write_u64::<LittleEndian>(self.reclaimable_bytes)?;
@@ -43,6 +59,10 @@ impl Subheader {
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
         let mut reader = bytes;
+
+        if bytes.len() == Self::LEGACY_SIZE {
+            return Subheader::read_legacy_from(&mut reader);
+        }
         Subheader::read_from(&mut reader)
     }
 
@@ -52,4 +72,11 @@ impl Subheader {
         self.write_to(&mut writer)?;
         Ok(buffer)
     }
+
+    pub fn to_legacy_bytes(self) -> Result<[u8; Self::LEGACY_SIZE]> {
+        let mut buffer = [0u8; Self::LEGACY_SIZE];
+        let mut writer = &mut buffer[..];
+        self.write_legacy_to(&mut writer)?;
+        Ok(buffer)
+    }
 }
@@ -0,0 +1,72 @@
+use strum_macros::Display;
+use uuid::Uuid;
+
+use crate::vault::versions::shared::Offset;
+
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+pub enum FrameKind {
+    Subheader,
+    Checkpoint,
+    Record,
+    BlobManifest,
+    BlobChunk,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BrokenFrame {
+    pub offset: Offset,
+    pub size: u64,
+    pub expected: Option<FrameKind>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SequenceGap {
+    pub offset: Offset,
+    pub expected: u64,
+    pub found: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DanglingBlob {
+    pub blob_id: Uuid,
+    pub manifest_offset: Offset,
+    pub missing_chunks: Vec<Offset>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrphanedFrame {
+    pub offset: Offset,
+    pub size: u64,
+    pub kind: FrameKind,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    pub version: u16,
+    pub boot_header_valid: bool,
+    pub subheader_valid: bool,
+    pub frames_scanned: usize,
+    pub records_checked: usize,
+    pub checkpoints_checked: usize,
+    pub blobs_checked: usize,
+    pub truncated_at: Option<Offset>,
+    pub replay_error: Option<String>,
+    pub broken_frames: Vec<BrokenFrame>,
+    pub sequence_gaps: Vec<SequenceGap>,
+    pub dangling_blobs: Vec<DanglingBlob>,
+    pub orphaned_frames: Vec<OrphanedFrame>,
+    pub recorded_reclaimable_bytes: u64,
+    pub found_reclaimable_bytes: u64,
+}
+
+impl VerifyReport {
+    pub fn is_healthy(&self) -> bool {
+        self.boot_header_valid
+            && self.subheader_valid
+            && self.truncated_at.is_none()
+            && self.replay_error.is_none()
+            && self.broken_frames.is_empty()
+            && self.sequence_gaps.is_empty()
+            && self.dangling_blobs.is_empty()
+    }
+}
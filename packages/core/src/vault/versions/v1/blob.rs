@@ -1,15 +1,22 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::features::chunks::ChunkHash;
+
+pub const HASHED_MANIFEST_VERSION: u16 = 2;
+// Chunk hashes from here on are keyed with the vault's own format version.
+pub const VERSIONED_HASH_MANIFEST_VERSION: u16 = 3;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlobChunkMeta {
     pub offset: u64,
     pub size: u32,
+    pub hash: Option<ChunkHash>,
 }
 
 impl BlobChunkMeta {
-    pub fn new(offset: u64, size: u32) -> Self {
-        Self { offset, size }
+    pub fn new(offset: u64, size: u32, hash: Option<ChunkHash>) -> Self {
+        Self { offset, size, hash }
     }
 }
 
@@ -21,3 +28,34 @@ pub struct BlobManifest {
     pub chunk_size: u32,
     pub chunks: Vec<BlobChunkMeta>,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegacyBlobChunkMeta {
+    pub offset: u64,
+    pub size: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegacyBlobManifest {
+    pub id: Uuid,
+    pub version: u16,
+    pub size_bytes: u64,
+    pub chunk_size: u32,
+    pub chunks: Vec<LegacyBlobChunkMeta>,
+}
+
+impl From<LegacyBlobManifest> for BlobManifest {
+    fn from(legacy: LegacyBlobManifest) -> Self {
+        Self {
+            id: legacy.id,
+            version: legacy.version,
+            size_bytes: legacy.size_bytes,
+            chunk_size: legacy.chunk_size,
+            chunks: legacy
+                .chunks
+                .into_iter()
+                .map(|chunk| BlobChunkMeta::new(chunk.offset, chunk.size, None))
+                .collect(),
+        }
+    }
+}
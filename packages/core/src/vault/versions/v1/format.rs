@@ -1,16 +1,27 @@
 use std::io::Read;
 
+use openvault_crypto::keys::salt::Salt;
+
 use super::io;
-use super::replay::replay_records;
-use crate::errors::Result;
+use super::recovery::recover_tail;
+use super::replay::{replay_records, replay_records_at};
+use super::verify::verify_frames;
+use crate::errors::{Error, Result};
+use crate::features::chunks::{ChunkHash, ChunksStore};
 use crate::features::shared::BlobRef;
 use crate::internal::io_ext::{ReadWriter, Reader};
+use crate::vault::crypto::credential::Credential;
+use crate::vault::crypto::keyring::Keyring;
 use crate::vault::versions::shared::Offset;
+use crate::vault::versions::shared::boot_header::BootHeader;
 use crate::vault::versions::shared::checkpoint::Checkpoint;
-use crate::vault::versions::shared::format::{FormatContext, FormatHandler};
+use crate::vault::versions::shared::format::{BlobStream, FormatContext, FormatHandler};
+use crate::vault::versions::shared::key_slot::KeySlotKind;
 use crate::vault::versions::shared::record::Record;
-use crate::vault::versions::shared::replay::ReplayState;
+use crate::vault::versions::shared::recovery::RecoveryReport;
+use crate::vault::versions::shared::replay::{ReplayState, ReplayTarget};
 use crate::vault::versions::shared::subheader::Subheader;
+use crate::vault::versions::shared::verify::VerifyReport;
 
 pub const V1_FORMAT_VERSION: u16 = 1;
 
@@ -22,12 +33,42 @@ impl FormatHandler for V1FormatHandler {
         V1_FORMAT_VERSION
     }
 
+    fn subheader_offset(&self) -> Offset {
+        io::SUBHEADER_OFFSET
+    }
+
+    fn has_key_slots(&self) -> bool {
+        false
+    }
+
+    fn create_keyring(
+        &self,
+        _rw: &mut ReadWriter,
+        boot_header: &BootHeader,
+        credential: &Credential,
+    ) -> Result<Keyring> {
+        derive_password_keyring(boot_header, credential)
+    }
+
+    fn unlock_keyring(
+        &self,
+        _reader: &mut Reader,
+        boot_header: &BootHeader,
+        credential: &Credential,
+    ) -> Result<Keyring> {
+        derive_password_keyring(boot_header, credential)
+    }
+
     fn init_layout(&self, rw: &mut ReadWriter, context: &FormatContext) -> Result<Subheader> {
-        io::init_layout(rw, context)
+        io::init_layout(rw, io::SUBHEADER_OFFSET, context)
     }
 
     fn read_subheader(&self, reader: &mut Reader, context: &FormatContext) -> Result<Subheader> {
-        io::read_subheader(reader, context)
+        io::read_subheader(reader, io::SUBHEADER_OFFSET, context)
+    }
+
+    fn recover(&self, rw: &mut ReadWriter, context: &FormatContext) -> Result<RecoveryReport> {
+        recover_tail(rw, io::SUBHEADER_OFFSET, context)
     }
 
     fn read_blob(
@@ -36,7 +77,21 @@ impl FormatHandler for V1FormatHandler {
         blob_ref: &BlobRef,
         context: &FormatContext,
     ) -> Result<Vec<u8>> {
-        io::read_blob(reader, blob_ref, context)
+        io::read_blob(reader, blob_ref, V1_FORMAT_VERSION, context)
+    }
+
+    fn open_blob<'a>(
+        &self,
+        reader: &'a mut Reader,
+        blob_ref: &BlobRef,
+        context: FormatContext<'a>,
+    ) -> Result<BlobStream<'a>> {
+        Ok(Box::new(io::BlobReader::open(
+            reader,
+            blob_ref,
+            V1_FORMAT_VERSION,
+            context,
+        )?))
     }
 
     fn write_blob(
@@ -44,8 +99,18 @@ impl FormatHandler for V1FormatHandler {
         rw: &mut ReadWriter,
         reader: &mut dyn Read,
         context: &FormatContext,
+        chunks: &mut ChunksStore,
     ) -> Result<BlobRef> {
-        io::write_blob(rw, reader, context)
+        io::write_blob(rw, reader, V1_FORMAT_VERSION, context, chunks)
+    }
+
+    fn read_blob_chunks(
+        &self,
+        reader: &mut Reader,
+        blob_ref: &BlobRef,
+        context: &FormatContext,
+    ) -> Result<Vec<(Offset, Option<ChunkHash>)>> {
+        io::read_blob_chunks(reader, blob_ref, context)
     }
 
     fn write_subheader(
@@ -54,7 +119,7 @@ impl FormatHandler for V1FormatHandler {
         subheader: &Subheader,
         context: &FormatContext,
     ) -> Result {
-        io::write_subheader(rw, subheader, context)
+        io::write_subheader(rw, io::SUBHEADER_OFFSET, subheader, context)
     }
 
     fn read_checkpoint(
@@ -72,16 +137,17 @@ impl FormatHandler for V1FormatHandler {
         checkpoint: &mut Checkpoint,
         context: &FormatContext,
     ) -> Result<Offset> {
-        io::write_checkpoint(rw, checkpoint, context)
+        io::write_checkpoint(rw, io::SUBHEADER_OFFSET, checkpoint, context)
     }
 
     fn append_record(
         &self,
         rw: &mut ReadWriter,
         record: &mut Record,
+        released_bytes: u64,
         context: &FormatContext,
     ) -> Result<Offset> {
-        io::append_record(rw, record, context)
+        io::append_record(rw, io::SUBHEADER_OFFSET, record, released_bytes, context)
     }
 
     fn read_record(
@@ -94,6 +160,42 @@ impl FormatHandler for V1FormatHandler {
     }
 
     fn replay(&self, reader: &mut Reader, context: &FormatContext) -> Result<ReplayState> {
-        replay_records(reader, context)
+        replay_records(reader, io::SUBHEADER_OFFSET, context)
+    }
+
+    fn replay_at(
+        &self,
+        reader: &mut Reader,
+        target: ReplayTarget,
+        context: &FormatContext,
+    ) -> Result<ReplayState> {
+        replay_records_at(reader, io::SUBHEADER_OFFSET, target, context)
     }
+
+    fn verify(
+        &self,
+        reader: &mut Reader,
+        blob_refs: &[BlobRef],
+        context: &FormatContext,
+    ) -> Result<VerifyReport> {
+        verify_frames(
+            reader,
+            io::SUBHEADER_OFFSET,
+            blob_refs,
+            V1_FORMAT_VERSION,
+            context,
+        )
+    }
+}
+
+fn derive_password_keyring(boot_header: &BootHeader, credential: &Credential) -> Result<Keyring> {
+    if credential.kind() != KeySlotKind::Password {
+        return Err(Error::KeySlotsUnsupported);
+    }
+
+    Keyring::derive(
+        credential.secret(),
+        &Salt::from(boot_header.salt),
+        &boot_header.kdf,
+    )
 }
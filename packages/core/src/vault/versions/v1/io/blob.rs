@@ -1,30 +1,65 @@
-use std::io::Read;
+use openvault_crypto::hash::HmacSha256;
+use openvault_crypto::keys::derived_key::DerivedKey;
+use std::io::{self, Read, Seek, SeekFrom};
 use uuid::Uuid;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::errors::{Error, Result};
+use crate::features::chunks::{ChunkHash, ChunksStore};
 use crate::features::shared::BlobRef;
 use crate::internal::io_ext::{ReadWriter, Reader, SeekExt};
+use crate::vault::versions::shared::Offset;
 use crate::vault::versions::shared::format::FormatContext;
-use crate::vault::versions::v1::blob::{BlobChunkMeta, BlobManifest};
+use crate::vault::versions::v1::V1_FORMAT_VERSION;
+use crate::vault::versions::v1::blob::{
+    BlobChunkMeta, BlobManifest, HASHED_MANIFEST_VERSION, VERSIONED_HASH_MANIFEST_VERSION,
+};
 use crate::vault::versions::v1::io::aad::AadDomain;
 use crate::vault::versions::v1::io::frame::{open_frame, seal_frame};
 use crate::vault::versions::v1::mapper::{decode_manifest, encode_manifest};
 
 const BLOB_CHUNK_SIZE: usize = 256 * 1024;
-const BLOB_MANIFEST_VERSION: u16 = 1;
+const BLOB_MANIFEST_VERSION: u16 = VERSIONED_HASH_MANIFEST_VERSION;
 
 pub fn write_blob(
+    rw: &mut ReadWriter,
+    reader: impl Read,
+    version: u16,
+    context: &FormatContext,
+    chunks: &mut ChunksStore,
+) -> Result<BlobRef> {
+    let start = rw.seek_to_end()?;
+    let mut metas = Vec::new();
+
+    let result = write_blob_frames(rw, reader, version, context, chunks, &mut metas);
+
+    // A source that fails halfway leaves no blob to release, so undo what it retained here
+    // and count everything it appended as reclaimable.
+    if result.is_err() {
+        for hash in metas.iter().filter_map(|meta| meta.hash) {
+            chunks.release(hash);
+        }
+        let end = rw.seek_to_end()?;
+        chunks.add_released_bytes(end - start);
+    }
+
+    result
+}
+
+fn write_blob_frames(
     rw: &mut ReadWriter,
     mut reader: impl Read,
+    version: u16,
     context: &FormatContext,
+    chunks: &mut ChunksStore,
+    metas: &mut Vec<BlobChunkMeta>,
 ) -> Result<BlobRef> {
-    rw.seek_to_end()?;
+    let hash_key = context.keyring.derive_chunk_hash_key(version)?;
 
-    let mut chunks = Vec::new();
     let mut total_size = 0u64;
 
     loop {
-        let mut buffer = Vec::with_capacity(BLOB_CHUNK_SIZE);
+        let mut buffer = Zeroizing::new(Vec::with_capacity(BLOB_CHUNK_SIZE));
 
         let n = reader
             .by_ref()
@@ -35,8 +70,15 @@ pub fn write_blob(
             break;
         }
 
-        let offset = seal_frame(rw, AadDomain::BlobChunk, &buffer, context)?;
-        chunks.push(BlobChunkMeta::new(offset, n as u32));
+        let hash = ChunkHash(HmacSha256::mac(hash_key.as_bytes(), &buffer)?);
+
+        let offset = match chunks.find(&hash) {
+            Some(entry) if entry.size as u64 == n => entry.offset,
+            _ => seal_frame(rw, AadDomain::BlobChunk, &buffer, context)?,
+        };
+
+        chunks.retain(hash, offset, n as u32);
+        metas.push(BlobChunkMeta::new(offset, n as u32, Some(hash)));
         total_size += n;
     }
 
@@ -46,7 +88,7 @@ pub fn write_blob(
         id,
         size_bytes: total_size,
         chunk_size: BLOB_CHUNK_SIZE as u32,
-        chunks,
+        chunks: metas.clone(),
     };
 
     let manifest_bytes = encode_manifest(&manifest)?;
@@ -55,11 +97,162 @@ pub fn write_blob(
     Ok(BlobRef::new(id, total_size, manifest_offset))
 }
 
+pub fn read_blob_chunks(
+    reader: &mut Reader,
+    blob_ref: &BlobRef,
+    context: &FormatContext,
+) -> Result<Vec<(Offset, Option<ChunkHash>)>> {
+    let manifest = read_manifest(reader, blob_ref, context)?;
+
+    Ok(manifest
+        .chunks
+        .iter()
+        .map(|chunk| (chunk.offset, chunk.hash))
+        .collect())
+}
+
 pub fn read_blob(
     reader: &mut Reader,
     blob_ref: &BlobRef,
+    version: u16,
     context: &FormatContext,
 ) -> Result<Vec<u8>> {
+    let manifest = read_manifest(reader, blob_ref, context)?;
+    let hash_key = chunk_hash_key(&manifest, version, context)?;
+
+    let mut blob = Vec::with_capacity(manifest.size_bytes as usize);
+
+    for chunk in &manifest.chunks {
+        blob.extend(read_chunk(reader, chunk, hash_key.as_ref(), context)?);
+    }
+
+    Ok(blob)
+}
+
+pub struct BlobReader<'a> {
+    reader: &'a mut Reader,
+    context: FormatContext<'a>,
+    manifest: BlobManifest,
+    hash_key: Option<DerivedKey>,
+    chunk_starts: Vec<u64>,
+    position: u64,
+    current: Option<(usize, Vec<u8>)>,
+}
+
+impl<'a> BlobReader<'a> {
+    pub fn open(
+        reader: &'a mut Reader,
+        blob_ref: &BlobRef,
+        version: u16,
+        context: FormatContext<'a>,
+    ) -> Result<Self> {
+        let manifest = read_manifest(reader, blob_ref, &context)?;
+        let hash_key = chunk_hash_key(&manifest, version, &context)?;
+
+        let mut chunk_starts = Vec::with_capacity(manifest.chunks.len());
+        let mut total_size = 0u64;
+
+        for chunk in &manifest.chunks {
+            chunk_starts.push(total_size);
+            total_size += chunk.size as u64;
+        }
+
+        if total_size != manifest.size_bytes {
+            return Err(Error::InvalidVaultFormat);
+        }
+
+        Ok(Self {
+            reader,
+            context,
+            manifest,
+            hash_key,
+            chunk_starts,
+            position: 0,
+            current: None,
+        })
+    }
+
+    fn size(&self) -> u64 {
+        self.manifest.size_bytes
+    }
+
+    fn load_chunk(&mut self, index: usize) -> Result<&[u8]> {
+        let bytes = match self.current.take() {
+            Some((loaded, bytes)) if loaded == index => bytes,
+            previous => {
+                if let Some((_, mut bytes)) = previous {
+                    bytes.zeroize();
+                }
+
+                read_chunk(
+                    self.reader,
+                    &self.manifest.chunks[index],
+                    self.hash_key.as_ref(),
+                    &self.context,
+                )?
+            }
+        };
+
+        let (_, bytes) = self.current.insert((index, bytes));
+
+        Ok(bytes)
+    }
+}
+
+impl Read for BlobReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size() {
+            return Ok(0);
+        }
+
+        let index = self
+            .chunk_starts
+            .partition_point(|start| *start <= self.position)
+            - 1;
+        let chunk_offset = (self.position - self.chunk_starts[index]) as usize;
+
+        let chunk = self.load_chunk(index).map_err(io::Error::other)?;
+        let n = buf.len().min(chunk.len() - chunk_offset);
+        buf[..n].copy_from_slice(&chunk[chunk_offset..chunk_offset + n]);
+
+        self.position += n as u64;
+
+        Ok(n)
+    }
+}
+
+impl Seek for BlobReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.size().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+
+        self.position = position.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )
+        })?;
+
+        Ok(self.position)
+    }
+}
+
+impl Drop for BlobReader<'_> {
+    fn drop(&mut self) {
+        if let Some((_, bytes)) = self.current.as_mut() {
+            bytes.zeroize();
+        }
+    }
+}
+
+pub(crate) fn read_manifest(
+    reader: &mut Reader,
+    blob_ref: &BlobRef,
+    context: &FormatContext,
+) -> Result<BlobManifest> {
     reader.seek_from_start(blob_ref.manifest_offset)?;
 
     let manifest_bytes = open_frame(reader, AadDomain::BlobManifest, context)?;
@@ -69,40 +262,77 @@ pub fn read_blob(
         return Err(Error::InvalidVaultFormat);
     }
 
-    let mut blob = Vec::with_capacity(manifest.size_bytes as usize);
+    Ok(manifest)
+}
 
-    for chunk in &manifest.chunks {
-        reader.seek_from_start(chunk.offset)?;
+// Manifests written before hashes were keyed per format version used the v1 context throughout.
+pub(crate) fn chunk_hash_key(
+    manifest: &BlobManifest,
+    version: u16,
+    context: &FormatContext,
+) -> Result<Option<DerivedKey>> {
+    let key_version = match manifest.version {
+        v if v < HASHED_MANIFEST_VERSION => return Ok(None),
+        HASHED_MANIFEST_VERSION => V1_FORMAT_VERSION,
+        _ => version,
+    };
 
-        let chunk_bytes = open_frame(reader, AadDomain::BlobChunk, context)?;
+    Ok(Some(context.keyring.derive_chunk_hash_key(key_version)?))
+}
 
-        if chunk_bytes.len() != chunk.size as usize {
-            return Err(Error::InvalidVaultFormat);
-        }
+// Chunk frames are authenticated on their own, the keyed hash also binds each one to its manifest.
+pub(crate) fn read_chunk(
+    reader: &mut Reader,
+    chunk: &BlobChunkMeta,
+    hash_key: Option<&DerivedKey>,
+    context: &FormatContext,
+) -> Result<Vec<u8>> {
+    reader.seek_from_start(chunk.offset)?;
+
+    let mut chunk_bytes = open_frame(reader, AadDomain::BlobChunk, context)?;
+
+    if chunk_bytes.len() != chunk.size as usize {
+        chunk_bytes.zeroize();
+        return Err(Error::InvalidVaultFormat);
+    }
 
-        blob.extend(chunk_bytes);
+    if let (Some(key), Some(expected)) = (hash_key, chunk.hash)
+        && ChunkHash(HmacSha256::mac(key.as_bytes(), &chunk_bytes)?) != expected
+    {
+        chunk_bytes.zeroize();
+        return Err(Error::InvalidVaultChecksum);
     }
 
-    Ok(blob)
+    Ok(chunk_bytes)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use std::io::{Cursor, Read, Seek, SeekFrom};
 
     use openvault_crypto::compression::CompressionAlgorithm;
     use openvault_crypto::encryption::EncryptionAlgorithm;
+    use openvault_crypto::keys::kdf_params::KdfParams;
     use openvault_crypto::keys::salt::Salt;
 
+    use crate::features::chunks::ChunksStore;
     use crate::vault::crypto::keyring::Keyring;
     use crate::vault::versions::shared::format::FormatContext;
-    use crate::vault::versions::v1::io::init_layout;
+    use crate::vault::versions::v1::io::{SUBHEADER_OFFSET, init_layout};
 
-    use super::{read_blob, write_blob};
+    use crate::errors::Error;
+    use crate::features::shared::BlobRef;
+    use crate::vault::versions::v1::V1_FORMAT_VERSION;
+    use crate::vault::versions::v1::io::aad::AadDomain;
+    use crate::vault::versions::v1::io::frame::seal_frame;
+    use crate::vault::versions::v1::mapper::encode_manifest;
+
+    use super::{BlobReader, read_blob, read_manifest, write_blob};
 
     fn test_keyring() -> Keyring {
         let salt = Salt::random();
-        Keyring::derive(b"test-password", &salt).expect("failed to derive test keyring")
+        Keyring::derive(b"test-password", &salt, &KdfParams::default())
+            .expect("failed to derive test keyring")
     }
 
     #[test]
@@ -114,15 +344,23 @@ mod tests {
             CompressionAlgorithm::default(),
             EncryptionAlgorithm::default(),
         );
-        init_layout(&mut io, &context).expect("init layout");
+        init_layout(&mut io, SUBHEADER_OFFSET, &context).expect("init layout");
 
         let mut payload = Vec::new();
         for i in 0..(512 * 1024 + 157) {
             payload.push((i % 251) as u8);
         }
 
-        let blob_ref = write_blob(&mut io, &mut payload.as_slice(), &context).expect("write blob");
-        let restored = read_blob(&mut io, &blob_ref, &context).expect("read blob");
+        let blob_ref = write_blob(
+            &mut io,
+            &mut payload.as_slice(),
+            V1_FORMAT_VERSION,
+            &context,
+            &mut ChunksStore::new(),
+        )
+        .expect("write blob");
+        let restored =
+            read_blob(&mut io, &blob_ref, V1_FORMAT_VERSION, &context).expect("read blob");
 
         assert_eq!(restored, payload);
     }
@@ -136,13 +374,121 @@ mod tests {
             CompressionAlgorithm::default(),
             EncryptionAlgorithm::default(),
         );
-        init_layout(&mut io, &context).expect("init layout");
+        init_layout(&mut io, SUBHEADER_OFFSET, &context).expect("init layout");
 
         let payload = vec![1, 2, 3, 4, 5];
 
-        let blob_ref = write_blob(&mut io, &mut payload.as_slice(), &context).expect("write blob");
-        let restored = read_blob(&mut io, &blob_ref, &context).expect("read blob");
+        let blob_ref = write_blob(
+            &mut io,
+            &mut payload.as_slice(),
+            V1_FORMAT_VERSION,
+            &context,
+            &mut ChunksStore::new(),
+        )
+        .expect("write blob");
+        let restored =
+            read_blob(&mut io, &blob_ref, V1_FORMAT_VERSION, &context).expect("read blob");
+
+        assert_eq!(restored, payload);
+    }
+
+    #[test]
+    fn blob_reader_seeks_across_chunks() {
+        let keyring = test_keyring();
+        let mut io = Cursor::new(Vec::new());
+        let context = FormatContext::new(
+            &keyring,
+            CompressionAlgorithm::default(),
+            EncryptionAlgorithm::default(),
+        );
+        init_layout(&mut io, SUBHEADER_OFFSET, &context).expect("init layout");
+
+        let payload: Vec<u8> = (0..(600 * 1024)).map(|i| (i % 251) as u8).collect();
+        let blob_ref = write_blob(
+            &mut io,
+            &mut payload.as_slice(),
+            V1_FORMAT_VERSION,
+            &context,
+            &mut ChunksStore::new(),
+        )
+        .expect("write blob");
+
+        let mut reader =
+            BlobReader::open(&mut io, &blob_ref, V1_FORMAT_VERSION, context).expect("open blob");
+
+        let start = 256 * 1024 - 10;
+        reader.seek(SeekFrom::Start(start)).expect("seek");
+        let mut window = vec![0u8; 20];
+        reader
+            .read_exact(&mut window)
+            .expect("read across chunk boundary");
+        assert_eq!(window, payload[start as usize..start as usize + 20]);
+
+        reader.seek(SeekFrom::End(-5)).expect("seek from end");
+        let mut tail = Vec::new();
+        reader.read_to_end(&mut tail).expect("read tail");
+        assert_eq!(tail, payload[payload.len() - 5..]);
+
+        assert!(
+            reader
+                .seek(SeekFrom::Current(-(payload.len() as i64) - 1))
+                .is_err()
+        );
 
+        reader.seek(SeekFrom::Start(0)).expect("rewind");
+        let mut restored = Vec::new();
+        reader.read_to_end(&mut restored).expect("read all");
         assert_eq!(restored, payload);
     }
+
+    #[test]
+    fn chunks_must_match_their_manifest_hash() {
+        let keyring = test_keyring();
+        let mut io = Cursor::new(Vec::new());
+        let context = FormatContext::new(
+            &keyring,
+            CompressionAlgorithm::default(),
+            EncryptionAlgorithm::default(),
+        );
+        init_layout(&mut io, SUBHEADER_OFFSET, &context).expect("init layout");
+
+        let mut chunks = ChunksStore::new();
+        let first = write_blob(
+            &mut io,
+            &mut [1u8; 64].as_slice(),
+            V1_FORMAT_VERSION,
+            &context,
+            &mut chunks,
+        )
+        .expect("write first blob");
+        let second = write_blob(
+            &mut io,
+            &mut [2u8; 64].as_slice(),
+            V1_FORMAT_VERSION,
+            &context,
+            &mut chunks,
+        )
+        .expect("write second blob");
+
+        // A manifest that keeps the first chunk's hash but points at the second chunk's frame.
+        let mut manifest = read_manifest(&mut io, &first, &context).expect("read manifest");
+        manifest.chunks[0].offset = read_manifest(&mut io, &second, &context)
+            .expect("read manifest")
+            .chunks[0]
+            .offset;
+        let manifest_bytes = encode_manifest(&manifest).expect("encode manifest");
+        let manifest_offset =
+            seal_frame(&mut io, AadDomain::BlobManifest, &manifest_bytes, &context)
+                .expect("seal manifest");
+        let swapped = BlobRef::new(first.id, first.size_bytes, manifest_offset);
+
+        assert!(matches!(
+            read_blob(&mut io, &swapped, V1_FORMAT_VERSION, &context),
+            Err(Error::InvalidVaultChecksum)
+        ));
+
+        let mut reader =
+            BlobReader::open(&mut io, &swapped, V1_FORMAT_VERSION, context).expect("open blob");
+        assert!(reader.read_to_end(&mut Vec::new()).is_err());
+    }
 }
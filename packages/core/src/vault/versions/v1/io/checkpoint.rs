@@ -1,30 +1,40 @@
+use chrono::Utc;
+
 use crate::errors::Result;
 use crate::internal::io_ext::{ReadWriter, Reader, SeekExt};
 use crate::vault::versions::shared::Offset;
 use crate::vault::versions::shared::checkpoint::Checkpoint;
 use crate::vault::versions::shared::format::FormatContext;
+use crate::vault::versions::shared::frame::read_frame_size;
+use crate::vault::versions::shared::subheader::Subheader;
 use crate::vault::versions::v1::io::aad::AadDomain;
 use crate::vault::versions::v1::io::frame::{open_frame, seal_frame};
+use crate::vault::versions::v1::io::record::read_record;
 use crate::vault::versions::v1::io::subheader::{read_subheader, write_subheader};
 use crate::vault::versions::v1::mapper::{decode_checkpoint, encode_checkpoint};
 
 pub fn write_checkpoint(
     rw: &mut ReadWriter,
+    subheader_offset: Offset,
     checkpoint: &mut Checkpoint,
     context: &FormatContext,
 ) -> Result<Offset> {
-    let mut subheader = read_subheader(rw, context)?;
+    let mut subheader = read_subheader(rw, subheader_offset, context)?;
+
+    let superseded = superseded_bytes(rw, &subheader, context)?;
 
     rw.seek_to_end()?;
 
     checkpoint.sequence = subheader.last_sequence;
+    checkpoint.previous_offset = Some(subheader.checkpoint_offset);
+    checkpoint.created_at = Some(Utc::now());
 
     let checkpoint_bytes = encode_checkpoint(checkpoint)?;
     let offset = seal_frame(rw, AadDomain::Checkpoint, &checkpoint_bytes, context)?;
 
     subheader.checkpoint_offset = offset;
-    subheader.reclaimable_bytes = 0;
-    write_subheader(rw, &subheader, context)?;
+    subheader.reclaimable_bytes += superseded;
+    write_subheader(rw, subheader_offset, &subheader, context)?;
 
     Ok(offset)
 }
@@ -38,3 +48,27 @@ pub fn read_checkpoint(
     let checkpoint_bytes = open_frame(reader, AadDomain::Checkpoint, context)?;
     decode_checkpoint(&checkpoint_bytes)
 }
+
+// Bytes that stop being live once a new checkpoint lands: the previous checkpoint
+// and every record it did not already cover.
+pub fn superseded_bytes(
+    reader: &mut Reader,
+    subheader: &Subheader,
+    context: &FormatContext,
+) -> Result<u64> {
+    let mut bytes = 0;
+
+    if subheader.checkpoint_offset != 0 {
+        bytes += read_frame_size(reader, subheader.checkpoint_offset)?;
+    }
+
+    let mut offset = subheader.tail_record_offset;
+    while offset != 0 && offset > subheader.checkpoint_offset {
+        bytes += read_frame_size(reader, offset)?;
+        offset = read_record(reader, offset, context)?
+            .header
+            .prev_record_offset;
+    }
+
+    Ok(bytes)
+}
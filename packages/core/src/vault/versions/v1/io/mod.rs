@@ -5,18 +5,26 @@ pub mod frame;
 pub mod record;
 pub mod subheader;
 
-pub use blob::{read_blob, write_blob};
+pub use blob::{BlobReader, read_blob, read_blob_chunks, write_blob};
 pub use checkpoint::{read_checkpoint, write_checkpoint};
 pub use record::{append_record, read_record};
-pub use subheader::{read_subheader, write_subheader};
+pub use subheader::{
+    SUBHEADER_OFFSET, init_subheader_slots, read_subheader, read_subheader_slots, write_subheader,
+    write_subheader_slot,
+};
 
 use crate::errors::Result;
 use crate::internal::io_ext::ReadWriter;
+use crate::vault::versions::shared::Offset;
 use crate::vault::versions::shared::format::FormatContext;
 use crate::vault::versions::shared::subheader::Subheader;
 
-pub fn init_layout(rw: &mut ReadWriter, context: &FormatContext) -> Result<Subheader> {
+pub fn init_layout(
+    rw: &mut ReadWriter,
+    subheader_offset: Offset,
+    context: &FormatContext,
+) -> Result<Subheader> {
     let subheader = Subheader::default();
-    write_subheader(rw, &subheader, context)?;
+    init_subheader_slots(rw, subheader_offset, &subheader, context)?;
     Ok(subheader)
 }
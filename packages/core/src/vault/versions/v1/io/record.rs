@@ -1,3 +1,5 @@
+use chrono::Utc;
+
 use crate::errors::{Error, Result};
 use crate::internal::io_ext::{ReadWriter, Reader, SeekExt};
 use crate::vault::versions::shared::Offset;
@@ -10,23 +12,27 @@ use crate::vault::versions::v1::mapper::{decode_record, encode_record};
 
 pub fn append_record(
     rw: &mut ReadWriter,
+    subheader_offset: Offset,
     record: &mut Record,
+    released_bytes: u64,
     context: &FormatContext,
 ) -> Result<Offset> {
-    let mut subheader = read_subheader(rw, context)?;
+    let mut subheader = read_subheader(rw, subheader_offset, context)?;
 
     rw.seek_to_end()?;
 
     record.header.sequence = subheader.last_sequence + 1;
     record.header.prev_record_offset = subheader.tail_record_offset;
+    record.recorded_at = Some(Utc::now());
 
     let record_bytes = encode_record(record)?;
     let record_offset = seal_frame(rw, AadDomain::Record, &record_bytes, context)?;
 
     subheader.tail_record_offset = record_offset;
     subheader.last_sequence += 1;
+    subheader.reclaimable_bytes += released_bytes;
 
-    write_subheader(rw, &subheader, context)?;
+    write_subheader(rw, subheader_offset, &subheader, context)?;
 
     Ok(record_offset)
 }
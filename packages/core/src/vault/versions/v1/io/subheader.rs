@@ -1,38 +1,202 @@
 use openvault_crypto::encryption::Nonce;
 
-use crate::errors::Result;
-use crate::internal::io_ext::{ReadWriter, Reader, SeekExt};
+use crate::errors::{Error, Result};
+use crate::internal::io_ext::{ReadExt, ReadWriter, Reader, SeekExt};
+use crate::vault::versions::shared::Offset;
 use crate::vault::versions::shared::boot_header::BootHeader;
 use crate::vault::versions::shared::format::FormatContext;
-use crate::vault::versions::shared::frame::{read_frame, write_frame};
+use crate::vault::versions::shared::frame::{FrameHeader, write_frame};
 use crate::vault::versions::shared::subheader::Subheader;
 use crate::vault::versions::v1::io::aad::AadDomain;
 
-pub const SUBHEADER_OFFSET: u64 = BootHeader::SIZE as u64;
+pub const SUBHEADER_OFFSET: u64 = BootHeader::LEGACY_SIZE as u64;
 
-pub fn write_subheader(rw: &mut ReadWriter, data: &Subheader, context: &FormatContext) -> Result {
+pub const SUBHEADER_SLOT_SIZE: u64 = 128;
+pub const SUBHEADER_SLOT_COUNT: u64 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubheaderLayout {
+    Single,
+    Dual,
+}
+
+#[derive(Clone, Debug)]
+pub struct SubheaderSlots {
+    pub layout: SubheaderLayout,
+    pub frames_offset: Offset,
+    slots: [Option<Subheader>; 2],
+}
+
+impl SubheaderSlots {
+    pub fn current(&self) -> Option<Subheader> {
+        self.slots
+            .iter()
+            .flatten()
+            .max_by_key(|subheader| subheader.generation)
+            .copied()
+    }
+
+    fn next_slot(&self) -> (usize, u64) {
+        match self.slots {
+            [Some(first), Some(second)] if first.generation > second.generation => {
+                (1, first.generation + 1)
+            }
+            [Some(_), Some(second)] => (0, second.generation + 1),
+            [Some(first), None] => (1, first.generation + 1),
+            [None, Some(second)] => (0, second.generation + 1),
+            [None, None] => (0, 1),
+        }
+    }
+}
+
+pub fn init_subheader_slots(
+    rw: &mut ReadWriter,
+    subheader_offset: Offset,
+    data: &Subheader,
+    context: &FormatContext,
+) -> Result {
+    let region = vec![0u8; (SUBHEADER_SLOT_SIZE * SUBHEADER_SLOT_COUNT) as usize];
+
+    rw.seek_from_start(subheader_offset)?;
+    rw.write_all(&region)?;
+
+    let mut subheader = *data;
+    for (slot, generation) in [(1, 0), (0, 1)] {
+        subheader.generation = generation;
+        seal_slot(
+            rw,
+            slot_offset(subheader_offset, slot),
+            &subheader.to_bytes()?,
+            context,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn write_subheader(
+    rw: &mut ReadWriter,
+    subheader_offset: Offset,
+    data: &Subheader,
+    context: &FormatContext,
+) -> Result {
+    let slots = read_subheader_slots(rw, subheader_offset, context)?;
+    write_subheader_slot(rw, subheader_offset, &slots, data, context)
+}
+
+pub fn write_subheader_slot(
+    rw: &mut ReadWriter,
+    subheader_offset: Offset,
+    slots: &SubheaderSlots,
+    data: &Subheader,
+    context: &FormatContext,
+) -> Result {
+    if slots.layout == SubheaderLayout::Single {
+        return seal_slot(rw, subheader_offset, &data.to_legacy_bytes()?, context);
+    }
+
+    let (slot, generation) = slots.next_slot();
+
+    let mut subheader = *data;
+    subheader.generation = generation;
+
+    seal_slot(
+        rw,
+        slot_offset(subheader_offset, slot),
+        &subheader.to_bytes()?,
+        context,
+    )
+}
+
+pub fn read_subheader(
+    reader: &mut Reader,
+    subheader_offset: Offset,
+    context: &FormatContext,
+) -> Result<Subheader> {
+    let slots = read_subheader_slots(reader, subheader_offset, context)?;
+
+    match slots.current() {
+        Some(subheader) => Ok(subheader),
+        None => open_slot(reader, subheader_offset, context),
+    }
+}
+
+pub fn read_subheader_slots(
+    reader: &mut Reader,
+    subheader_offset: Offset,
+    context: &FormatContext,
+) -> Result<SubheaderSlots> {
+    reader.seek_from_start(subheader_offset)?;
+    let frame = FrameHeader::read_from(reader)?;
+
+    let first = open_slot(reader, subheader_offset, context).ok();
+
+    if frame.size as usize == Subheader::LEGACY_SIZE + seal_overhead(context)? {
+        return Ok(SubheaderSlots {
+            layout: SubheaderLayout::Single,
+            frames_offset: subheader_offset + FrameHeader::SIZE as u64 + frame.size as u64,
+            slots: [first, None],
+        });
+    }
+
+    let second = open_slot(reader, slot_offset(subheader_offset, 1), context).ok();
+
+    Ok(SubheaderSlots {
+        layout: SubheaderLayout::Dual,
+        frames_offset: slot_offset(subheader_offset, SUBHEADER_SLOT_COUNT as usize),
+        slots: [first, second],
+    })
+}
+
+fn slot_offset(subheader_offset: Offset, slot: usize) -> Offset {
+    subheader_offset + SUBHEADER_SLOT_SIZE * slot as u64
+}
+
+fn seal_overhead(context: &FormatContext) -> Result<usize> {
+    let key = AadDomain::Subheader.derive_key(context.keyring)?;
+    let cipher = context.cipher.resolve();
+
+    Ok(cipher
+        .encrypt(key.as_bytes(), &Nonce::random(), &[], &[])?
+        .len())
+}
+
+fn seal_slot(
+    rw: &mut ReadWriter,
+    slot_offset: Offset,
+    plaintext: &[u8],
+    context: &FormatContext,
+) -> Result {
     let aad_domain = AadDomain::Subheader;
 
     let nonce = Nonce::random();
-    let aad = aad_domain.encode(SUBHEADER_OFFSET);
+    let aad = aad_domain.encode(slot_offset);
     let key = aad_domain.derive_key(context.keyring)?;
 
     let cipher = context.cipher.resolve();
-    let ciphertext = cipher.encrypt(key.as_bytes(), &nonce, &data.to_bytes()?, &aad)?;
+    let ciphertext = cipher.encrypt(key.as_bytes(), &nonce, plaintext, &aad)?;
 
-    rw.seek_from_start(SUBHEADER_OFFSET)?;
+    rw.seek_from_start(slot_offset)?;
 
     write_frame(rw, &nonce, &ciphertext)
 }
 
-pub fn read_subheader(reader: &mut Reader, context: &FormatContext) -> Result<Subheader> {
-    reader.seek_from_start(SUBHEADER_OFFSET)?;
+fn open_slot(
+    reader: &mut Reader,
+    slot_offset: Offset,
+    context: &FormatContext,
+) -> Result<Subheader> {
+    reader.seek_from_start(slot_offset)?;
 
-    let (frame, ciphertext) = read_frame(reader)?;
+    let frame = FrameHeader::read_from(reader)?;
+    if frame.size as u64 > SUBHEADER_SLOT_SIZE - FrameHeader::SIZE as u64 {
+        return Err(Error::InvalidVaultFormat);
+    }
+    let ciphertext = reader.read_exact_vec(frame.size as usize)?;
 
     let aad_domain = AadDomain::Subheader;
 
-    let aad = aad_domain.encode(SUBHEADER_OFFSET);
+    let aad = aad_domain.encode(slot_offset);
     let key = aad_domain.derive_key(context.keyring)?;
 
     let cipher = context.cipher.resolve();
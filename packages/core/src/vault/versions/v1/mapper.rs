@@ -1,14 +1,16 @@
 use crate::errors::{Error, Result};
-use crate::vault::versions::shared::checkpoint::Checkpoint;
-use crate::vault::versions::shared::record::Record;
-use crate::vault::versions::v1::blob::BlobManifest;
+use crate::vault::versions::shared::checkpoint::{Checkpoint, LegacyCheckpoint, UndatedCheckpoint};
+use crate::vault::versions::shared::record::{LegacyRecord, Record};
+use crate::vault::versions::v1::blob::{BlobManifest, HASHED_MANIFEST_VERSION, LegacyBlobManifest};
 
 pub fn encode_record(record: &Record) -> Result<Vec<u8>> {
     postcard::to_allocvec(record).map_err(|_| Error::InvalidVaultFormat)
 }
 
 pub fn decode_record(bytes: &[u8]) -> Result<Record> {
-    postcard::from_bytes(bytes).map_err(|_| Error::InvalidVaultFormat)
+    postcard::from_bytes::<Record>(bytes)
+        .or_else(|_| postcard::from_bytes::<LegacyRecord>(bytes).map(Into::into))
+        .map_err(|_| Error::InvalidVaultFormat)
 }
 
 pub fn encode_checkpoint(checkpoint: &Checkpoint) -> Result<Vec<u8>> {
@@ -16,7 +18,10 @@ pub fn encode_checkpoint(checkpoint: &Checkpoint) -> Result<Vec<u8>> {
 }
 
 pub fn decode_checkpoint(bytes: &[u8]) -> Result<Checkpoint> {
-    postcard::from_bytes(bytes).map_err(|_| Error::InvalidVaultFormat)
+    postcard::from_bytes::<Checkpoint>(bytes)
+        .or_else(|_| postcard::from_bytes::<UndatedCheckpoint>(bytes).map(Into::into))
+        .or_else(|_| postcard::from_bytes::<LegacyCheckpoint>(bytes).map(Into::into))
+        .map_err(|_| Error::InvalidVaultFormat)
 }
 
 pub fn encode_manifest(manifest: &BlobManifest) -> Result<Vec<u8>> {
@@ -24,5 +29,10 @@ pub fn encode_manifest(manifest: &BlobManifest) -> Result<Vec<u8>> {
 }
 
 pub fn decode_manifest(bytes: &[u8]) -> Result<BlobManifest> {
-    postcard::from_bytes(bytes).map_err(|_| Error::InvalidVaultFormat)
+    match postcard::from_bytes::<BlobManifest>(bytes) {
+        Ok(manifest) if manifest.version >= HASHED_MANIFEST_VERSION => Ok(manifest),
+        _ => postcard::from_bytes::<LegacyBlobManifest>(bytes)
+            .map(Into::into)
+            .map_err(|_| Error::InvalidVaultFormat),
+    }
 }
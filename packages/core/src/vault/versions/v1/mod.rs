@@ -1,7 +1,9 @@
 mod blob;
 mod format;
-mod io;
+pub(crate) mod io;
 mod mapper;
-mod replay;
+pub(crate) mod recovery;
+pub(crate) mod replay;
+pub(crate) mod verify;
 
 pub use format::{V1_FORMAT_VERSION, V1FormatHandler};
@@ -0,0 +1,175 @@
+use crate::errors::{Error, Result};
+use crate::internal::io_ext::{ReadWriter, Reader, SeekExt};
+use crate::vault::versions::shared::Offset;
+use crate::vault::versions::shared::checkpoint::Checkpoint;
+use crate::vault::versions::shared::format::FormatContext;
+use crate::vault::versions::shared::frame::FrameHeader;
+use crate::vault::versions::shared::record::Record;
+use crate::vault::versions::shared::recovery::RecoveryReport;
+use crate::vault::versions::shared::subheader::Subheader;
+use crate::vault::versions::v1::io::aad::AadDomain;
+use crate::vault::versions::v1::io::checkpoint::superseded_bytes;
+use crate::vault::versions::v1::io::frame::open_frame;
+use crate::vault::versions::v1::io::{read_subheader_slots, write_subheader_slot};
+use crate::vault::versions::v1::mapper::{decode_checkpoint, decode_record};
+
+enum TrailingFrame {
+    Record(Record),
+    Checkpoint(Checkpoint),
+    Blob,
+}
+
+pub fn recover_tail(
+    rw: &mut ReadWriter,
+    subheader_offset: Offset,
+    context: &FormatContext,
+) -> Result<RecoveryReport> {
+    let slots = read_subheader_slots(rw, subheader_offset, context)?;
+    let mut report = RecoveryReport::default();
+
+    let (mut subheader, mut offset) = match slots.current() {
+        Some(subheader) => {
+            let start = scan_start(rw, &subheader, slots.frames_offset)?;
+            (subheader, start)
+        }
+        None => {
+            report.subheader_rebuilt = true;
+            (Subheader::default(), slots.frames_offset)
+        }
+    };
+    let recorded = subheader;
+
+    let file_len = rw.seek_to_end()?;
+    let mut authenticated = false;
+
+    while offset < file_len {
+        let Some(size) = frame_size(rw, offset, file_len)? else {
+            report.truncated_at = Some(offset);
+            break;
+        };
+
+        let Some(frame) = open_trailing_frame(rw, offset, context) else {
+            // Only a torn tail may be cut. A later frame that still authenticates
+            // means this one is damaged in place, and truncating would drop it too.
+            if authenticates_after(rw, offset + size, file_len, context)? {
+                return Err(Error::DamagedFrame(offset));
+            }
+            report.truncated_at = Some(offset);
+            break;
+        };
+        authenticated = true;
+
+        match frame {
+            TrailingFrame::Record(record) => {
+                if record.header.prev_record_offset == subheader.tail_record_offset
+                    && record.header.sequence == subheader.last_sequence + 1
+                {
+                    subheader.tail_record_offset = offset;
+                    subheader.last_sequence += 1;
+                    report.adopted_frames += 1;
+                }
+            }
+            TrailingFrame::Checkpoint(checkpoint) => {
+                if checkpoint.sequence == subheader.last_sequence
+                    && checkpoint
+                        .previous_offset
+                        .is_none_or(|previous| previous == subheader.checkpoint_offset)
+                {
+                    subheader.reclaimable_bytes += superseded_bytes(rw, &subheader, context)?;
+                    subheader.checkpoint_offset = offset;
+                    report.adopted_frames += 1;
+                }
+            }
+            TrailingFrame::Blob => {}
+        }
+
+        offset += size;
+    }
+
+    if report.subheader_rebuilt && !authenticated {
+        return Err(Error::UnlockFailed);
+    }
+
+    if report.subheader_rebuilt || subheader != recorded {
+        write_subheader_slot(rw, subheader_offset, &slots, &subheader, context)?;
+    }
+
+    Ok(report)
+}
+
+fn scan_start(reader: &mut Reader, subheader: &Subheader, frames_offset: Offset) -> Result<Offset> {
+    let mut start = frames_offset;
+
+    for offset in [subheader.tail_record_offset, subheader.checkpoint_offset] {
+        if offset == 0 {
+            continue;
+        }
+
+        reader.seek_from_start(offset)?;
+        let header = FrameHeader::read_from(reader)?;
+        start = start.max(offset + FrameHeader::SIZE as u64 + header.size as u64);
+    }
+
+    Ok(start)
+}
+
+fn frame_size(reader: &mut Reader, offset: Offset, file_len: u64) -> Result<Option<u64>> {
+    if offset + FrameHeader::SIZE as u64 > file_len {
+        return Ok(None);
+    }
+
+    reader.seek_from_start(offset)?;
+    let header = FrameHeader::read_from(reader)?;
+    let size = FrameHeader::SIZE as u64 + header.size as u64;
+
+    Ok((offset + size <= file_len).then_some(size))
+}
+
+fn authenticates_after(
+    reader: &mut Reader,
+    mut offset: Offset,
+    file_len: u64,
+    context: &FormatContext,
+) -> Result<bool> {
+    while let Some(size) = frame_size(reader, offset, file_len)? {
+        if open_trailing_frame(reader, offset, context).is_some() {
+            return Ok(true);
+        }
+        offset += size;
+    }
+
+    Ok(false)
+}
+
+fn open_trailing_frame(
+    reader: &mut Reader,
+    offset: Offset,
+    context: &FormatContext,
+) -> Option<TrailingFrame> {
+    if let Ok(record) = open_frame_at(reader, offset, AadDomain::Record, context)
+        .and_then(|bytes| decode_record(&bytes))
+    {
+        return Some(TrailingFrame::Record(record));
+    }
+
+    if let Ok(checkpoint) = open_frame_at(reader, offset, AadDomain::Checkpoint, context)
+        .and_then(|bytes| decode_checkpoint(&bytes))
+    {
+        return Some(TrailingFrame::Checkpoint(checkpoint));
+    }
+
+    [AadDomain::BlobManifest, AadDomain::BlobChunk]
+        .into_iter()
+        .any(|domain| open_frame_at(reader, offset, domain, context).is_ok())
+        .then_some(TrailingFrame::Blob)
+}
+
+fn open_frame_at(
+    reader: &mut Reader,
+    offset: Offset,
+    domain: AadDomain,
+    context: &FormatContext,
+) -> Result<Vec<u8>> {
+    reader.seek_from_start(offset)?;
+    open_frame(reader, domain, context)
+}
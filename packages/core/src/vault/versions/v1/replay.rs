@@ -1,12 +1,22 @@
-use crate::errors::Result;
+use chrono::{DateTime, Utc};
+
+use crate::errors::{Error, Result};
 use crate::internal::io_ext::Reader;
+use crate::vault::versions::shared::Offset;
+use crate::vault::versions::shared::checkpoint::Checkpoint;
 use crate::vault::versions::shared::format::FormatContext;
-use crate::vault::versions::shared::replay::{ReplayRecord, ReplayState};
+use crate::vault::versions::shared::record::Record;
+use crate::vault::versions::shared::replay::{ReplayRecord, ReplayState, ReplayTarget};
+use crate::vault::versions::shared::subheader::Subheader;
 use crate::vault::versions::v1::io::record::read_replay_records;
 use crate::vault::versions::v1::io::{read_checkpoint, read_subheader};
 
-pub fn replay_records(reader: &mut Reader, context: &FormatContext) -> Result<ReplayState> {
-    let subheader = read_subheader(reader, context)?;
+pub fn replay_records(
+    reader: &mut Reader,
+    subheader_offset: Offset,
+    context: &FormatContext,
+) -> Result<ReplayState> {
+    let subheader = read_subheader(reader, subheader_offset, context)?;
 
     let checkpoint = (subheader.checkpoint_offset != 0)
         .then(|| read_checkpoint(reader, subheader.checkpoint_offset, context))
@@ -19,10 +29,128 @@ pub fn replay_records(reader: &mut Reader, context: &FormatContext) -> Result<Re
         context,
     )?;
 
+    let records = records.into_iter().map(into_replay_record).collect();
+
+    Ok(ReplayState::new(subheader, checkpoint, records))
+}
+
+pub fn replay_records_at(
+    reader: &mut Reader,
+    subheader_offset: Offset,
+    target: ReplayTarget,
+    context: &FormatContext,
+) -> Result<ReplayState> {
+    let subheader = read_subheader(reader, subheader_offset, context)?;
+    let records = read_replay_records(reader, subheader.tail_record_offset, 0, context)?;
+
+    let sequence = match target {
+        ReplayTarget::Sequence(sequence) => sequence,
+        ReplayTarget::Timestamp(timestamp) => {
+            resolve_sequence(reader, &subheader, &records, timestamp, context)?
+        }
+    };
+
+    if sequence > subheader.last_sequence {
+        return Err(Error::HistoryUnavailable);
+    }
+
+    let checkpoint = find_base_checkpoint(reader, subheader.checkpoint_offset, sequence, context)?;
+    let base_sequence = match &checkpoint {
+        Some(checkpoint) => checkpoint.sequence,
+        // Below the oldest checkpoint only while compaction has not dropped its records.
+        None if subheader.last_sequence == 0 || has_first_record(&records) => 0,
+        None => return Err(Error::HistoryUnavailable),
+    };
+
     let records = records
         .into_iter()
-        .map(|(offset, record)| ReplayRecord::new(offset, record.header, record.payload))
+        .filter(|(_, record)| {
+            record.header.sequence > base_sequence && record.header.sequence <= sequence
+        })
+        .map(into_replay_record)
         .collect();
 
     Ok(ReplayState::new(subheader, checkpoint, records))
 }
+
+fn into_replay_record((offset, record): (Offset, Record)) -> ReplayRecord {
+    ReplayRecord::new(offset, record.header, record.payload, record.recorded_at)
+}
+
+fn has_first_record(records: &[(Offset, Record)]) -> bool {
+    records
+        .iter()
+        .any(|(_, record)| record.header.sequence == 1)
+}
+
+// Records and checkpoints carry the time they were written, older ones do not. A
+// timestamp past every dated point resolves to the newest state, which also covers
+// undated history and a freshly compacted vault with no later records.
+fn resolve_sequence(
+    reader: &mut Reader,
+    subheader: &Subheader,
+    records: &[(Offset, Record)],
+    timestamp: DateTime<Utc>,
+    context: &FormatContext,
+) -> Result<u64> {
+    let mut points: Vec<_> = records
+        .iter()
+        .filter_map(|(_, record)| record.recorded_at.map(|at| (record.header.sequence, at)))
+        .collect();
+    points.extend(checkpoint_times(
+        reader,
+        subheader.checkpoint_offset,
+        context,
+    )?);
+
+    if points.iter().all(|(_, at)| *at <= timestamp) {
+        return Ok(subheader.last_sequence);
+    }
+
+    points
+        .into_iter()
+        .filter(|(_, at)| *at <= timestamp)
+        .map(|(sequence, _)| sequence)
+        .max()
+        .ok_or(Error::HistoryUnavailable)
+}
+
+fn checkpoint_times(
+    reader: &mut Reader,
+    checkpoint_offset: Offset,
+    context: &FormatContext,
+) -> Result<Vec<(u64, DateTime<Utc>)>> {
+    let mut times = Vec::new();
+    let mut offset = checkpoint_offset;
+
+    while offset != 0 {
+        let checkpoint = read_checkpoint(reader, offset, context)?;
+        times.extend(checkpoint.created_at.map(|at| (checkpoint.sequence, at)));
+        offset = checkpoint.previous_offset.unwrap_or(0);
+    }
+
+    Ok(times)
+}
+
+fn find_base_checkpoint(
+    reader: &mut Reader,
+    checkpoint_offset: Offset,
+    sequence: u64,
+    context: &FormatContext,
+) -> Result<Option<Checkpoint>> {
+    let mut offset = checkpoint_offset;
+
+    while offset != 0 {
+        let checkpoint = read_checkpoint(reader, offset, context)?;
+
+        if checkpoint.sequence <= sequence {
+            return Ok(Some(checkpoint));
+        }
+
+        offset = checkpoint
+            .previous_offset
+            .ok_or(Error::HistoryUnavailable)?;
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,316 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::errors::Result;
+use crate::features::shared::BlobRef;
+use crate::internal::io_ext::{Reader, SeekExt};
+use crate::vault::versions::shared::Offset;
+use crate::vault::versions::shared::format::FormatContext;
+use crate::vault::versions::shared::frame::FrameHeader;
+use crate::vault::versions::shared::subheader::Subheader;
+use crate::vault::versions::shared::verify::{
+    BrokenFrame, DanglingBlob, FrameKind, OrphanedFrame, SequenceGap, VerifyReport,
+};
+use crate::vault::versions::v1::io::aad::AadDomain;
+use crate::vault::versions::v1::io::blob::{chunk_hash_key, read_chunk, read_manifest};
+use crate::vault::versions::v1::io::frame::open_frame;
+use crate::vault::versions::v1::io::read_subheader_slots;
+use crate::vault::versions::v1::mapper::{decode_checkpoint, decode_record};
+
+const FRAME_DOMAINS: [(FrameKind, AadDomain); 4] = [
+    (FrameKind::BlobChunk, AadDomain::BlobChunk),
+    (FrameKind::BlobManifest, AadDomain::BlobManifest),
+    (FrameKind::Record, AadDomain::Record),
+    (FrameKind::Checkpoint, AadDomain::Checkpoint),
+];
+
+struct ScannedFrame {
+    kind: Option<FrameKind>,
+    size: u64,
+}
+
+pub fn verify_frames(
+    reader: &mut Reader,
+    subheader_offset: Offset,
+    blob_refs: &[BlobRef],
+    version: u16,
+    context: &FormatContext,
+) -> Result<VerifyReport> {
+    let mut report = VerifyReport::default();
+
+    let slots = read_subheader_slots(reader, subheader_offset, context)?;
+    let Some(subheader) = slots.current() else {
+        return Ok(report);
+    };
+    report.subheader_valid = true;
+    report.recorded_reclaimable_bytes = subheader.reclaimable_bytes;
+
+    let frames = scan_frames(reader, slots.frames_offset, context, &mut report)?;
+
+    let (checkpoints, base_sequence) =
+        verify_checkpoint_chain(reader, &subheader, &frames, context, &mut report)?;
+    let records = verify_record_chain(
+        reader,
+        &subheader,
+        base_sequence,
+        &frames,
+        context,
+        &mut report,
+    )?;
+    let blobs = verify_blobs(reader, blob_refs, version, context, &mut report)?;
+
+    let mut live: HashSet<Offset> = records
+        .iter()
+        .copied()
+        .filter(|offset| *offset > subheader.checkpoint_offset)
+        .chain(blobs.iter().copied())
+        .collect();
+    live.insert(subheader.checkpoint_offset);
+
+    let referenced: HashSet<Offset> = records
+        .into_iter()
+        .chain(checkpoints)
+        .chain(blobs)
+        .collect();
+
+    for (offset, frame) in &frames {
+        if !live.contains(offset) {
+            report.found_reclaimable_bytes += frame.size;
+        }
+
+        if let Some(kind) = frame.kind
+            && !referenced.contains(offset)
+        {
+            report.orphaned_frames.push(OrphanedFrame {
+                offset: *offset,
+                size: frame.size,
+                kind,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+fn scan_frames(
+    reader: &mut Reader,
+    frames_offset: Offset,
+    context: &FormatContext,
+    report: &mut VerifyReport,
+) -> Result<BTreeMap<Offset, ScannedFrame>> {
+    let file_len = reader.seek_to_end()?;
+
+    let mut offset = frames_offset;
+    let mut frames = BTreeMap::new();
+
+    while offset < file_len {
+        if offset + FrameHeader::SIZE as u64 > file_len {
+            report.truncated_at = Some(offset);
+            break;
+        }
+
+        reader.seek_from_start(offset)?;
+        let header = FrameHeader::read_from(reader)?;
+        let size = FrameHeader::SIZE as u64 + header.size as u64;
+
+        if offset + size > file_len {
+            report.truncated_at = Some(offset);
+            break;
+        }
+
+        let kind = FRAME_DOMAINS
+            .iter()
+            .find(|(_, domain)| open_frame_at(reader, offset, *domain, context).is_ok())
+            .map(|(kind, _)| *kind);
+
+        if kind.is_none() {
+            report.broken_frames.push(BrokenFrame {
+                offset,
+                size,
+                expected: None,
+            });
+        }
+
+        frames.insert(offset, ScannedFrame { kind, size });
+        offset += size;
+    }
+
+    report.frames_scanned = frames.len();
+
+    Ok(frames)
+}
+
+fn verify_checkpoint_chain(
+    reader: &mut Reader,
+    subheader: &Subheader,
+    frames: &BTreeMap<Offset, ScannedFrame>,
+    context: &FormatContext,
+    report: &mut VerifyReport,
+) -> Result<(Vec<Offset>, u64)> {
+    let mut offsets = Vec::new();
+    let mut offset = subheader.checkpoint_offset;
+    let mut base_sequence = 0;
+
+    while offset != 0 {
+        let checkpoint = open_frame_at(reader, offset, AadDomain::Checkpoint, context)
+            .and_then(|bytes| decode_checkpoint(&bytes));
+
+        let Ok(checkpoint) = checkpoint else {
+            mark_broken(report, frames, offset, FrameKind::Checkpoint);
+            break;
+        };
+
+        offsets.push(offset);
+        base_sequence = checkpoint.sequence;
+
+        match checkpoint.previous_offset {
+            Some(previous) if previous < offset => offset = previous,
+            Some(0) | None => break,
+            Some(previous) => {
+                mark_broken(report, frames, previous, FrameKind::Checkpoint);
+                break;
+            }
+        }
+    }
+
+    report.checkpoints_checked = offsets.len();
+
+    Ok((offsets, base_sequence))
+}
+
+fn verify_record_chain(
+    reader: &mut Reader,
+    subheader: &Subheader,
+    base_sequence: u64,
+    frames: &BTreeMap<Offset, ScannedFrame>,
+    context: &FormatContext,
+    report: &mut VerifyReport,
+) -> Result<Vec<Offset>> {
+    let mut offsets = Vec::new();
+    let mut offset = subheader.tail_record_offset;
+    let mut expected = subheader.last_sequence;
+
+    while offset != 0 {
+        let record = open_frame_at(reader, offset, AadDomain::Record, context)
+            .and_then(|bytes| decode_record(&bytes));
+
+        let Ok(record) = record else {
+            mark_broken(report, frames, offset, FrameKind::Record);
+            break;
+        };
+
+        let sequence = record.header.sequence;
+        if sequence != expected {
+            report.sequence_gaps.push(SequenceGap {
+                offset,
+                expected,
+                found: sequence,
+            });
+        }
+
+        offsets.push(offset);
+        expected = sequence.saturating_sub(1);
+
+        let previous = record.header.prev_record_offset;
+        if previous >= offset && previous != 0 {
+            mark_broken(report, frames, previous, FrameKind::Record);
+            break;
+        }
+
+        // A compacted vault starts its records right after the oldest checkpoint.
+        if previous == 0 && expected != 0 && expected != base_sequence {
+            report.sequence_gaps.push(SequenceGap {
+                offset,
+                expected: 1,
+                found: sequence,
+            });
+        }
+
+        offset = previous;
+    }
+
+    report.records_checked = offsets.len();
+
+    Ok(offsets)
+}
+
+fn verify_blobs(
+    reader: &mut Reader,
+    blob_refs: &[BlobRef],
+    version: u16,
+    context: &FormatContext,
+    report: &mut VerifyReport,
+) -> Result<HashSet<Offset>> {
+    let mut offsets = HashSet::new();
+    let mut seen = HashSet::new();
+
+    for blob_ref in blob_refs {
+        if !seen.insert(blob_ref.manifest_offset) {
+            continue;
+        }
+        report.blobs_checked += 1;
+
+        let Ok(manifest) = read_manifest(reader, blob_ref, context) else {
+            report.dangling_blobs.push(DanglingBlob {
+                blob_id: blob_ref.id,
+                manifest_offset: blob_ref.manifest_offset,
+                missing_chunks: Vec::new(),
+            });
+            continue;
+        };
+        offsets.insert(blob_ref.manifest_offset);
+
+        let hash_key = chunk_hash_key(&manifest, version, context)?;
+        let mut missing_chunks = Vec::new();
+
+        for chunk in &manifest.chunks {
+            match read_chunk(reader, chunk, hash_key.as_ref(), context) {
+                Ok(_) => {
+                    offsets.insert(chunk.offset);
+                }
+                Err(_) => missing_chunks.push(chunk.offset),
+            }
+        }
+
+        if !missing_chunks.is_empty() {
+            report.dangling_blobs.push(DanglingBlob {
+                blob_id: blob_ref.id,
+                manifest_offset: blob_ref.manifest_offset,
+                missing_chunks,
+            });
+        }
+    }
+
+    Ok(offsets)
+}
+
+fn mark_broken(
+    report: &mut VerifyReport,
+    frames: &BTreeMap<Offset, ScannedFrame>,
+    offset: Offset,
+    expected: FrameKind,
+) {
+    if let Some(broken) = report
+        .broken_frames
+        .iter_mut()
+        .find(|frame| frame.offset == offset)
+    {
+        broken.expected = Some(expected);
+        return;
+    }
+
+    report.broken_frames.push(BrokenFrame {
+        offset,
+        size: frames.get(&offset).map_or(0, |frame| frame.size),
+        expected: Some(expected),
+    });
+}
+
+fn open_frame_at(
+    reader: &mut Reader,
+    offset: Offset,
+    domain: AadDomain,
+    context: &FormatContext,
+) -> Result<Vec<u8>> {
+    reader.seek_from_start(offset)?;
+    open_frame(reader, domain, context)
+}
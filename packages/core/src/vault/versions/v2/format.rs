@@ -0,0 +1,214 @@
+use std::io::Read;
+
+use openvault_crypto::keys::master_key::MasterKey;
+
+use crate::errors::Result;
+use crate::features::chunks::{ChunkHash, ChunksStore};
+use crate::features::shared::BlobRef;
+use crate::internal::io_ext::{ReadWriter, Reader};
+use crate::vault::crypto::credential::Credential;
+use crate::vault::crypto::keyring::Keyring;
+use crate::vault::versions::shared::Offset;
+use crate::vault::versions::shared::boot_header::BootHeader;
+use crate::vault::versions::shared::checkpoint::Checkpoint;
+use crate::vault::versions::shared::format::{BlobStream, FormatContext, FormatHandler};
+use crate::vault::versions::shared::key_slot::{KeySlot, KeySlotTable};
+use crate::vault::versions::shared::record::Record;
+use crate::vault::versions::shared::recovery::RecoveryReport;
+use crate::vault::versions::shared::replay::{ReplayState, ReplayTarget};
+use crate::vault::versions::shared::subheader::Subheader;
+use crate::vault::versions::shared::verify::VerifyReport;
+use crate::vault::versions::v1::io;
+use crate::vault::versions::v1::recovery::recover_tail;
+use crate::vault::versions::v1::replay::{replay_records, replay_records_at};
+use crate::vault::versions::v1::verify::verify_frames;
+
+pub const V2_FORMAT_VERSION: u16 = 2;
+
+pub static V2_FORMAT: V2FormatHandler = V2FormatHandler::new(V2_FORMAT_VERSION);
+
+// Key slot layout shared by every version from 2 on; only the slot encoding varies.
+#[derive(Debug)]
+pub struct V2FormatHandler {
+    version: u16,
+}
+
+impl V2FormatHandler {
+    pub const fn new(version: u16) -> Self {
+        Self { version }
+    }
+}
+
+impl FormatHandler for V2FormatHandler {
+    fn version(&self) -> u16 {
+        self.version
+    }
+
+    fn subheader_offset(&self) -> Offset {
+        KeySlotTable::end_offset_for(self.version)
+    }
+
+    fn has_key_slots(&self) -> bool {
+        true
+    }
+
+    fn create_keyring(
+        &self,
+        rw: &mut ReadWriter,
+        boot_header: &BootHeader,
+        credential: &Credential,
+    ) -> Result<Keyring> {
+        let master = MasterKey::generate()?;
+
+        let mut slots = KeySlotTable::new(self.version);
+        slots.insert(KeySlot::wrap(
+            &master,
+            credential,
+            &boot_header.kdf,
+            boot_header.cipher,
+        )?)?;
+        slots.write_to(rw)?;
+
+        Ok(Keyring::new(master))
+    }
+
+    fn unlock_keyring(
+        &self,
+        reader: &mut Reader,
+        boot_header: &BootHeader,
+        credential: &Credential,
+    ) -> Result<Keyring> {
+        let slots = KeySlotTable::read_from(reader, self.version)?;
+        let (_, master) = slots.unlock(credential, boot_header.cipher)?;
+
+        Ok(Keyring::new(master))
+    }
+
+    fn init_layout(&self, rw: &mut ReadWriter, context: &FormatContext) -> Result<Subheader> {
+        io::init_layout(rw, self.subheader_offset(), context)
+    }
+
+    fn read_subheader(&self, reader: &mut Reader, context: &FormatContext) -> Result<Subheader> {
+        io::read_subheader(reader, self.subheader_offset(), context)
+    }
+
+    fn recover(&self, rw: &mut ReadWriter, context: &FormatContext) -> Result<RecoveryReport> {
+        recover_tail(rw, self.subheader_offset(), context)
+    }
+
+    fn read_blob(
+        &self,
+        reader: &mut Reader,
+        blob_ref: &BlobRef,
+        context: &FormatContext,
+    ) -> Result<Vec<u8>> {
+        io::read_blob(reader, blob_ref, self.version, context)
+    }
+
+    fn open_blob<'a>(
+        &self,
+        reader: &'a mut Reader,
+        blob_ref: &BlobRef,
+        context: FormatContext<'a>,
+    ) -> Result<BlobStream<'a>> {
+        Ok(Box::new(io::BlobReader::open(
+            reader,
+            blob_ref,
+            self.version,
+            context,
+        )?))
+    }
+
+    fn write_blob(
+        &self,
+        rw: &mut ReadWriter,
+        reader: &mut dyn Read,
+        context: &FormatContext,
+        chunks: &mut ChunksStore,
+    ) -> Result<BlobRef> {
+        io::write_blob(rw, reader, self.version, context, chunks)
+    }
+
+    fn read_blob_chunks(
+        &self,
+        reader: &mut Reader,
+        blob_ref: &BlobRef,
+        context: &FormatContext,
+    ) -> Result<Vec<(Offset, Option<ChunkHash>)>> {
+        io::read_blob_chunks(reader, blob_ref, context)
+    }
+
+    fn write_subheader(
+        &self,
+        rw: &mut ReadWriter,
+        subheader: &Subheader,
+        context: &FormatContext,
+    ) -> Result {
+        io::write_subheader(rw, self.subheader_offset(), subheader, context)
+    }
+
+    fn read_checkpoint(
+        &self,
+        reader: &mut Reader,
+        offset: Offset,
+        context: &FormatContext,
+    ) -> Result<Checkpoint> {
+        io::read_checkpoint(reader, offset, context)
+    }
+
+    fn write_checkpoint(
+        &self,
+        rw: &mut ReadWriter,
+        checkpoint: &mut Checkpoint,
+        context: &FormatContext,
+    ) -> Result<Offset> {
+        io::write_checkpoint(rw, self.subheader_offset(), checkpoint, context)
+    }
+
+    fn append_record(
+        &self,
+        rw: &mut ReadWriter,
+        record: &mut Record,
+        released_bytes: u64,
+        context: &FormatContext,
+    ) -> Result<Offset> {
+        io::append_record(rw, self.subheader_offset(), record, released_bytes, context)
+    }
+
+    fn read_record(
+        &self,
+        reader: &mut Reader,
+        offset: Offset,
+        context: &FormatContext,
+    ) -> Result<Record> {
+        io::read_record(reader, offset, context)
+    }
+
+    fn replay(&self, reader: &mut Reader, context: &FormatContext) -> Result<ReplayState> {
+        replay_records(reader, self.subheader_offset(), context)
+    }
+
+    fn replay_at(
+        &self,
+        reader: &mut Reader,
+        target: ReplayTarget,
+        context: &FormatContext,
+    ) -> Result<ReplayState> {
+        replay_records_at(reader, self.subheader_offset(), target, context)
+    }
+
+    fn verify(
+        &self,
+        reader: &mut Reader,
+        blob_refs: &[BlobRef],
+        context: &FormatContext,
+    ) -> Result<VerifyReport> {
+        verify_frames(
+            reader,
+            self.subheader_offset(),
+            blob_refs,
+            self.version,
+            context,
+        )
+    }
+}
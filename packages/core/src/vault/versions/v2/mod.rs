@@ -0,0 +1,3 @@
+mod format;
+
+pub use format::{V2_FORMAT, V2_FORMAT_VERSION, V2FormatHandler};
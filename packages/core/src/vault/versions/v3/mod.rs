@@ -0,0 +1,6 @@
+use crate::vault::versions::v2::V2FormatHandler;
+
+// The v2 layout with Argon2id parameters stored in the boot header and in every key slot.
+pub const V3_FORMAT_VERSION: u16 = 3;
+
+pub static V3_FORMAT: V2FormatHandler = V2FormatHandler::new(V3_FORMAT_VERSION);
@@ -0,0 +1,395 @@
+mod common;
+
+use std::io::Cursor;
+
+use uuid::Uuid;
+
+use openvault_core::archive::{ArchiveCredential, ArchiveRecipient};
+use openvault_core::errors::Error;
+use openvault_core::features::chunks::ChunksStore;
+use openvault_core::features::filesystem::{FILESYSTEM_ROOT_FOLDER_ID, FilesystemStore};
+use openvault_core::features::secrets::{
+    CustomField, LoginEntry, NewCustomSecret, NewLoginSecret, NewSecretEntry, NewSecureNoteSecret,
+    NewWifiNetworkSecret, SECRETS_ROOT_FOLDER_ID, SecretEntry, SecretEntryView, SecretKind,
+    SecretStore, TOTP, WifiSecurity,
+};
+use openvault_core::operations::archive::{
+    ArchiveDestination, ArchiveSelection, export_archive, import_archive,
+};
+use openvault_core::operations::blob::{read_blob, write_blob};
+use openvault_core::operations::vault::create_and_open_vault;
+use openvault_core::vault::runtime::VaultSession;
+use openvault_crypto::hash::Crc32Hasher;
+use openvault_crypto::keys::EphemeralKeyPair;
+use openvault_crypto::keys::kdf_params::{KDF_PARAMS_SIZE, KdfParams};
+use openvault_crypto::keys::salt::SALT_SIZE;
+
+use common::{secrets_key, temp_vault_path};
+
+const SECRET_PASSWORD: &str = "correct horse battery staple";
+const TOTP_SECRET: &str = "JBSWY3DPEHPK3PXPJBSWY3DP";
+
+fn totp() -> TOTP {
+    TOTP::new(TOTP_SECRET.to_string(), None, None).expect("valid totp")
+}
+
+struct Fixture {
+    path: std::path::PathBuf,
+    session: VaultSession,
+    filesystem: FilesystemStore,
+    secrets: SecretStore,
+    chunks: ChunksStore,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let path = temp_vault_path("archive");
+        let session = create_and_open_vault(&path, b"password", Default::default())
+            .expect("create and open vault");
+
+        Self {
+            path,
+            session,
+            filesystem: FilesystemStore::new(),
+            secrets: SecretStore::new(),
+            chunks: ChunksStore::new(),
+        }
+    }
+
+    fn add_login(&mut self, folder_id: Uuid, name: &str) {
+        let entry = LoginEntry::seal(
+            NewLoginSecret {
+                folder_id,
+                name: name.to_string(),
+                username: "alice".to_string(),
+                password: SECRET_PASSWORD.to_string(),
+                website: Some("https://example.com".to_string()),
+                comments: None,
+                totp: Some(totp()),
+            },
+            &secrets_key(&self.session),
+            self.session.cipher(),
+        )
+        .expect("seal login");
+        self.secrets.add_entry(entry).expect("add login");
+    }
+
+    fn import(&mut self, credential: &ArchiveCredential, archive: &[u8]) -> Result<(), Error> {
+        import_archive(
+            &mut self.session,
+            &mut self.chunks,
+            &mut self.filesystem,
+            &mut self.secrets,
+            &ArchiveDestination::default(),
+            credential,
+            &mut Cursor::new(archive),
+        )
+        .map(|_| ())
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn source_archive(recipient: &ArchiveRecipient) -> (Vec<u8>, Vec<u8>) {
+    let mut source = Fixture::new();
+    let payload: Vec<u8> = (0..600 * 1024).map(|i| (i % 251) as u8).collect();
+
+    let projects = source
+        .filesystem
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "projects".to_string())
+        .expect("add folder");
+    let nested = source
+        .filesystem
+        .add_folder(projects, "nested".to_string())
+        .expect("add nested folder");
+    let blob = write_blob(
+        &mut source.session,
+        &mut source.chunks,
+        &mut Cursor::new(payload.clone()),
+    )
+    .expect("write blob");
+    source
+        .filesystem
+        .add_file(nested, "report.pdf".to_string(), "pdf".to_string(), blob)
+        .expect("add file");
+    let empty = write_blob(
+        &mut source.session,
+        &mut source.chunks,
+        &mut Cursor::new(Vec::new()),
+    )
+    .expect("write blob");
+    source
+        .filesystem
+        .add_file(projects, "empty.txt".to_string(), "txt".to_string(), empty)
+        .expect("add empty file");
+
+    let shared = source
+        .secrets
+        .add_folder(SECRETS_ROOT_FOLDER_ID, "shared".to_string())
+        .expect("add secret folder");
+    source.add_login(shared, "mail");
+
+    let mut archive = Vec::new();
+    export_archive(
+        &mut source.session,
+        &source.filesystem,
+        &source.secrets,
+        &ArchiveSelection {
+            filesystem_folder: Some(projects),
+            secrets_folder: Some(shared),
+        },
+        recipient,
+        &mut archive,
+    )
+    .expect("export archive");
+
+    (archive, payload)
+}
+
+fn assert_imported(target: &mut Fixture, payload: &[u8], folder_name: &str) {
+    let projects = target
+        .filesystem
+        .folders(FILESYSTEM_ROOT_FOLDER_ID)
+        .into_iter()
+        .find(|folder| folder.name == folder_name)
+        .expect("imported folder");
+    let files = target.filesystem.files(projects.id);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].name, "empty.txt");
+
+    let nested = target.filesystem.folders(projects.id);
+    assert_eq!(nested.len(), 1);
+    let report = target.filesystem.files(nested[0].id);
+    assert_eq!(report[0].name, "report.pdf");
+    assert_eq!(
+        read_blob(&mut target.session, &report[0].blob).expect("read blob"),
+        payload
+    );
+
+    let shared = target
+        .secrets
+        .folders(SECRETS_ROOT_FOLDER_ID)
+        .into_iter()
+        .find(|folder| folder.name.starts_with("shared"))
+        .expect("imported secret folder");
+    let entries = target.secrets.entries(shared.id);
+    let view = entries[0]
+        .as_login()
+        .expect("imported login")
+        .reveal(&secrets_key(&target.session), target.session.cipher())
+        .expect("reveal login");
+    assert_eq!(view.name, "mail");
+    assert_eq!(view.password, SECRET_PASSWORD);
+    assert_eq!(view.totp, Some(totp()));
+}
+
+#[test]
+fn password_archive_round_trips_and_renames_conflicts() {
+    let (archive, payload) = source_archive(&ArchiveRecipient::Password(b"archive-pass"));
+
+    assert!(
+        !archive
+            .windows(SECRET_PASSWORD.len())
+            .any(|window| window == SECRET_PASSWORD.as_bytes())
+    );
+    assert!(!archive.windows(8).any(|window| window == b"projects"));
+
+    let mut target = Fixture::new();
+    target
+        .filesystem
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "projects".to_string())
+        .expect("add conflicting folder");
+
+    assert!(matches!(
+        target.import(&ArchiveCredential::Password(b"wrong"), &archive),
+        Err(Error::ArchiveUnlockFailed)
+    ));
+
+    target
+        .import(&ArchiveCredential::Password(b"archive-pass"), &archive)
+        .expect("import archive");
+    assert_imported(&mut target, &payload, "projects (1)");
+
+    target
+        .import(&ArchiveCredential::Password(b"archive-pass"), &archive)
+        .expect("import archive again");
+    let names: Vec<_> = target
+        .secrets
+        .folders(SECRETS_ROOT_FOLDER_ID)
+        .into_iter()
+        .map(|folder| folder.name)
+        .collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&"shared".to_string()));
+    assert!(names.contains(&"shared (1)".to_string()));
+}
+
+#[test]
+fn recipient_archive_opens_only_with_the_matching_key() {
+    let recipient = EphemeralKeyPair::generate().expect("generate recipient keys");
+    let stranger = EphemeralKeyPair::generate().expect("generate stranger keys");
+
+    let (archive, payload) = source_archive(&ArchiveRecipient::PublicKey(&recipient.public));
+
+    let mut target = Fixture::new();
+    assert!(matches!(
+        target.import(&ArchiveCredential::PrivateKey(&stranger.private), &archive),
+        Err(Error::ArchiveUnlockFailed)
+    ));
+    assert!(matches!(
+        target.import(&ArchiveCredential::Password(b"archive-pass"), &archive),
+        Err(Error::ArchiveUnlockFailed)
+    ));
+
+    target
+        .import(&ArchiveCredential::PrivateKey(&recipient.private), &archive)
+        .expect("import archive");
+    assert_imported(&mut target, &payload, "projects");
+}
+
+#[test]
+fn archive_carries_every_secret_kind_in_a_folder() {
+    let mut source = Fixture::new();
+    let mixed = source
+        .secrets
+        .add_folder(SECRETS_ROOT_FOLDER_ID, "mixed".to_string())
+        .expect("add secret folder");
+    source.add_login(mixed, "mail");
+
+    let key = secrets_key(&source.session);
+    for input in [
+        NewSecretEntry::SecureNote(NewSecureNoteSecret {
+            folder_id: mixed,
+            name: "recovery".to_string(),
+            content: "seven words".to_string(),
+        }),
+        NewSecretEntry::WifiNetwork(NewWifiNetworkSecret {
+            folder_id: mixed,
+            name: "office".to_string(),
+            ssid: "corp".to_string(),
+            password: Some("wifi-pass".to_string()),
+            security: WifiSecurity::Wpa3Personal,
+            comments: None,
+        }),
+        NewSecretEntry::Custom(NewCustomSecret {
+            folder_id: mixed,
+            name: "locker".to_string(),
+            fields: vec![CustomField {
+                label: "code".to_string(),
+                value: "4711".to_string(),
+                concealed: true,
+            }],
+            comments: Some("gym".to_string()),
+        }),
+    ] {
+        let entry = SecretEntry::seal(input, &key, source.session.cipher()).expect("seal entry");
+        source.secrets.add_entry(entry).expect("add entry");
+    }
+
+    let mut archive = Vec::new();
+    export_archive(
+        &mut source.session,
+        &source.filesystem,
+        &source.secrets,
+        &ArchiveSelection {
+            filesystem_folder: None,
+            secrets_folder: Some(mixed),
+        },
+        &ArchiveRecipient::Password(b"archive-pass"),
+        &mut archive,
+    )
+    .expect("export archive");
+
+    let mut target = Fixture::new();
+    target
+        .import(&ArchiveCredential::Password(b"archive-pass"), &archive)
+        .expect("import archive");
+
+    let mixed = target
+        .secrets
+        .folders(SECRETS_ROOT_FOLDER_ID)
+        .into_iter()
+        .find(|folder| folder.name == "mixed")
+        .expect("imported secret folder");
+    let key = secrets_key(&target.session);
+    let views: Vec<_> = target
+        .secrets
+        .entries(mixed.id)
+        .iter()
+        .map(|entry| entry.reveal(&key, target.session.cipher()).expect("reveal"))
+        .collect();
+    assert_eq!(views.len(), 4);
+
+    for view in &views {
+        match view {
+            SecretEntryView::Login(view) => assert_eq!(view.password, SECRET_PASSWORD),
+            SecretEntryView::SecureNote(view) => assert_eq!(view.content, "seven words"),
+            SecretEntryView::WifiNetwork(view) => {
+                assert_eq!(view.ssid, "corp");
+                assert_eq!(view.password, "wifi-pass");
+                assert_eq!(view.security, WifiSecurity::Wpa3Personal);
+            }
+            SecretEntryView::Custom(view) => {
+                assert_eq!(view.fields[0].value, "4711");
+                assert!(view.fields[0].concealed);
+                assert_eq!(view.comments, "gym");
+            }
+            other => panic!("unexpected {:?} entry", other.kind()),
+        }
+    }
+    assert!(
+        [
+            SecretKind::Login,
+            SecretKind::SecureNote,
+            SecretKind::WifiNetwork,
+            SecretKind::Custom
+        ]
+        .iter()
+        .all(|kind| views.iter().any(|view| view.kind() == *kind))
+    );
+}
+
+#[test]
+fn tampered_or_truncated_archives_are_rejected() {
+    let (archive, _) = source_archive(&ArchiveRecipient::Password(b"archive-pass"));
+    let credential = ArchiveCredential::Password(b"archive-pass");
+
+    let mut tampered = archive.clone();
+    let index = tampered.len() / 2;
+    tampered[index] ^= 0x01;
+
+    let mut target = Fixture::new();
+    assert!(target.import(&credential, &tampered).is_err());
+    assert!(
+        target
+            .import(&credential, &archive[..archive.len() - 1])
+            .is_err()
+    );
+
+    let mut trailing = archive.clone();
+    trailing.push(0);
+    assert!(target.import(&credential, &trailing).is_err());
+}
+
+#[test]
+fn archive_kdf_params_above_the_ceiling_are_rejected() {
+    let (mut archive, _) = source_archive(&ArchiveRecipient::Password(b"archive-pass"));
+
+    // Rewrite the header's memory cost and re-seal its checksum, as a crafted archive would.
+    let kdf_offset = 11 + SALT_SIZE;
+    let header_size = kdf_offset + KDF_PARAMS_SIZE;
+    let excessive = KdfParams::new(KdfParams::MAX.memory_kib * 4, 1, 1).expect("valid params");
+    archive[kdf_offset..header_size].copy_from_slice(&excessive.to_bytes());
+    let crc = Crc32Hasher::checksum(&archive[..header_size]);
+    archive[header_size..header_size + 4].copy_from_slice(&crc.to_le_bytes());
+
+    let mut target = Fixture::new();
+    assert!(matches!(
+        target.import(&ArchiveCredential::Password(b"archive-pass"), &archive),
+        Err(Error::InvalidArchiveFormat)
+    ));
+}
@@ -0,0 +1,215 @@
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+
+use openvault_core::features::secrets::{
+    ApiKeyEntry, AuditIssue, AuditOptions, AuditReport, BreachIndex, LoginEntry, NewApiKeySecret,
+    NewLoginSecret, SECRETS_ROOT_FOLDER_ID, SecretError, SecretStore, TOTP, WeakPattern,
+};
+use openvault_crypto::encryption::EncryptionAlgorithm;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+// SHA-1 of "correct horse", split the way the k-anonymity range API serves it.
+const BREACHED_RANGE: &str = "# pwned range\n2F9E5\n0000000000000000000000000000000000A:3\n3523B62ABC141A2B4D6019D23CBA835DBD0:42\n";
+
+fn key() -> DerivedKey {
+    DerivedKey::new([7u8; 32]).expect("derived key")
+}
+
+fn login(name: &str, password: &str, website: &str, totp: bool) -> LoginEntry {
+    LoginEntry::seal(
+        NewLoginSecret {
+            folder_id: SECRETS_ROOT_FOLDER_ID,
+            name: name.to_string(),
+            username: "alice@example.com".to_string(),
+            password: password.to_string(),
+            website: Some(website.to_string()),
+            comments: None,
+            totp: totp
+                .then(|| TOTP::new("JBSWY3DPEHPK3PXP".to_string(), None, None).expect("totp")),
+        },
+        &key(),
+        EncryptionAlgorithm::default(),
+    )
+    .expect("seal login")
+}
+
+fn issues_of(report: &AuditReport, id: Uuid) -> &[AuditIssue] {
+    report
+        .findings
+        .iter()
+        .find(|finding| finding.id == id)
+        .map(|finding| finding.issues.as_slice())
+        .unwrap_or_default()
+}
+
+#[test]
+fn audit_flags_reuse_weakness_staleness_totp_and_http() {
+    let mut store = SecretStore::new();
+    let bank = store
+        .add_entry(login("bank", "correct horse", "https://bank.example", true))
+        .expect("add bank");
+    let mail = store
+        .add_entry(login("mail", "correct horse", "http://mail.example", false))
+        .expect("add mail");
+    let vault = store
+        .add_entry(login(
+            "vault",
+            "n7#Qv9!xR2@kLm4$Tz8w",
+            "HTTPS://vault.example",
+            true,
+        ))
+        .expect("add vault");
+
+    let mut forum = login("forum", "Password111", "https://forum.example", true);
+    forum.updated_at = Utc::now() - TimeDelta::days(400);
+    let forum = store.add_entry(forum).expect("add forum");
+
+    store
+        .add_entry(
+            ApiKeyEntry::seal(
+                NewApiKeySecret {
+                    folder_id: SECRETS_ROOT_FOLDER_ID,
+                    name: "token".to_string(),
+                    api_key: "a".to_string(),
+                    website: Some("http://api.example".to_string()),
+                    comments: None,
+                },
+                &key(),
+                EncryptionAlgorithm::default(),
+            )
+            .expect("seal api key"),
+        )
+        .expect("add api key");
+
+    let report = store
+        .audit(
+            &key(),
+            EncryptionAlgorithm::default(),
+            &AuditOptions::default(),
+        )
+        .expect("audit");
+
+    assert_eq!(report.audited, 4);
+    assert_eq!(
+        report
+            .findings
+            .iter()
+            .map(|finding| finding.name.as_str())
+            .collect::<Vec<_>>(),
+        ["bank", "forum", "mail"]
+    );
+    assert!(issues_of(&report, vault).is_empty());
+
+    assert_eq!(
+        issues_of(&report, bank),
+        [AuditIssue::ReusedPassword {
+            shared_with: vec![mail]
+        }]
+    );
+    assert_eq!(
+        issues_of(&report, mail),
+        [
+            AuditIssue::ReusedPassword {
+                shared_with: vec![bank]
+            },
+            AuditIssue::InsecureWebsite,
+            AuditIssue::MissingTotp,
+        ]
+    );
+
+    let forum_issues = issues_of(&report, forum);
+    assert_eq!(forum_issues.len(), 2);
+    assert!(matches!(
+        &forum_issues[0],
+        AuditIssue::WeakPassword { patterns, .. }
+            if patterns == &[WeakPattern::CommonPassword, WeakPattern::RepeatedCharacters]
+    ));
+    assert_eq!(forum_issues[1], AuditIssue::Stale { days: 400 });
+}
+
+#[test]
+fn weak_patterns_and_entropy_threshold_are_reported() {
+    let mut store = SecretStore::new();
+    let cases = [
+        ("short", "Xk9#", vec![]),
+        ("sequence", "Zx!abcdQ7#mvLp2@", vec![WeakPattern::Sequence]),
+        ("walk", "Zx!asdfQ7#mvLp2@", vec![WeakPattern::KeyboardWalk]),
+        (
+            "username",
+            "Zx!AliceQ7#mvLp2@",
+            vec![WeakPattern::ContainsUsername],
+        ),
+    ];
+
+    let ids = cases
+        .iter()
+        .map(|(name, password, _)| {
+            store
+                .add_entry(login(name, password, "https://example.com", true))
+                .expect("add login")
+        })
+        .collect::<Vec<_>>();
+
+    let report = store
+        .audit(
+            &key(),
+            EncryptionAlgorithm::default(),
+            &AuditOptions::default(),
+        )
+        .expect("audit");
+
+    for (id, (name, _, expected)) in ids.into_iter().zip(cases) {
+        match issues_of(&report, id) {
+            [
+                AuditIssue::WeakPassword {
+                    entropy_bits,
+                    patterns,
+                },
+            ] => {
+                assert_eq!(patterns, &expected, "{name}");
+                assert!(!expected.is_empty() || *entropy_bits < 60.0, "{name}");
+            }
+            issues => panic!("{name}: unexpected issues {issues:?}"),
+        }
+    }
+}
+
+#[test]
+fn breach_index_matches_ranges_and_full_hashes() {
+    let index = BreachIndex::parse(BREACHED_RANGE.as_bytes()).expect("parse range");
+    assert_eq!(index.occurrences("correct horse"), Some(42));
+    assert_eq!(index.occurrences("battery staple"), None);
+
+    let full = BreachIndex::parse("2f9e53523b62abc141a2b4d6019d23cba835dbd0\n".as_bytes())
+        .expect("parse full hash");
+    assert_eq!(full.occurrences("correct horse"), Some(1));
+
+    for invalid in [
+        "3523B62ABC141A2B4D6019D23CBA835DBD0:42\n",
+        "2F9E5\nnot-a-hash\n",
+        "2F9E53523B62ABC141A2B4D6019D23CBA835DBD0:many\n",
+    ] {
+        assert!(matches!(
+            BreachIndex::parse(invalid.as_bytes()),
+            Err(SecretError::InvalidInput(_))
+        ));
+    }
+
+    let mut store = SecretStore::new();
+    let id = store
+        .add_entry(login("bank", "correct horse", "https://bank.example", true))
+        .expect("add bank");
+
+    let options = AuditOptions {
+        breaches: Some(index),
+        ..AuditOptions::default()
+    };
+    let report = store
+        .audit(&key(), EncryptionAlgorithm::default(), &options)
+        .expect("audit");
+
+    assert_eq!(
+        issues_of(&report, id),
+        [AuditIssue::BreachedPassword { occurrences: 42 }]
+    );
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use std::io::Cursor;
+
+use openvault_core::errors::Error;
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::features::secrets::{
+    LoginEntry, NewLoginSecret, SECRETS_ROOT_FOLDER_ID, SecretEntry,
+};
+use openvault_core::operations::blob::{read_blob, write_blob};
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::vault::{change_password, create_and_open_vault, open_vault};
+use openvault_core::repositories::{
+    ChunksRepository, FeatureRepository, FilesystemRepository, SecretsRepository,
+};
+
+use common::{secrets_key, temp_vault_path};
+
+fn assert_password_change_preserves_data(config: CreateConfig) {
+    let vault_path = temp_vault_path("password");
+
+    let mut session =
+        create_and_open_vault(&vault_path, b"old-password", config).expect("create and open vault");
+
+    let mut filesystem = FilesystemRepository::load(&mut session).expect("load filesystem");
+    let mut secrets = SecretsRepository::load(&mut session).expect("load secrets");
+
+    let payload = vec![42u8; 300 * 1024];
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+        .expect("write blob");
+    let file_id = filesystem
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "data.bin".to_string(),
+            "bin".to_string(),
+            blob,
+        )
+        .expect("add file");
+    FilesystemRepository::commit(&mut session, &mut filesystem).expect("commit filesystem");
+
+    let entry = LoginEntry::seal(
+        NewLoginSecret {
+            folder_id: SECRETS_ROOT_FOLDER_ID,
+            name: "mail".to_string(),
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+            website: None,
+            comments: None,
+            totp: None,
+        },
+        &secrets_key(&session),
+        session.cipher(),
+    )
+    .expect("seal entry");
+    let entry_id = secrets.add_entry(entry).expect("add entry");
+    SecretsRepository::commit(&mut session, &mut secrets).expect("commit secrets");
+
+    change_password(&mut session, b"old-password", b"new-password").expect("change password");
+    drop(session);
+
+    let old = open_vault(&vault_path, b"old-password");
+    assert!(matches!(old, Err(Error::UnlockFailed)));
+
+    let mut session = open_vault(&vault_path, b"new-password").expect("open with new password");
+
+    let filesystem = FilesystemRepository::load(&mut session).expect("reload filesystem");
+    let file = filesystem.file(&file_id).expect("file exists");
+    let restored = read_blob(&mut session, &file.blob).expect("read blob");
+    assert_eq!(restored, payload);
+
+    let secrets = SecretsRepository::load(&mut session).expect("reload secrets");
+    let view = secrets
+        .entry(&entry_id)
+        .and_then(SecretEntry::as_login)
+        .expect("entry exists")
+        .reveal(&secrets_key(&session), session.cipher())
+        .expect("reveal entry");
+    assert_eq!(view.password, "hunter2");
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn change_password_reseals_blobs_and_secrets() {
+    assert_password_change_preserves_data(CreateConfig::default().with_version(1));
+}
+
+#[test]
+fn change_password_rewraps_key_slot() {
+    assert_password_change_preserves_data(CreateConfig::default());
+}
+
+#[test]
+fn change_password_with_key_slots_keeps_data_frames() {
+    let vault_path = temp_vault_path("password");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let payload = vec![7u8; 64 * 1024];
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+        .expect("write blob");
+    let before_size = session.file().metadata().expect("before metadata").len();
+
+    change_password(&mut session, b"password", b"new-password").expect("change password");
+
+    let after_size = session.file().metadata().expect("after metadata").len();
+    assert_eq!(before_size, after_size);
+
+    let restored = read_blob(&mut session, &blob).expect("read blob with same keyring");
+    assert_eq!(restored, payload);
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn change_password_rejects_wrong_current_password() {
+    let vault_path = temp_vault_path("password");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let result = change_password(&mut session, b"wrong", b"new-password");
+    assert!(matches!(result, Err(Error::UnlockFailed)));
+
+    drop(session);
+    open_vault(&vault_path, b"password").expect("original password still works");
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use uuid::Uuid;
+
+use openvault_core::features::secrets::SECRETS_FEATURE_ID;
+use openvault_core::vault::runtime::VaultSession;
+use openvault_crypto::keys::derived_key::DerivedKey;
+
+pub fn temp_vault_path(prefix: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("openvault_{prefix}_test_{}.ov", Uuid::new_v4()));
+    path
+}
+
+pub fn secrets_key(session: &VaultSession) -> DerivedKey {
+    session
+        .keyring()
+        .derive_feature_key(session.version(), SECRETS_FEATURE_ID)
+        .expect("derive secrets key")
+}
@@ -1,22 +1,21 @@
+mod common;
+
 use std::io::Cursor;
 
 use uuid::Uuid;
 
+use openvault_core::errors::Error;
 use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
 use openvault_core::operations::blob::{read_blob, write_blob};
 use openvault_core::operations::compact::compact_vault;
-use openvault_core::operations::vault::create_and_open_vault;
-use openvault_core::repositories::{FeatureRepository, FilesystemRepository};
+use openvault_core::operations::vault::{create_and_open_vault, open_vault};
+use openvault_core::repositories::{ChunksRepository, FeatureRepository, FilesystemRepository};
 
-fn temp_vault_path() -> std::path::PathBuf {
-    let mut path = std::env::temp_dir();
-    path.push(format!("openvault_compact_test_{}.ov", Uuid::new_v4()));
-    path
-}
+use common::temp_vault_path;
 
 #[test]
 fn compaction_rewrites_blob_refs_and_preserves_latest_state() {
-    let vault_path = temp_vault_path();
+    let vault_path = temp_vault_path("compact");
 
     let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
         .expect("create and open vault");
@@ -25,7 +24,9 @@ fn compaction_rewrites_blob_refs_and_preserves_latest_state() {
 
     let large_payload = vec![77u8; 2 * 1024 * 1024];
     let mut large_reader = Cursor::new(large_payload);
-    let stale_blob = write_blob(&mut session, &mut large_reader).expect("write large blob");
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let stale_blob =
+        write_blob(&mut session, &mut chunks, &mut large_reader).expect("write large blob");
 
     let stale_file = store
         .add_file(
@@ -42,7 +43,8 @@ fn compaction_rewrites_blob_refs_and_preserves_latest_state() {
 
     let live_payload = b"live-data".to_vec();
     let mut live_reader = Cursor::new(live_payload.clone());
-    let live_blob = write_blob(&mut session, &mut live_reader).expect("write live blob");
+    let live_blob =
+        write_blob(&mut session, &mut chunks, &mut live_reader).expect("write live blob");
 
     let live_file = store
         .add_file(
@@ -72,3 +74,130 @@ fn compaction_rewrites_blob_refs_and_preserves_latest_state() {
 
     std::fs::remove_file(vault_path).expect("cleanup vault");
 }
+
+#[test]
+fn compaction_keeps_file_revisions_readable() {
+    let vault_path = temp_vault_path("compact");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+
+    let original_payload = b"first draft".to_vec();
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let original_blob = write_blob(
+        &mut session,
+        &mut chunks,
+        &mut Cursor::new(original_payload.clone()),
+    )
+    .expect("write original blob");
+    let file_id = store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "draft.txt".to_string(),
+            "txt".to_string(),
+            original_blob,
+        )
+        .expect("add file");
+
+    let updated_blob = write_blob(
+        &mut session,
+        &mut chunks,
+        &mut Cursor::new(b"final".to_vec()),
+    )
+    .expect("write update");
+    store
+        .replace_file_blob(file_id, updated_blob)
+        .expect("replace file blob");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit file");
+
+    compact_vault(&mut session).expect("compact vault");
+
+    let reloaded = FilesystemRepository::load(&mut session).expect("reload filesystem");
+    let revisions = &reloaded.file(&file_id).expect("file exists").revisions;
+    assert_eq!(revisions.len(), 1);
+
+    let restored = read_blob(&mut session, &revisions[0].blob).expect("read revision");
+    assert_eq!(restored, original_payload);
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn compaction_swaps_vault_file_and_leaves_no_leftovers() {
+    let vault_dir = std::env::temp_dir().join(format!("openvault_compact_dir_{}", Uuid::new_v4()));
+    std::fs::create_dir(&vault_dir).expect("create vault dir");
+    let vault_path = vault_dir.join("vault.ov");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+    store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "before".to_string())
+        .expect("add folder");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit folder");
+
+    std::fs::write(vault_dir.join("vault.ov.bak"), b"stale backup").expect("write stale backup");
+
+    compact_vault(&mut session).expect("compact vault");
+
+    let mut store = FilesystemRepository::load(&mut session).expect("reload filesystem");
+    store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "after".to_string())
+        .expect("add folder");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit after compaction");
+    drop(session);
+
+    let entries: Vec<_> = std::fs::read_dir(&vault_dir)
+        .expect("read vault dir")
+        .map(|entry| entry.expect("dir entry").file_name())
+        .collect();
+    assert_eq!(entries, vec!["vault.ov"]);
+
+    let mut session = open_vault(&vault_path, b"password").expect("reopen vault");
+    let store = FilesystemRepository::load(&mut session).expect("load filesystem");
+    let mut names: Vec<_> = store
+        .folders(FILESYSTEM_ROOT_FOLDER_ID)
+        .into_iter()
+        .map(|folder| folder.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["after", "before"]);
+
+    std::fs::remove_dir_all(vault_dir).expect("cleanup vault dir");
+}
+
+#[test]
+fn opening_a_vault_removes_compaction_leftovers() {
+    let vault_dir = std::env::temp_dir().join(format!("openvault_compact_dir_{}", Uuid::new_v4()));
+    std::fs::create_dir(&vault_dir).expect("create vault dir");
+    let vault_path = vault_dir.join("vault.ov");
+
+    let session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    drop(session);
+
+    let temp_name = format!("vault.ov.compact-tmp-{}", Uuid::new_v4());
+    std::fs::write(vault_dir.join(&temp_name), b"torn rebuild").expect("write stale temp");
+    std::fs::write(vault_dir.join("vault.ov.bak"), b"stale backup").expect("write stale backup");
+    std::fs::write(vault_dir.join("other.ov.bak"), b"unrelated").expect("write unrelated file");
+
+    assert!(matches!(
+        open_vault(&vault_path, b"wrong"),
+        Err(Error::UnlockFailed)
+    ));
+    assert!(vault_dir.join(&temp_name).exists());
+
+    open_vault(&vault_path, b"password").expect("open vault");
+
+    let mut entries: Vec<_> = std::fs::read_dir(&vault_dir)
+        .expect("read vault dir")
+        .map(|entry| entry.expect("dir entry").file_name())
+        .collect();
+    entries.sort();
+    assert_eq!(entries, vec!["other.ov.bak", "vault.ov"]);
+
+    std::fs::remove_dir_all(vault_dir).expect("cleanup vault dir");
+}
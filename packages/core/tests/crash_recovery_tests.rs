@@ -0,0 +1,340 @@
+mod common;
+
+use std::io::{Cursor, Seek, SeekFrom, Write};
+
+use openvault_core::errors::Error;
+use openvault_core::features::filesystem::{FILESYSTEM_ROOT_FOLDER_ID, FilesystemStore};
+use openvault_core::operations::blob::write_blob;
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::history::create_checkpoint;
+use openvault_core::operations::vault::{RECOVERY_COPY_SUFFIX, create_and_open_vault, open_vault};
+use openvault_core::repositories::{ChunksRepository, FeatureRepository, FilesystemRepository};
+use openvault_core::vault::runtime::VaultSession;
+use openvault_core::vault::versions::factory::FormatRef;
+use openvault_core::vault::versions::shared::checkpoint::Checkpoint;
+use openvault_core::vault::versions::shared::format::FormatContext;
+use openvault_core::vault::versions::shared::frame::FrameHeader;
+
+use common::temp_vault_path;
+
+const PASSWORD: &[u8] = b"password";
+
+fn root_names(store: &FilesystemStore) -> Vec<String> {
+    let folders = store.folders(FILESYSTEM_ROOT_FOLDER_ID).into_iter();
+    let files = store.files(FILESYSTEM_ROOT_FOLDER_ID).into_iter();
+
+    let mut names: Vec<_> = folders
+        .map(|folder| folder.name)
+        .chain(files.map(|file| file.name))
+        .collect();
+    names.sort();
+    names
+}
+
+fn entry_names(session: &mut VaultSession) -> Vec<String> {
+    let store = FilesystemRepository::load(session).expect("load filesystem");
+    root_names(&store)
+}
+
+fn add_folder(session: &mut VaultSession, name: &str) {
+    let mut store = FilesystemRepository::load(session).expect("load filesystem");
+    store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, name.to_string())
+        .expect("add folder");
+    FilesystemRepository::commit(session, &mut store).expect("commit folder");
+}
+
+fn add_file(session: &mut VaultSession) {
+    let mut store = FilesystemRepository::load(session).expect("load filesystem");
+    let mut chunks = ChunksRepository::load(session).expect("load chunks");
+    let blob =
+        write_blob(session, &mut chunks, &mut Cursor::new(vec![7u8; 2048])).expect("write blob");
+    store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "upload.bin".to_string(),
+            "bin".to_string(),
+            blob,
+        )
+        .expect("add file");
+    FilesystemRepository::commit(session, &mut store).expect("commit file");
+}
+
+fn write_checkpoint(session: &mut VaultSession) {
+    let store = FilesystemRepository::load(session).expect("load filesystem");
+    let feature = FilesystemRepository::create_checkpoint(&store).expect("checkpoint feature");
+
+    create_checkpoint(session, &mut Checkpoint::new(vec![feature])).expect("write checkpoint");
+}
+
+// Appends land first and the subheader slot is rewritten last, so an interrupted
+// operation is modelled as a prefix of [appended bytes, rewritten header bytes].
+fn write_log(before: &[u8], after: &[u8]) -> Vec<(usize, Vec<u8>)> {
+    let mut log = vec![(before.len(), after[before.len()..].to_vec())];
+
+    let changed: Vec<usize> = (0..before.len())
+        .filter(|&index| before[index] != after[index])
+        .collect();
+
+    if let (Some(&first), Some(&last)) = (changed.first(), changed.last()) {
+        log.push((first, after[first..=last].to_vec()));
+    }
+
+    log
+}
+
+fn apply_writes(before: &[u8], log: &[(usize, Vec<u8>)], mut budget: usize) -> Vec<u8> {
+    let mut bytes = before.to_vec();
+
+    for (offset, data) in log {
+        let end = offset + budget.min(data.len());
+        if bytes.len() < end {
+            bytes.resize(end, 0);
+        }
+        bytes[*offset..end].copy_from_slice(&data[..end - offset]);
+        budget -= end - offset;
+    }
+
+    bytes
+}
+
+fn replayed_names(
+    format: FormatRef,
+    vault: &mut Cursor<Vec<u8>>,
+    context: &FormatContext,
+) -> Vec<String> {
+    let replay = format.replay(vault, context).expect("replay vault");
+    let store = FilesystemRepository::restore_from_replay(&replay).expect("restore filesystem");
+    root_names(&store)
+}
+
+fn assert_crash_safe(version: u16, operation: fn(&mut VaultSession)) {
+    let vault_path = temp_vault_path("crash");
+    let config = CreateConfig::default().with_version(version);
+    let mut session =
+        create_and_open_vault(&vault_path, PASSWORD, config).expect("create and open vault");
+
+    add_folder(&mut session, "baseline");
+    let names_before = entry_names(&mut session);
+    let before = std::fs::read(&vault_path).expect("read vault");
+
+    operation(&mut session);
+    let names_after = entry_names(&mut session);
+    let after = std::fs::read(&vault_path).expect("read vault");
+
+    let format = session.format();
+    let context = FormatContext::new(session.keyring(), session.compressor(), session.cipher());
+
+    let log = write_log(&before, &after);
+    let total: usize = log.iter().map(|(_, data)| data.len()).sum();
+
+    for cut in 0..=total {
+        let mut vault = Cursor::new(apply_writes(&before, &log, cut));
+
+        let recovery = format
+            .recover(&mut vault, &context)
+            .unwrap_or_else(|error| panic!("recovery failed after {cut} bytes: {error}"));
+        if let Some(offset) = recovery.truncated_at {
+            vault.get_mut().truncate(offset as usize);
+        }
+
+        let names = replayed_names(format, &mut vault, &context);
+        assert!(
+            names == names_before || names == names_after,
+            "unexpected state after {cut} bytes: {names:?}"
+        );
+        if cut >= log[0].1.len() {
+            assert_eq!(
+                names, names_after,
+                "appended frames dropped after {cut} bytes"
+            );
+        }
+        if cut == total {
+            assert!(recovery.is_clean());
+        }
+
+        let report = format
+            .verify(&mut vault, &[], &context)
+            .expect("verify vault");
+        assert!(
+            report.truncated_at.is_none(),
+            "garbage left after {cut} bytes"
+        );
+        assert!(
+            report.broken_frames.is_empty(),
+            "broken frame after {cut} bytes"
+        );
+        assert!(
+            report.sequence_gaps.is_empty(),
+            "sequence gap after {cut} bytes"
+        );
+        assert!(
+            format
+                .recover(&mut vault, &context)
+                .expect("recover")
+                .is_clean()
+        );
+    }
+
+    drop(session);
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn interrupted_record_append_recovers_at_every_byte() {
+    assert_crash_safe(1, |session| add_folder(session, "second"));
+    assert_crash_safe(2, |session| add_folder(session, "second"));
+}
+
+#[test]
+fn interrupted_blob_commit_recovers_at_every_byte() {
+    assert_crash_safe(1, add_file);
+    assert_crash_safe(2, add_file);
+}
+
+#[test]
+fn interrupted_checkpoint_recovers_at_every_byte() {
+    assert_crash_safe(1, write_checkpoint);
+    assert_crash_safe(2, write_checkpoint);
+}
+
+#[test]
+fn torn_subheader_slots_are_rebuilt_only_with_the_right_key() {
+    for version in [1, 2, 3] {
+        assert_torn_slots_rebuilt(version);
+    }
+}
+
+fn tear_subheader_slots(vault_path: &std::path::Path, subheader_offset: u64) {
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(vault_path)
+        .expect("open vault file");
+    for slot in [0, 128] {
+        file.seek(SeekFrom::Start(subheader_offset + slot + 40))
+            .expect("seek into slot");
+        file.write_all(&[0xff; 8]).expect("tear slot");
+    }
+}
+
+fn recovery_copies(vault_path: &std::path::Path) -> Vec<std::path::PathBuf> {
+    let filename = vault_path.file_name().unwrap().to_str().unwrap();
+    let prefix = format!("{filename}{RECOVERY_COPY_SUFFIX}");
+
+    std::fs::read_dir(vault_path.parent().unwrap())
+        .expect("read vault dir")
+        .map(|entry| entry.expect("dir entry").path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .collect()
+}
+
+#[test]
+fn damaged_frame_before_valid_frames_is_not_truncated() {
+    let vault_path = temp_vault_path("crash");
+    let mut session =
+        create_and_open_vault(&vault_path, PASSWORD, Default::default()).expect("create vault");
+
+    add_folder(&mut session, "first");
+    add_folder(&mut session, "second");
+    let subheader_offset = session.format().subheader_offset();
+    drop(session);
+
+    tear_subheader_slots(&vault_path, subheader_offset);
+    let frames_offset = subheader_offset + 256;
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(&vault_path)
+        .expect("open vault file");
+    file.seek(SeekFrom::Start(frames_offset + FrameHeader::SIZE as u64))
+        .expect("seek into first frame");
+    file.write_all(&[0xaa; 4]).expect("damage first frame");
+    drop(file);
+
+    let damaged = std::fs::read(&vault_path).expect("read vault");
+    assert!(matches!(
+        open_vault(&vault_path, PASSWORD),
+        Err(Error::DamagedFrame(offset)) if offset == frames_offset
+    ));
+    assert_eq!(std::fs::read(&vault_path).expect("read vault"), damaged);
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn torn_tail_behind_a_rebuilt_subheader_is_kept_aside() {
+    let vault_path = temp_vault_path("crash");
+    let mut session =
+        create_and_open_vault(&vault_path, PASSWORD, Default::default()).expect("create vault");
+
+    add_folder(&mut session, "first");
+    let subheader_offset = session.format().subheader_offset();
+    drop(session);
+
+    tear_subheader_slots(&vault_path, subheader_offset);
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&vault_path)
+        .expect("open vault file");
+    file.write_all(&[0x11; FrameHeader::SIZE + 8])
+        .expect("append torn frame");
+    drop(file);
+    let torn_len = std::fs::metadata(&vault_path)
+        .expect("vault metadata")
+        .len();
+
+    let mut session = open_vault(&vault_path, PASSWORD).expect("open rebuilt vault");
+    assert_eq!(entry_names(&mut session), vec!["first"]);
+    drop(session);
+
+    let copies = recovery_copies(&vault_path);
+    assert_eq!(copies.len(), 1);
+    assert_eq!(
+        std::fs::metadata(&copies[0]).expect("copy metadata").len(),
+        torn_len
+    );
+    assert!(
+        std::fs::metadata(&vault_path)
+            .expect("vault metadata")
+            .len()
+            < torn_len
+    );
+
+    std::fs::remove_file(&copies[0]).expect("cleanup copy");
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+fn assert_torn_slots_rebuilt(version: u16) {
+    let vault_path = temp_vault_path("crash");
+    let config = CreateConfig::default().with_version(version);
+    let mut session =
+        create_and_open_vault(&vault_path, PASSWORD, config).expect("create and open vault");
+
+    add_folder(&mut session, "first");
+    add_folder(&mut session, "second");
+    let subheader_offset = session.format().subheader_offset();
+    drop(session);
+
+    tear_subheader_slots(&vault_path, subheader_offset);
+
+    let damaged = std::fs::read(&vault_path).expect("read vault");
+    assert!(matches!(
+        open_vault(&vault_path, b"wrong"),
+        Err(Error::UnlockFailed)
+    ));
+    assert_eq!(std::fs::read(&vault_path).expect("read vault"), damaged);
+
+    let mut session = open_vault(&vault_path, PASSWORD).expect("open rebuilt vault");
+    assert_eq!(entry_names(&mut session), vec!["first", "second"]);
+
+    add_folder(&mut session, "third");
+    drop(session);
+
+    let mut session = open_vault(&vault_path, PASSWORD).expect("reopen vault");
+    assert_eq!(entry_names(&mut session), vec!["first", "second", "third"]);
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use std::io::Cursor;
+
+use openvault_core::features::chunks::ChunksStore;
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::operations::blob::{read_blob, release_blob, write_blob};
+use openvault_core::operations::compact::compact_vault;
+use openvault_core::operations::vault::{create_and_open_vault, open_vault};
+use openvault_core::repositories::{ChunksRepository, FeatureRepository, FilesystemRepository};
+
+use common::temp_vault_path;
+
+fn noisy_payload(len: usize) -> Vec<u8> {
+    let mut state = 0x9E37_79B9_u32;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn identical_blobs_share_chunks() {
+    let vault_path = temp_vault_path("dedup");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    let mut chunks = ChunksStore::new();
+
+    let payload = noisy_payload(512 * 1024);
+
+    let first = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+        .expect("write first blob");
+    let size_after_first = session.file().metadata().expect("metadata").len();
+    let unique_chunks = chunks.len();
+
+    let second = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+        .expect("write second blob");
+    let size_after_second = session.file().metadata().expect("metadata").len();
+
+    assert_ne!(first, second);
+    assert_eq!(chunks.len(), unique_chunks);
+    assert!(
+        size_after_second - size_after_first < payload.len() as u64 / 4,
+        "expected duplicate blob to reuse chunks"
+    );
+
+    assert_eq!(
+        read_blob(&mut session, &first).expect("read first"),
+        payload
+    );
+    assert_eq!(
+        read_blob(&mut session, &second).expect("read second"),
+        payload
+    );
+
+    ChunksRepository::commit(&mut session, &mut chunks).expect("commit chunks");
+    drop(session);
+
+    let mut session = open_vault(&vault_path, b"password").expect("reopen vault");
+    let restored = ChunksRepository::load(&mut session).expect("load chunks");
+    assert_eq!(restored.len(), unique_chunks);
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn compaction_keeps_shared_chunks_until_last_reference() {
+    let vault_path = temp_vault_path("dedup");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+    let mut chunks = ChunksStore::new();
+
+    let payload = noisy_payload(256 * 1024);
+    let mut file_ids = Vec::new();
+
+    for name in ["a.bin", "b.bin"] {
+        let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+            .expect("write blob");
+        let id = store
+            .add_file(
+                FILESYSTEM_ROOT_FOLDER_ID,
+                name.to_string(),
+                "bin".to_string(),
+                blob,
+            )
+            .expect("add file");
+        file_ids.push(id);
+    }
+    ChunksRepository::commit(&mut session, &mut chunks).expect("commit chunks");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit files");
+
+    let unique_chunks = chunks.len();
+    let removed = store.file(&file_ids[0]).expect("file exists").blob.clone();
+    store.remove_file(file_ids[0]).expect("remove file");
+    release_blob(&mut session, &mut chunks, &removed).expect("release blob");
+    assert_eq!(chunks.len(), unique_chunks);
+
+    ChunksRepository::commit(&mut session, &mut chunks).expect("commit chunks");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit delete");
+
+    compact_vault(&mut session).expect("compact vault");
+
+    let store = FilesystemRepository::load(&mut session).expect("reload filesystem");
+    let survivor = store
+        .file(&file_ids[1])
+        .expect("survivor exists")
+        .blob
+        .clone();
+    assert_eq!(
+        read_blob(&mut session, &survivor).expect("read survivor"),
+        payload
+    );
+
+    let mut chunks = ChunksRepository::load(&mut session).expect("reload chunks");
+    assert_eq!(chunks.len(), unique_chunks);
+
+    release_blob(&mut session, &mut chunks, &survivor).expect("release survivor");
+    assert!(chunks.is_empty());
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use chrono::Utc;
+
+use openvault_core::errors::Error;
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::operations::compact::compact_vault;
+use openvault_core::operations::history::{create_checkpoint, current_delta_sequence};
+use openvault_core::operations::replay::replay_at;
+use openvault_core::operations::vault::create_and_open_vault;
+use openvault_core::operations::verify::verify_vault;
+use openvault_core::repositories::{FeatureRepository, FilesystemRepository};
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::runtime::VaultSession;
+use openvault_core::vault::versions::shared::checkpoint::Checkpoint;
+use openvault_core::vault::versions::shared::replay::ReplayTarget;
+
+use common::temp_vault_path;
+
+fn folder_names_at(session: &mut VaultSession, target: ReplayTarget) -> Vec<String> {
+    let replay = replay_at(session, target).expect("replay at target");
+    let store = FilesystemRepository::restore_from_replay(&replay).expect("restore filesystem");
+
+    let mut names: Vec<_> = store
+        .folders(FILESYSTEM_ROOT_FOLDER_ID)
+        .into_iter()
+        .map(|folder| folder.name)
+        .collect();
+    names.sort();
+    names
+}
+
+fn add_folder(session: &mut VaultSession, name: &str) -> u64 {
+    let mut store = FilesystemRepository::load(session).expect("load filesystem");
+    store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, name.to_string())
+        .expect("add folder");
+    FilesystemRepository::commit(session, &mut store).expect("commit folder");
+
+    current_delta_sequence(session).expect("current sequence")
+}
+
+fn write_checkpoint(session: &mut VaultSession) {
+    let store = FilesystemRepository::load(session).expect("load filesystem");
+    let feature = FilesystemRepository::create_checkpoint(&store).expect("checkpoint feature");
+
+    create_checkpoint(session, &mut Checkpoint::new(vec![feature])).expect("write checkpoint");
+}
+
+#[test]
+fn replay_at_rebuilds_state_before_and_after_checkpoints() {
+    let vault_path = temp_vault_path("history");
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let first = add_folder(&mut session, "first");
+    let after_first = Utc::now();
+    write_checkpoint(&mut session);
+
+    let second = add_folder(&mut session, "second");
+    write_checkpoint(&mut session);
+    add_folder(&mut session, "third");
+
+    assert!(folder_names_at(&mut session, ReplayTarget::Sequence(0)).is_empty());
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Sequence(first)),
+        vec!["first"]
+    );
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Sequence(second)),
+        vec!["first", "second"]
+    );
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Timestamp(after_first)),
+        vec!["first"]
+    );
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Timestamp(Utc::now())),
+        vec!["first", "second", "third"]
+    );
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn replay_at_rejects_compacted_or_future_history() {
+    let vault_path = temp_vault_path("history");
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let before_compaction = Utc::now();
+    let first = add_folder(&mut session, "first");
+    compact_vault(&mut session).expect("compact vault");
+    assert_eq!(
+        current_delta_sequence(&mut session).expect("current sequence"),
+        first
+    );
+
+    assert!(matches!(
+        replay_at(&mut session, ReplayTarget::Timestamp(before_compaction)),
+        Err(Error::HistoryUnavailable)
+    ));
+    assert!(matches!(
+        replay_at(&mut session, ReplayTarget::Sequence(first - 1)),
+        Err(Error::HistoryUnavailable)
+    ));
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Sequence(first)),
+        vec!["first"]
+    );
+
+    let second = add_folder(&mut session, "second");
+    assert_eq!(second, first + 1);
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Sequence(second)),
+        vec!["first", "second"]
+    );
+    assert!(matches!(
+        replay_at(&mut session, ReplayTarget::Sequence(second + 1)),
+        Err(Error::HistoryUnavailable)
+    ));
+
+    let report =
+        verify_vault(&vault_path, &Credential::password(b"password")).expect("verify vault");
+    assert!(report.sequence_gaps.is_empty());
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn replay_at_resolves_timestamps_right_after_compaction() {
+    let vault_path = temp_vault_path("history");
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    add_folder(&mut session, "first");
+    compact_vault(&mut session).expect("compact vault");
+
+    let after_compaction = Utc::now();
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Timestamp(after_compaction)),
+        vec!["first"]
+    );
+
+    add_folder(&mut session, "second");
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Timestamp(after_compaction)),
+        vec!["first"]
+    );
+    assert_eq!(
+        folder_names_at(&mut session, ReplayTarget::Timestamp(Utc::now())),
+        vec!["first", "second"]
+    );
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
@@ -0,0 +1,269 @@
+mod common;
+
+use std::io::{Cursor, Seek, SeekFrom, Write};
+
+use openvault_core::errors::Error;
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::features::secrets::{LoginEntry, NewLoginSecret, SECRETS_ROOT_FOLDER_ID};
+use openvault_core::operations::blob::{read_blob, write_blob};
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::key_slots::add_key_slot;
+use openvault_core::operations::vault::{
+    change_password, create_and_open_vault, kdf_params, open_vault, unlock_vault,
+    upgrade_kdf_params,
+};
+use openvault_core::repositories::{
+    ChunksRepository, FeatureRepository, FilesystemRepository, SecretsRepository,
+};
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::versions::shared::boot_header::BootHeader;
+use openvault_core::vault::versions::shared::key_slot::{KEY_SLOT_COUNT, KeySlot, KeySlotTable};
+use openvault_crypto::errors::Error as CryptoError;
+use openvault_crypto::keys::kdf_params::KdfParams;
+
+use common::{secrets_key, temp_vault_path};
+
+fn stronger_params() -> KdfParams {
+    KdfParams::new(32 * 1024, 3, 1).expect("valid params")
+}
+
+#[test]
+fn custom_kdf_params_are_stored_in_boot_header() {
+    let vault_path = temp_vault_path("kdf");
+
+    let config = CreateConfig::default().with_kdf_params(stronger_params());
+    let session =
+        create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+    drop(session);
+
+    let mut session = open_vault(&vault_path, b"password").expect("reopen vault");
+    assert_eq!(
+        kdf_params(&mut session).expect("read params"),
+        stronger_params()
+    );
+    drop(session);
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(&vault_path)
+        .expect("open raw vault");
+    file.seek(SeekFrom::Start(30)).expect("seek to kdf params");
+    file.write_all(&[0xFF]).expect("tamper kdf params");
+    drop(file);
+
+    let tampered = open_vault(&vault_path, b"password");
+    assert!(matches!(tampered, Err(Error::InvalidVaultChecksum)));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn kdf_params_above_the_ceiling_are_rejected() {
+    let vault_path = temp_vault_path("kdf");
+    let excessive = KdfParams::new(KdfParams::MAX.memory_kib * 4, 3, 1).expect("valid params");
+
+    let config = CreateConfig::default().with_kdf_params(excessive);
+    let result = create_and_open_vault(&vault_path, b"password", config);
+    assert!(matches!(
+        result,
+        Err(Error::Crypto(CryptoError::InvalidKdfParams))
+    ));
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    let upgrade = upgrade_kdf_params(
+        &mut session,
+        &[Credential::password(b"password")],
+        excessive,
+    );
+    assert!(matches!(
+        upgrade,
+        Err(Error::Crypto(CryptoError::InvalidKdfParams))
+    ));
+    drop(session);
+
+    // A crafted header only needs a matching checksum to be read back.
+    let mut file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&vault_path)
+        .expect("open raw vault");
+    let mut header = BootHeader::read_from(&mut file).expect("read boot header");
+    header.kdf = excessive;
+    header.write_to(&mut file).expect("write boot header");
+    drop(file);
+
+    let oversized = open_vault(&vault_path, b"password");
+    assert!(matches!(oversized, Err(Error::InvalidVaultFormat)));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn legacy_format_rejects_custom_kdf_params() {
+    let vault_path = temp_vault_path("kdf");
+
+    let config = CreateConfig::default()
+        .with_version(1)
+        .with_kdf_params(stronger_params());
+    let result = create_and_open_vault(&vault_path, b"password", config);
+
+    assert!(matches!(result, Err(Error::KdfParamsUnsupported)));
+    assert!(!vault_path.exists());
+}
+
+#[test]
+fn upgrade_kdf_params_rewraps_every_slot() {
+    let vault_path = temp_vault_path("kdf");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    add_key_slot(&mut session, &Credential::keyfile(b"keyfile-contents")).expect("add slot");
+
+    let missing = upgrade_kdf_params(
+        &mut session,
+        &[Credential::password(b"password")],
+        stronger_params(),
+    );
+    assert!(matches!(missing, Err(Error::KeySlotCredentialMissing(1))));
+
+    let credentials = [
+        Credential::password(b"password"),
+        Credential::keyfile(b"keyfile-contents"),
+    ];
+    upgrade_kdf_params(&mut session, &credentials, stronger_params()).expect("upgrade params");
+    assert_eq!(
+        kdf_params(&mut session).expect("read params"),
+        stronger_params()
+    );
+
+    let slots = KeySlotTable::read_from(session.file_mut(), 3).expect("read slots");
+    assert_eq!(slots.len(), 2);
+    assert!(slots.slots().all(|(_, slot)| slot.kdf == stronger_params()));
+
+    let downgrade = upgrade_kdf_params(&mut session, &credentials, KdfParams::LEGACY);
+    assert!(matches!(downgrade, Err(Error::KdfParamsDowngrade)));
+
+    let less_memory = KdfParams::new(8 * 1024, 12, 1).expect("valid params");
+    let traded = upgrade_kdf_params(&mut session, &credentials, less_memory);
+    assert!(matches!(traded, Err(Error::KdfParamsDowngrade)));
+    drop(session);
+
+    open_vault(&vault_path, b"password").expect("open password slot");
+    unlock_vault(&vault_path, &Credential::keyfile(b"keyfile-contents")).expect("open keyfile");
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn upgrade_kdf_params_migrates_older_formats_to_the_latest() {
+    for version in [1, 2] {
+        let vault_path = temp_vault_path("kdf");
+
+        let config = CreateConfig::default().with_version(version);
+        let mut session =
+            create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+
+        let mut filesystem = FilesystemRepository::load(&mut session).expect("load filesystem");
+        let mut secrets = SecretsRepository::load(&mut session).expect("load secrets");
+        let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+
+        let payload = vec![7u8; 300 * 1024];
+        let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+            .expect("write blob");
+        filesystem
+            .add_file(
+                FILESYSTEM_ROOT_FOLDER_ID,
+                "kept.bin".to_string(),
+                "bin".to_string(),
+                blob,
+            )
+            .expect("add file");
+        let login = LoginEntry::seal(
+            NewLoginSecret {
+                folder_id: SECRETS_ROOT_FOLDER_ID,
+                name: "mail".to_string(),
+                username: "alice".to_string(),
+                password: "hunter2".to_string(),
+                website: None,
+                comments: None,
+                totp: None,
+            },
+            &secrets_key(&session),
+            session.cipher(),
+        )
+        .expect("seal login");
+        secrets.add_entry(login).expect("add login");
+        ChunksRepository::commit(&mut session, &mut chunks).expect("commit chunks");
+        FilesystemRepository::commit(&mut session, &mut filesystem).expect("commit file");
+        SecretsRepository::commit(&mut session, &mut secrets).expect("commit login");
+
+        upgrade_kdf_params(
+            &mut session,
+            &[Credential::password(b"password")],
+            stronger_params(),
+        )
+        .expect("upgrade params");
+        assert_eq!(session.version(), 3, "version {version}");
+        drop(session);
+
+        let mut session = open_vault(&vault_path, b"password").expect("reopen migrated vault");
+        assert_eq!(session.version(), 3, "version {version}");
+        assert_eq!(
+            kdf_params(&mut session).expect("read params"),
+            stronger_params()
+        );
+
+        let filesystem = FilesystemRepository::load(&mut session).expect("load filesystem");
+        let file = filesystem
+            .files(FILESYSTEM_ROOT_FOLDER_ID)
+            .into_iter()
+            .next()
+            .expect("migrated file");
+        assert_eq!(
+            read_blob(&mut session, &file.blob).expect("read blob"),
+            payload
+        );
+
+        let secrets = SecretsRepository::load(&mut session).expect("load secrets");
+        let view = secrets.entries(SECRETS_ROOT_FOLDER_ID)[0]
+            .as_login()
+            .expect("migrated login")
+            .reveal(&secrets_key(&session), session.cipher())
+            .expect("reveal login");
+        assert_eq!(view.password, "hunter2");
+
+        std::fs::remove_file(vault_path).expect("cleanup vault");
+    }
+}
+
+#[test]
+fn v2_vaults_keep_their_original_layout() {
+    let vault_path = temp_vault_path("kdf");
+
+    let config = CreateConfig::default().with_version(2);
+    let mut session =
+        create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+    assert_eq!(
+        KeySlotTable::end_offset_for(2),
+        (BootHeader::LEGACY_SIZE + KEY_SLOT_COUNT * KeySlot::LEGACY_SIZE) as u64
+    );
+    assert_eq!(
+        kdf_params(&mut session).expect("read params"),
+        KdfParams::LEGACY
+    );
+
+    change_password(&mut session, b"password", b"rotated").expect("change password");
+    drop(session);
+
+    open_vault(&vault_path, b"rotated").expect("reopen v2 vault");
+
+    let custom = CreateConfig::default()
+        .with_version(2)
+        .with_kdf_params(stronger_params())
+        .with_overwrite(true);
+    let result = create_and_open_vault(&vault_path, b"password", custom);
+    assert!(matches!(result, Err(Error::KdfParamsUnsupported)));
+
+    std::fs::remove_file(vault_path).ok();
+}
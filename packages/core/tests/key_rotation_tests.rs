@@ -0,0 +1,164 @@
+mod common;
+
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+use openvault_core::errors::Error;
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::features::secrets::{
+    LoginEntry, NewLoginSecret, SECRETS_ROOT_FOLDER_ID, SecretEntry,
+};
+use openvault_core::operations::blob::{read_blob, write_blob};
+use openvault_core::operations::compact::compact_and_rotate_keys;
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::key_slots::add_key_slot;
+use openvault_core::operations::vault::{create_and_open_vault, open_vault, unlock_vault};
+use openvault_core::repositories::{
+    ChunksRepository, FeatureRepository, FilesystemRepository, SecretsRepository,
+};
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::runtime::VaultSession;
+use openvault_core::vault::versions::factory::LATEST_FORMAT_VERSION;
+use openvault_core::vault::versions::shared::boot_header::BootHeader;
+use openvault_core::vault::versions::shared::key_slot::KeySlotTable;
+use openvault_crypto::keys::recovery_key::RecoveryKey;
+
+use common::{secrets_key, temp_vault_path};
+
+fn read_salt(session: &mut VaultSession) -> Vec<u8> {
+    BootHeader::read_from(session.file_mut())
+        .expect("read boot header")
+        .salt
+        .to_vec()
+}
+
+fn assert_rotation_preserves_data(config: CreateConfig) {
+    let vault_path = temp_vault_path("rotation");
+    let mut session =
+        create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+
+    let mut filesystem = FilesystemRepository::load(&mut session).expect("load filesystem");
+    let mut secrets = SecretsRepository::load(&mut session).expect("load secrets");
+
+    let payload = vec![9u8; 64 * 1024];
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+        .expect("write blob");
+    let file_id = filesystem
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "data.bin".to_string(),
+            "bin".to_string(),
+            blob,
+        )
+        .expect("add file");
+    FilesystemRepository::commit(&mut session, &mut filesystem).expect("commit filesystem");
+
+    let entry = LoginEntry::seal(
+        NewLoginSecret {
+            folder_id: SECRETS_ROOT_FOLDER_ID,
+            name: "mail".to_string(),
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+            website: None,
+            comments: None,
+            totp: None,
+        },
+        &secrets_key(&session),
+        session.cipher(),
+    )
+    .expect("seal entry");
+    let entry_id = secrets.add_entry(entry).expect("add entry");
+    SecretsRepository::commit(&mut session, &mut secrets).expect("commit secrets");
+
+    let old_salt = read_salt(&mut session);
+    let old_secrets_key = secrets_key(&session);
+
+    compact_and_rotate_keys(&mut session, &[Credential::password(b"password")])
+        .expect("rotate keys");
+
+    assert_ne!(read_salt(&mut session), old_salt);
+
+    let secrets = SecretsRepository::load(&mut session).expect("reload secrets");
+    let entry = secrets.entry(&entry_id).expect("entry exists");
+    assert!(entry.reveal(&old_secrets_key, session.cipher()).is_err());
+    drop(session);
+
+    let mut session = open_vault(&vault_path, b"password").expect("open rotated vault");
+
+    let filesystem = FilesystemRepository::load(&mut session).expect("reload filesystem");
+    let file = filesystem.file(&file_id).expect("file exists");
+    assert_eq!(
+        read_blob(&mut session, &file.blob).expect("read blob"),
+        payload
+    );
+
+    let secrets = SecretsRepository::load(&mut session).expect("reload secrets");
+    let view = secrets
+        .entry(&entry_id)
+        .and_then(SecretEntry::as_login)
+        .expect("entry exists")
+        .reveal(&secrets_key(&session), session.cipher())
+        .expect("reveal entry");
+    assert_eq!(view.password, "hunter2");
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn rotation_reseals_data_under_new_salt_and_keys() {
+    assert_rotation_preserves_data(CreateConfig::default().with_version(1));
+    assert_rotation_preserves_data(CreateConfig::default());
+}
+
+#[test]
+fn rotation_rewraps_every_key_slot_and_retires_the_old_master() {
+    let vault_path = temp_vault_path("rotation");
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let recovery = RecoveryKey::generate();
+    add_key_slot(&mut session, &Credential::recovery_key(&recovery)).expect("add recovery slot");
+
+    let mut old_slots = vec![0u8; KeySlotTable::size_for(LATEST_FORMAT_VERSION)];
+    let file = session.file_mut();
+    file.seek(SeekFrom::Start(KeySlotTable::offset_for(
+        LATEST_FORMAT_VERSION,
+    )))
+    .expect("seek key slots");
+    file.read_exact(&mut old_slots).expect("read key slots");
+
+    assert!(matches!(
+        compact_and_rotate_keys(&mut session, &[Credential::password(b"password")]),
+        Err(Error::KeySlotCredentialMissing(_))
+    ));
+
+    compact_and_rotate_keys(
+        &mut session,
+        &[
+            Credential::password(b"password"),
+            Credential::recovery_key(&recovery),
+        ],
+    )
+    .expect("rotate keys");
+    drop(session);
+
+    unlock_vault(&vault_path, &Credential::recovery_key(&recovery)).expect("unlock with recovery");
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(&vault_path)
+        .expect("open vault file");
+    file.seek(SeekFrom::Start(KeySlotTable::offset_for(
+        LATEST_FORMAT_VERSION,
+    )))
+    .expect("seek key slots");
+    file.write_all(&old_slots).expect("restore old key slots");
+    drop(file);
+
+    assert!(matches!(
+        open_vault(&vault_path, b"password"),
+        Err(Error::UnlockFailed)
+    ));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
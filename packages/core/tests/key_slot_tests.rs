@@ -0,0 +1,142 @@
+mod common;
+
+use openvault_core::errors::Error;
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::key_slots::{add_key_slot, list_key_slots, revoke_key_slot};
+use openvault_core::operations::vault::{
+    change_password, create_and_open_vault, open_vault, unlock_vault,
+};
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::versions::shared::key_slot::{KEY_SLOT_COUNT, KeySlotKind};
+use openvault_crypto::keys::recovery_key::RecoveryKey;
+
+use common::temp_vault_path;
+
+#[test]
+fn recovery_key_and_keyfile_unlock_vault() {
+    let vault_path = temp_vault_path("key_slot");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let recovery_key = RecoveryKey::generate();
+    add_key_slot(&mut session, &Credential::recovery_key(&recovery_key)).expect("add recovery");
+    add_key_slot(&mut session, &Credential::keyfile(b"keyfile-contents")).expect("add keyfile");
+
+    let kinds: Vec<_> = list_key_slots(&mut session)
+        .expect("list slots")
+        .into_iter()
+        .map(|slot| slot.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            KeySlotKind::Password,
+            KeySlotKind::RecoveryKey,
+            KeySlotKind::Keyfile
+        ]
+    );
+    drop(session);
+
+    let typed = RecoveryKey::parse(&recovery_key.as_str().to_lowercase()).expect("parse key");
+    unlock_vault(&vault_path, &Credential::recovery_key(&typed)).expect("unlock with recovery");
+    unlock_vault(&vault_path, &Credential::keyfile(b"keyfile-contents")).expect("unlock keyfile");
+    open_vault(&vault_path, b"password").expect("unlock with password");
+
+    let wrong = unlock_vault(&vault_path, &Credential::keyfile(b"other-contents"));
+    assert!(matches!(wrong, Err(Error::UnlockFailed)));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn revoked_slot_no_longer_unlocks() {
+    let vault_path = temp_vault_path("key_slot");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+
+    let index = add_key_slot(&mut session, &Credential::password(b"second")).expect("add slot");
+    revoke_key_slot(&mut session, index).expect("revoke slot");
+
+    let last = list_key_slots(&mut session).expect("list slots")[0].index;
+    let result = revoke_key_slot(&mut session, last);
+    assert!(matches!(result, Err(Error::CannotRevokeLastKeySlot)));
+    drop(session);
+
+    let revoked = open_vault(&vault_path, b"second");
+    assert!(matches!(revoked, Err(Error::UnlockFailed)));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn legacy_format_rejects_key_slots() {
+    let vault_path = temp_vault_path("key_slot");
+
+    let config = CreateConfig::default().with_version(1);
+    let mut session =
+        create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+
+    let result = add_key_slot(&mut session, &Credential::password(b"second"));
+    assert!(matches!(result, Err(Error::KeySlotsUnsupported)));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn change_password_stages_new_slot_before_clearing_the_old_one() {
+    let vault_path = temp_vault_path("key_slot");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    add_key_slot(&mut session, &Credential::keyfile(b"keyfile-contents")).expect("add keyfile");
+
+    change_password(&mut session, b"password", b"rotated").expect("change password");
+    let slots = list_key_slots(&mut session).expect("list slots");
+    assert_eq!(slots.len(), 2);
+    assert_eq!(slots[0].kind, KeySlotKind::Keyfile);
+    assert_eq!(slots[1].kind, KeySlotKind::Password);
+    assert_eq!(slots[1].index, 2);
+    drop(session);
+
+    open_vault(&vault_path, b"rotated").expect("open with new password");
+    assert!(matches!(
+        open_vault(&vault_path, b"password"),
+        Err(Error::UnlockFailed)
+    ));
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn change_password_rewraps_in_place_when_table_is_full() {
+    let vault_path = temp_vault_path("key_slot");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    for index in 1..KEY_SLOT_COUNT {
+        add_key_slot(
+            &mut session,
+            &Credential::keyfile(format!("keyfile-{index}").as_bytes()),
+        )
+        .expect("add keyfile");
+    }
+    let full = add_key_slot(&mut session, &Credential::keyfile(b"one-too-many"));
+    assert!(matches!(full, Err(Error::KeySlotsFull)));
+
+    change_password(&mut session, b"password", b"rotated").expect("change password");
+    let slots = list_key_slots(&mut session).expect("list slots");
+    assert_eq!(slots.len(), KEY_SLOT_COUNT);
+    assert_eq!(slots[0].kind, KeySlotKind::Password);
+    drop(session);
+
+    open_vault(&vault_path, b"rotated").expect("open with new password");
+    assert!(matches!(
+        open_vault(&vault_path, b"password"),
+        Err(Error::UnlockFailed)
+    ));
+    unlock_vault(&vault_path, &Credential::keyfile(b"keyfile-7")).expect("unlock keyfile");
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
@@ -0,0 +1,153 @@
+use openvault_core::features::messages::{
+    DecryptedMessage, MessageContact, MessageCredentials, MessagesStore,
+};
+use openvault_crypto::protocols::messaging::sender_fingerprint;
+
+fn store_with_credentials(name: &str) -> (MessagesStore, MessageCredentials) {
+    let mut store = MessagesStore::new();
+    let credentials = store
+        .create_credentials(name.to_string(), None)
+        .expect("create credentials");
+    (store, credentials)
+}
+
+fn contact(credentials: &MessageCredentials) -> MessageContact {
+    MessageContact::new(
+        credentials.name.clone(),
+        credentials.signing_keys.public.clone(),
+        credentials.ephemeral_keys.public.clone(),
+        true,
+        None,
+    )
+}
+
+#[test]
+fn binary_payloads_round_trip_between_contacts() {
+    let (mut alice, alice_credentials) = store_with_credentials("alice");
+    let (mut bob, bob_credentials) = store_with_credentials("bob");
+
+    let bob_id = alice
+        .add_contact(contact(&bob_credentials))
+        .expect("add bob");
+    bob.add_contact(contact(&alice_credentials))
+        .expect("add alice");
+
+    let image: Vec<u8> = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]
+        .into_iter()
+        .chain((0..4096).map(|i| (i * 17 % 256) as u8))
+        .collect();
+
+    let envelope = alice
+        .encrypt_bytes_for_contact(bob_id, &image)
+        .expect("encrypt bytes");
+    assert_eq!(
+        bob.decrypt_bytes_from_contact_name("alice", &envelope)
+            .expect("decrypt bytes"),
+        image
+    );
+
+    let armored = alice
+        .encrypt_for_contact_name("bob", &image)
+        .expect("encrypt armored");
+    assert!(
+        bob.decrypt_from_contact_name("alice", armored.as_bytes())
+            .is_err()
+    );
+}
+
+#[test]
+fn one_envelope_reaches_every_selected_contact() {
+    let (mut sender, sender_credentials) = store_with_credentials("lead");
+    let mut team: Vec<_> = ["ana", "ben", "cy"]
+        .iter()
+        .map(|name| store_with_credentials(name))
+        .collect();
+    let (mut outsider, _) = store_with_credentials("outsider");
+
+    let ids: Vec<_> = team
+        .iter()
+        .map(|(_, credentials)| {
+            sender
+                .add_contact(contact(credentials))
+                .expect("add member")
+        })
+        .collect();
+    for (store, _) in &mut team {
+        store
+            .add_contact(contact(&sender_credentials))
+            .expect("add sender");
+    }
+    outsider
+        .add_contact(contact(&sender_credentials))
+        .expect("add sender");
+
+    let armored = sender
+        .encrypt_for_contacts(&ids, "standup moved to 10:30".as_bytes())
+        .expect("encrypt for contacts");
+
+    for (store, _) in &team {
+        assert_eq!(
+            store
+                .decrypt_from_contact_name("lead", armored.as_bytes())
+                .expect("decrypt"),
+            "standup moved to 10:30"
+        );
+    }
+    assert!(
+        outsider
+            .decrypt_from_contact_name("lead", armored.as_bytes())
+            .is_err()
+    );
+
+    assert!(sender.encrypt_for_contacts(&[], b"nobody").is_err());
+    assert!(
+        sender
+            .encrypt_for_contacts(&[uuid::Uuid::new_v4()], b"unknown")
+            .is_err()
+    );
+}
+
+#[test]
+fn decrypt_auto_identifies_the_sender_by_fingerprint() {
+    let (mut alice, alice_credentials) = store_with_credentials("alice");
+    let (mut bob, bob_credentials) = store_with_credentials("bob");
+    let (mut carol, carol_credentials) = store_with_credentials("carol");
+
+    let bob_for_alice = alice
+        .add_contact(contact(&bob_credentials))
+        .expect("add bob");
+    let bob_for_carol = carol
+        .add_contact(contact(&bob_credentials))
+        .expect("add bob");
+    let alice_id = bob
+        .add_contact(contact(&alice_credentials))
+        .expect("add alice");
+
+    let armored = alice
+        .encrypt_for_contact(bob_for_alice, b"\x00hello\xff")
+        .expect("encrypt");
+    match bob.decrypt_auto(armored.as_bytes()).expect("decrypt auto") {
+        DecryptedMessage::Verified { contact, plaintext } => {
+            assert_eq!(contact.id, alice_id);
+            assert_eq!(plaintext, b"\x00hello\xff");
+        }
+        other => panic!("expected a verified sender, got {other:?}"),
+    }
+
+    let envelope = carol
+        .encrypt_bytes_for_contacts(&[bob_for_carol], b"who am I")
+        .expect("encrypt for contacts");
+    assert_eq!(
+        bob.decrypt_bytes_auto(&envelope).expect("decrypt auto"),
+        DecryptedMessage::UnknownSender {
+            fingerprint: Some(sender_fingerprint(&carol_credentials.signing_keys.public)),
+        }
+    );
+
+    bob.add_contact(contact(&carol_credentials))
+        .expect("add carol");
+    assert!(matches!(
+        bob.decrypt_bytes_auto(&envelope).expect("decrypt auto"),
+        DecryptedMessage::Verified { contact, .. } if contact.name == "carol"
+    ));
+}
@@ -0,0 +1,167 @@
+mod common;
+
+use std::io::Cursor;
+
+use uuid::Uuid;
+
+use openvault_core::errors::Error;
+use openvault_core::features::chunks::ChunksStore;
+use openvault_core::features::filesystem::{FILESYSTEM_ROOT_FOLDER_ID, FilesystemStore};
+use openvault_core::features::messages::{MessageContact, MessageCredentials, MessagesStore};
+use openvault_core::operations::blob::{read_blob, write_blob};
+use openvault_core::operations::share::{SharedFileDestination, import_shared_file, share_file};
+use openvault_core::operations::space::space_report;
+use openvault_core::operations::vault::create_and_open_vault;
+use openvault_core::repositories::{ChunksRepository, FeatureRepository};
+use openvault_core::vault::runtime::VaultSession;
+
+use common::temp_vault_path;
+
+struct Party {
+    path: std::path::PathBuf,
+    session: VaultSession,
+    chunks: ChunksStore,
+    filesystem: FilesystemStore,
+    messages: MessagesStore,
+    credentials: MessageCredentials,
+}
+
+impl Party {
+    fn new(name: &str) -> Self {
+        let path = temp_vault_path("share");
+        let session = create_and_open_vault(&path, b"password", Default::default())
+            .expect("create and open vault");
+        let mut messages = MessagesStore::new();
+        let credentials = messages
+            .create_credentials(name.to_string(), None)
+            .expect("create credentials");
+
+        Self {
+            path,
+            session,
+            chunks: ChunksStore::new(),
+            filesystem: FilesystemStore::new(),
+            messages,
+            credentials,
+        }
+    }
+
+    fn contact_for(&self) -> MessageContact {
+        MessageContact::new(
+            self.credentials.name.clone(),
+            self.credentials.signing_keys.public.clone(),
+            self.credentials.ephemeral_keys.public.clone(),
+            true,
+            None,
+        )
+    }
+
+    fn import(&mut self, contact_id: Uuid, shared: &[u8]) -> Result<Uuid, Error> {
+        import_shared_file(
+            &mut self.session,
+            &mut self.chunks,
+            &mut self.filesystem,
+            &self.messages,
+            contact_id,
+            SharedFileDestination {
+                folder_id: FILESYSTEM_ROOT_FOLDER_ID,
+                name: "photo.raw".to_string(),
+                extension: "raw".to_string(),
+            },
+            &mut Cursor::new(shared),
+        )
+    }
+}
+
+impl Drop for Party {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[test]
+fn shared_file_is_imported_into_the_recipient_vault() {
+    let mut alice = Party::new("alice");
+    let mut bob = Party::new("bob");
+    let mallory = Party::new("mallory");
+
+    let bob_id = alice
+        .messages
+        .add_contact(bob.contact_for())
+        .expect("add bob");
+    let alice_id = bob
+        .messages
+        .add_contact(alice.contact_for())
+        .expect("add alice");
+    let mallory_id = bob
+        .messages
+        .add_contact(mallory.contact_for())
+        .expect("add mallory");
+
+    let payload: Vec<u8> = (0..300 * 1024).map(|i| (i * 31 % 256) as u8).collect();
+    let blob = write_blob(
+        &mut alice.session,
+        &mut alice.chunks,
+        &mut Cursor::new(payload.clone()),
+    )
+    .expect("write blob");
+    let file_id = alice
+        .filesystem
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "photo.raw".to_string(),
+            "raw".to_string(),
+            blob,
+        )
+        .expect("add file");
+
+    let mut shared = Vec::new();
+    let written = share_file(
+        &mut alice.session,
+        &alice.filesystem,
+        &alice.messages,
+        file_id,
+        bob_id,
+        &mut shared,
+    )
+    .expect("share file");
+    assert_eq!(written, payload.len() as u64);
+
+    let reclaimable = space_report(&mut bob.session)
+        .expect("space report")
+        .reclaimable_bytes;
+    assert!(bob.import(mallory_id, &shared).is_err());
+    assert!(bob.filesystem.files(FILESYSTEM_ROOT_FOLDER_ID).is_empty());
+    ChunksRepository::commit(&mut bob.session, &mut bob.chunks).expect("commit chunks");
+    assert!(
+        space_report(&mut bob.session)
+            .expect("space report")
+            .reclaimable_bytes
+            > reclaimable
+    );
+
+    let reclaimable = space_report(&mut bob.session)
+        .expect("space report")
+        .reclaimable_bytes;
+    assert!(bob.import(alice_id, &shared[..shared.len() - 100]).is_err());
+    assert!(bob.chunks.is_empty());
+    ChunksRepository::commit(&mut bob.session, &mut bob.chunks).expect("commit chunks");
+    assert!(
+        space_report(&mut bob.session)
+            .expect("space report")
+            .reclaimable_bytes
+            > reclaimable
+    );
+
+    let imported = bob.import(alice_id, &shared).expect("import shared file");
+    let file = bob
+        .filesystem
+        .file(&imported)
+        .expect("imported file")
+        .clone();
+    assert_eq!(file.name, "photo.raw");
+    assert_eq!(
+        read_blob(&mut bob.session, &file.blob).expect("read blob"),
+        payload
+    );
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use std::io::Cursor;
+
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::operations::blob::{release_blob, write_blob};
+use openvault_core::operations::compact::compact_vault;
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::history::create_checkpoint;
+use openvault_core::operations::space::{CompactionPolicy, SpaceReport, space_report};
+use openvault_core::operations::vault::create_and_open_vault;
+use openvault_core::operations::verify::verify_vault;
+use openvault_core::repositories::{ChunksRepository, FeatureRepository, FilesystemRepository};
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::runtime::VaultSession;
+use openvault_core::vault::versions::shared::checkpoint::Checkpoint;
+
+use common::temp_vault_path;
+
+fn write_checkpoint(session: &mut VaultSession) {
+    let chunks = ChunksRepository::load(session).expect("load chunks");
+    let store = FilesystemRepository::load(session).expect("load filesystem");
+
+    let features = vec![
+        ChunksRepository::create_checkpoint(&chunks).expect("checkpoint chunks"),
+        FilesystemRepository::create_checkpoint(&store).expect("checkpoint filesystem"),
+    ];
+    create_checkpoint(session, &mut Checkpoint::new(features)).expect("write checkpoint");
+}
+
+fn assert_matches_verifier(session: &mut VaultSession, vault_path: &std::path::Path) -> u64 {
+    let reclaimable = space_report(session)
+        .expect("space report")
+        .reclaimable_bytes;
+    let report =
+        verify_vault(vault_path, &Credential::password(b"password")).expect("verify vault");
+
+    assert_eq!(reclaimable, report.found_reclaimable_bytes);
+    reclaimable
+}
+
+fn assert_tracks_reclaimable_space(version: u16) {
+    let vault_path = temp_vault_path("space");
+    let config = CreateConfig::default().with_version(version);
+    let mut session =
+        create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+
+    assert_eq!(assert_matches_verifier(&mut session, &vault_path), 0);
+
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(vec![3u8; 4096]))
+        .expect("write blob");
+    let file_id = store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "drop.bin".to_string(),
+            "bin".to_string(),
+            blob.clone(),
+        )
+        .expect("add file");
+    store
+        .add_folder(FILESYSTEM_ROOT_FOLDER_ID, "kept".to_string())
+        .expect("add folder");
+    ChunksRepository::commit(&mut session, &mut chunks).expect("commit chunks");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit filesystem");
+
+    write_checkpoint(&mut session);
+    let after_checkpoint = assert_matches_verifier(&mut session, &vault_path);
+    assert!(after_checkpoint > 0);
+
+    write_checkpoint(&mut session);
+    let after_second_checkpoint = assert_matches_verifier(&mut session, &vault_path);
+    assert!(after_second_checkpoint > after_checkpoint);
+
+    store.remove_file(file_id).expect("remove file");
+    release_blob(&mut session, &mut chunks, &blob).expect("release blob");
+    assert_eq!(
+        space_report(&mut session)
+            .expect("space report")
+            .reclaimable_bytes,
+        after_second_checkpoint
+    );
+    ChunksRepository::commit(&mut session, &mut chunks).expect("commit chunks");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit delete");
+
+    let after_release = space_report(&mut session)
+        .expect("space report")
+        .reclaimable_bytes;
+    assert!(after_release > after_second_checkpoint);
+
+    write_checkpoint(&mut session);
+    assert_matches_verifier(&mut session, &vault_path);
+
+    compact_vault(&mut session).expect("compact vault");
+    assert_eq!(assert_matches_verifier(&mut session, &vault_path), 0);
+
+    drop(session);
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn reclaimable_bytes_track_superseded_frames_and_released_blobs() {
+    assert_tracks_reclaimable_space(1);
+    assert_tracks_reclaimable_space(2);
+}
+
+#[test]
+fn compaction_policy_requires_both_thresholds() {
+    let policy = CompactionPolicy::default();
+    let large = 200 * 1024 * 1024;
+
+    let report = |total_bytes, reclaimable_bytes| SpaceReport {
+        total_bytes,
+        reclaimable_bytes,
+    };
+
+    assert!(policy.should_compact(&report(large, large / 2)));
+    assert!(!policy.should_compact(&report(large, large / 4)));
+    assert!(!policy.should_compact(&report(10 * 1024 * 1024, 9 * 1024 * 1024)));
+    assert!(!policy.should_compact(&report(0, 0)));
+
+    let eager = CompactionPolicy::new(0.0, 0);
+    assert!(eager.should_compact(&report(1024, 1)));
+    assert!(!eager.should_compact(&report(1024, 0)));
+}
@@ -0,0 +1,126 @@
+use openvault_core::features::secrets::{OtpAuthUri, SecretError, TOTP, TotpAlgorithm};
+
+// RFC 6238 appendix B seeds, base32 encoded: the ASCII digits "1234567890" repeated
+// to 20, 32 and 64 bytes for SHA-1, SHA-256 and SHA-512 respectively.
+const SHA1_SEED: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+const SHA256_SEED: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZA====";
+const SHA512_SEED: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNA=";
+
+fn totp(seed: &str, algorithm: TotpAlgorithm, period: u64, digits: u8) -> TOTP {
+    TOTP::new(seed.to_string(), Some(period), Some(digits))
+        .expect("valid totp")
+        .with_algorithm(algorithm)
+}
+
+#[test]
+fn rfc6238_test_vectors() {
+    let vectors: [(u64, &str, &str, &str); 6] = [
+        (59, "94287082", "46119246", "90693936"),
+        (1111111109, "07081804", "68084774", "25091201"),
+        (1111111111, "14050471", "67062674", "99943326"),
+        (1234567890, "89005924", "91819424", "93441116"),
+        (2000000000, "69279037", "90698825", "38618901"),
+        (20000000000, "65353130", "77737706", "47863826"),
+    ];
+
+    let sha1 = totp(SHA1_SEED, TotpAlgorithm::Sha1, 30, 8);
+    let sha256 = totp(SHA256_SEED, TotpAlgorithm::Sha256, 30, 8);
+    let sha512 = totp(SHA512_SEED, TotpAlgorithm::Sha512, 30, 8);
+
+    for (time, expected_sha1, expected_sha256, expected_sha512) in vectors {
+        assert_eq!(sha1.code_at(time).expect("sha1 code").code, expected_sha1);
+        assert_eq!(
+            sha256.code_at(time).expect("sha256 code").code,
+            expected_sha256
+        );
+        assert_eq!(
+            sha512.code_at(time).expect("sha512 code").code,
+            expected_sha512
+        );
+    }
+}
+
+#[test]
+fn rfc4226_test_vectors() {
+    let expected = [
+        "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583", "399871",
+        "520489",
+    ];
+
+    // With a one second period the TOTP counter is the timestamp itself.
+    let hotp = totp(SHA1_SEED, TotpAlgorithm::Sha1, 1, 6);
+    for (counter, code) in expected.iter().enumerate() {
+        assert_eq!(hotp.code_at(counter as u64).expect("hotp code").code, *code);
+    }
+}
+
+#[test]
+fn codes_report_the_time_left_in_their_period() {
+    let totp = totp(SHA1_SEED, TotpAlgorithm::Sha1, 30, 6);
+
+    let code = totp.code_at(59).expect("code");
+    assert_eq!(code.code, "287082");
+    assert_eq!(code.period, 30);
+    assert_eq!(code.remaining_seconds, 1);
+    assert_eq!(totp.code_at(60).expect("code").remaining_seconds, 30);
+
+    let lowercase = TOTP::new(SHA1_SEED.to_lowercase(), None, None).expect("valid totp");
+    assert_eq!(lowercase.code_at(59).expect("code").code, "287082");
+
+    let invalid = TOTP::new("not base32 at all!".to_string(), None, None).expect("valid totp");
+    assert!(matches!(
+        invalid.code_at(59),
+        Err(SecretError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn otpauth_uris_parse_and_round_trip() {
+    let uri = OtpAuthUri::parse(
+        "otpauth://totp/ACME%20Co:john.doe%40example.com?secret=gezd gnbv gy3t qojq gezd gnbv gy3t qojq&issuer=ACME+Co&algorithm=sha256&digits=8&period=60",
+    )
+    .expect("parse uri");
+
+    assert_eq!(uri.issuer.as_deref(), Some("ACME Co"));
+    assert_eq!(uri.account, "john.doe@example.com");
+    assert_eq!(uri.totp.secret, SHA1_SEED);
+    assert_eq!(uri.totp.algorithm, TotpAlgorithm::Sha256);
+    assert_eq!(uri.totp.digits.get(), 8);
+    assert_eq!(uri.totp.period.get(), 60);
+
+    let exported = uri.to_uri();
+    assert_eq!(
+        exported,
+        format!(
+            "otpauth://totp/ACME%20Co:john.doe%40example.com?secret={SHA1_SEED}&issuer=ACME%20Co&algorithm=SHA256&digits=8&period=60"
+        )
+    );
+    assert_eq!(OtpAuthUri::parse(&exported).expect("reparse uri"), uri);
+
+    let minimal = OtpAuthUri::parse(&format!("otpauth://totp/alice?secret={SHA1_SEED}"))
+        .expect("parse minimal uri");
+    assert_eq!(minimal.issuer, None);
+    assert_eq!(minimal.account, "alice");
+    assert_eq!(minimal.totp.algorithm, TotpAlgorithm::Sha1);
+    assert_eq!(minimal.totp.digits.get(), 6);
+    assert_eq!(minimal.totp.period.get(), 30);
+}
+
+#[test]
+fn malformed_otpauth_uris_are_rejected() {
+    let rejected = [
+        format!("otpauth://hotp/alice?secret={SHA1_SEED}&counter=1"),
+        "otpauth://totp/alice?issuer=ACME".to_string(),
+        "otpauth://totp/alice?secret=GEZDGNBVGY3TQOJ1GEZDGNBVGY3TQOJQ".to_string(),
+        format!("otpauth://totp/alice?secret={SHA1_SEED}&algorithm=MD5"),
+        format!("otpauth://totp/alice?secret={SHA1_SEED}&digits=0"),
+        format!("otpauth://totp/al%ZZice?secret={SHA1_SEED}"),
+    ];
+
+    for uri in rejected {
+        assert!(
+            matches!(OtpAuthUri::parse(&uri), Err(SecretError::InvalidInput(_))),
+            "{uri} should be rejected"
+        );
+    }
+}
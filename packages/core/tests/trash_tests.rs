@@ -0,0 +1,114 @@
+mod common;
+
+use std::io::Cursor;
+
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::features::secrets::{
+    LoginEntry, NewLoginSecret, SECRETS_ROOT_FOLDER_ID, SecretEntry,
+};
+use openvault_core::operations::blob::{read_blob, write_blob};
+use openvault_core::operations::compact::compact_vault;
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::vault::{change_password, create_and_open_vault, open_vault};
+use openvault_core::repositories::{
+    ChunksRepository, FeatureRepository, FilesystemRepository, SecretsRepository,
+};
+
+use common::{secrets_key, temp_vault_path};
+
+#[test]
+fn trashed_file_survives_compaction_until_purged() {
+    let vault_path = temp_vault_path("trash");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+
+    let payload = vec![9u8; 512 * 1024];
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(payload.clone()))
+        .expect("write blob");
+    let file_id = store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "big.bin".to_string(),
+            "bin".to_string(),
+            blob,
+        )
+        .expect("add file");
+    store.trash_file(file_id).expect("trash file");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit trash");
+
+    compact_vault(&mut session).expect("compact with trash");
+
+    let mut store = FilesystemRepository::load(&mut session).expect("reload filesystem");
+    store.restore_from_trash(file_id).expect("restore file");
+    let restored_blob = store.file(&file_id).expect("file restored").blob.clone();
+    assert_eq!(
+        read_blob(&mut session, &restored_blob).expect("read restored"),
+        payload
+    );
+
+    store.trash_file(file_id).expect("trash again");
+    store.empty_trash().expect("empty trash");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit purge");
+
+    let before_size = session.file().metadata().expect("before metadata").len();
+    compact_vault(&mut session).expect("compact after purge");
+    let after_size = session.file().metadata().expect("after metadata").len();
+
+    assert!(after_size < before_size);
+    assert!(
+        FilesystemRepository::load(&mut session)
+            .expect("reload filesystem")
+            .trash()
+            .is_empty()
+    );
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn trashed_secret_is_resealed_on_password_change() {
+    let vault_path = temp_vault_path("trash");
+
+    let config = CreateConfig::default().with_version(1);
+    let mut session =
+        create_and_open_vault(&vault_path, b"old-password", config).expect("create and open vault");
+    let mut secrets = SecretsRepository::load(&mut session).expect("load secrets");
+
+    let entry = LoginEntry::seal(
+        NewLoginSecret {
+            folder_id: SECRETS_ROOT_FOLDER_ID,
+            name: "mail".to_string(),
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+            website: None,
+            comments: None,
+            totp: None,
+        },
+        &secrets_key(&session),
+        session.cipher(),
+    )
+    .expect("seal entry");
+    let entry_id = secrets.add_entry(entry).expect("add entry");
+    secrets.trash_entry(entry_id).expect("trash entry");
+    SecretsRepository::commit(&mut session, &mut secrets).expect("commit secrets");
+
+    change_password(&mut session, b"old-password", b"new-password").expect("change password");
+    drop(session);
+
+    let mut session = open_vault(&vault_path, b"new-password").expect("open with new password");
+    let mut secrets = SecretsRepository::load(&mut session).expect("reload secrets");
+    secrets.restore_from_trash(entry_id).expect("restore entry");
+
+    let view = secrets
+        .entry(&entry_id)
+        .and_then(SecretEntry::as_login)
+        .expect("entry restored")
+        .reveal(&secrets_key(&session), session.cipher())
+        .expect("reveal entry");
+    assert_eq!(view.password, "hunter2");
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use std::io::{Cursor, Seek, SeekFrom, Write};
+
+use openvault_core::errors::Error;
+use openvault_core::features::filesystem::FILESYSTEM_ROOT_FOLDER_ID;
+use openvault_core::operations::blob::write_blob;
+use openvault_core::operations::config::CreateConfig;
+use openvault_core::operations::vault::create_and_open_vault;
+use openvault_core::operations::verify::verify_vault;
+use openvault_core::repositories::{ChunksRepository, FeatureRepository, FilesystemRepository};
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::versions::shared::verify::FrameKind;
+
+use common::temp_vault_path;
+
+fn credential() -> Credential {
+    Credential::password(b"password")
+}
+
+#[test]
+fn verify_reports_healthy_vault_and_orphaned_blobs() {
+    let vault_path = temp_vault_path("verify");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(vec![1u8; 1024]))
+        .expect("write blob");
+    store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "kept.bin".to_string(),
+            "bin".to_string(),
+            blob,
+        )
+        .expect("add file");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit file");
+
+    let report = verify_vault(&vault_path, &credential()).expect("verify vault");
+    assert!(report.is_healthy());
+    assert_eq!(report.records_checked, 1);
+    assert_eq!(report.blobs_checked, 1);
+    assert!(report.orphaned_frames.is_empty());
+    assert_eq!(report.found_reclaimable_bytes, 0);
+
+    write_blob(&mut session, &mut chunks, &mut Cursor::new(vec![2u8; 1024]))
+        .expect("write orphan blob");
+
+    let report = verify_vault(&vault_path, &credential()).expect("verify vault");
+    assert!(report.is_healthy());
+    assert_eq!(report.orphaned_frames.len(), 2);
+    assert!(
+        report
+            .orphaned_frames
+            .iter()
+            .any(|frame| frame.kind == FrameKind::BlobManifest)
+    );
+    assert_eq!(
+        report.found_reclaimable_bytes,
+        report.orphaned_frames.iter().map(|f| f.size).sum::<u64>()
+    );
+    assert_eq!(report.recorded_reclaimable_bytes, 0);
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn verify_reports_broken_chunk_and_dangling_blob() {
+    let vault_path = temp_vault_path("verify");
+
+    let mut session = create_and_open_vault(&vault_path, b"password", Default::default())
+        .expect("create and open vault");
+    let mut store = FilesystemRepository::load(&mut session).expect("load filesystem");
+
+    let chunk_offset = session.file().metadata().expect("metadata").len();
+    let mut chunks = ChunksRepository::load(&mut session).expect("load chunks");
+    let blob = write_blob(&mut session, &mut chunks, &mut Cursor::new(vec![3u8; 4096]))
+        .expect("write blob");
+    let blob_id = blob.id;
+    store
+        .add_file(
+            FILESYSTEM_ROOT_FOLDER_ID,
+            "damaged.bin".to_string(),
+            "bin".to_string(),
+            blob,
+        )
+        .expect("add file");
+    FilesystemRepository::commit(&mut session, &mut store).expect("commit file");
+    drop(session);
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(&vault_path)
+        .expect("open vault file");
+    file.seek(SeekFrom::Start(chunk_offset + 40))
+        .expect("seek into chunk");
+    file.write_all(&[0xff; 4]).expect("corrupt chunk");
+    drop(file);
+
+    let report = verify_vault(&vault_path, &credential()).expect("verify vault");
+
+    assert!(!report.is_healthy());
+    assert_eq!(report.broken_frames.len(), 1);
+    assert_eq!(report.broken_frames[0].offset, chunk_offset);
+    assert_eq!(report.dangling_blobs.len(), 1);
+    assert_eq!(report.dangling_blobs[0].blob_id, blob_id);
+    assert_eq!(report.dangling_blobs[0].missing_chunks, vec![chunk_offset]);
+    assert!(report.sequence_gaps.is_empty());
+
+    std::fs::remove_file(vault_path).expect("cleanup vault");
+}
+
+#[test]
+fn verify_rejects_wrong_password_for_every_format() {
+    for version in [1, 2, 3] {
+        let vault_path = temp_vault_path("verify");
+
+        let config = CreateConfig::default().with_version(version);
+        let session =
+            create_and_open_vault(&vault_path, b"password", config).expect("create and open vault");
+        drop(session);
+
+        let result = verify_vault(&vault_path, &Credential::password(b"wrong"));
+        assert!(
+            matches!(result, Err(Error::UnlockFailed)),
+            "version {version}"
+        );
+
+        let report = verify_vault(&vault_path, &credential()).expect("verify vault");
+        assert!(report.is_healthy(), "version {version}");
+
+        std::fs::remove_file(vault_path).expect("cleanup vault");
+    }
+}
@@ -8,6 +8,8 @@ pub use factory::CompressionAlgorithm;
 pub trait Compressor: Debug + Send + Sync {
     fn compress(&self, data: &[u8]) -> Result<Vec<u8>>;
     fn decompress(&self, data: &[u8]) -> Result<Vec<u8>>;
+    /// Fails instead of producing more than `limit` bytes.
+    fn decompress_bounded(&self, data: &[u8], limit: usize) -> Result<Vec<u8>>;
     fn compress_stream(&self, input: &mut dyn Read, output: &mut dyn Write) -> Result<()>;
     fn decompress_stream(&self, input: &mut dyn Read, output: &mut dyn Write) -> Result<()>;
 }
@@ -1,5 +1,7 @@
 use std::io::{Cursor, Read, Write};
 
+use zeroize::Zeroize;
+
 use crate::compression::Compressor;
 use crate::errors::{Error, Result};
 
@@ -17,6 +19,26 @@ impl Compressor for Zstd {
             .map_err(|e| Error::DecompressionFailed(e.to_string()))
     }
 
+    fn decompress_bounded(&self, data: &[u8], limit: usize) -> Result<Vec<u8>> {
+        let decoder = zstd::stream::read::Decoder::new(Cursor::new(data))
+            .map_err(|e| Error::DecompressionFailed(e.to_string()))?;
+
+        let mut output = Vec::new();
+        decoder
+            .take(limit as u64 + 1)
+            .read_to_end(&mut output)
+            .map_err(|e| Error::DecompressionFailed(e.to_string()))?;
+
+        if output.len() > limit {
+            output.zeroize();
+            return Err(Error::DecompressionFailed(format!(
+                "Output exceeds {limit} bytes"
+            )));
+        }
+
+        Ok(output)
+    }
+
     fn compress_stream(&self, input: &mut dyn Read, output: &mut dyn Write) -> Result<()> {
         zstd::stream::copy_encode(input, output, 0)
             .map_err(|e| Error::CompressionFailed(e.to_string()))?;
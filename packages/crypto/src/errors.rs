@@ -8,6 +8,9 @@ pub enum Error {
     #[error("Key derivation failed")]
     KeyDerivationFailed,
 
+    #[error("Invalid key derivation parameters")]
+    InvalidKdfParams,
+
     #[error("HKDF expand failed")]
     HkdfExpandFailed,
 
@@ -41,6 +44,9 @@ pub enum Error {
     #[error("Unsupported envelope version: {0}")]
     UnsupportedEnvelopeVersion(u8),
 
+    #[error("Message is not addressed to this key")]
+    RecipientNotFound,
+
     #[error("Signature verification failed")]
     SignatureVerificationFailed,
 
@@ -49,6 +55,12 @@ pub enum Error {
 
     #[error("Failed to lock memory")]
     MemoryLockFailed,
+
+    #[error("Invalid recovery key")]
+    InvalidRecoveryKey,
+
+    #[error("Invalid generator options: {0}")]
+    InvalidGeneratorOptions(String),
 }
 
 pub type Result<T = ()> = std::result::Result<T, Error>;
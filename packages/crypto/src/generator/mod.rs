@@ -0,0 +1,37 @@
+mod passphrase;
+mod password;
+
+pub use passphrase::{MAX_PASSPHRASE_WORDS, MIN_PASSPHRASE_WORDS, PassphraseOptions};
+pub use password::{MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH, PasswordOptions};
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct GeneratedPassword {
+    value: String,
+    entropy_bits: f64,
+}
+
+impl GeneratedPassword {
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    pub fn entropy_bits(&self) -> f64 {
+        self.entropy_bits
+    }
+}
+
+// Draws are rejected above the largest multiple of `bound`, a bare modulo would favour low indexes.
+fn random_index(bound: usize) -> usize {
+    let bound = bound as u64;
+    let limit = u64::MAX - u64::MAX % bound;
+
+    loop {
+        let value = OsRng.next_u64();
+        if value < limit {
+            return (value % bound) as usize;
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use super::{GeneratedPassword, random_index};
+use crate::errors::{Error, Result};
+
+pub const MIN_PASSPHRASE_WORDS: usize = 3;
+pub const MAX_PASSPHRASE_WORDS: usize = 20;
+
+// BIP-39 English list (CC0): 2048 words of 3 to 8 lowercase letters, unique in their first four.
+const WORDLIST: &str = include_str!("wordlist.txt");
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PassphraseOptions {
+    pub words: usize,
+    pub separator: String,
+    pub capitalize: bool,
+    pub include_number: bool,
+}
+
+impl Default for PassphraseOptions {
+    fn default() -> Self {
+        Self {
+            words: 6,
+            separator: "-".to_string(),
+            capitalize: false,
+            include_number: false,
+        }
+    }
+}
+
+impl GeneratedPassword {
+    pub fn passphrase(options: &PassphraseOptions) -> Result<Self> {
+        if !(MIN_PASSPHRASE_WORDS..=MAX_PASSPHRASE_WORDS).contains(&options.words) {
+            return Err(Error::InvalidGeneratorOptions(format!(
+                "Passphrase must have between {MIN_PASSPHRASE_WORDS} and {MAX_PASSPHRASE_WORDS} words"
+            )));
+        }
+
+        let wordlist: Vec<&str> = WORDLIST.lines().collect();
+        let longest = wordlist
+            .iter()
+            .map(|word| word.len())
+            .max()
+            .unwrap_or_default();
+
+        // The digit is appended to one word, its position and value both add entropy.
+        let number = options
+            .include_number
+            .then(|| (random_index(options.words), random_index(10)));
+
+        let mut value =
+            String::with_capacity(options.words * (longest + options.separator.len()) + 1);
+        for index in 0..options.words {
+            if index > 0 {
+                value.push_str(&options.separator);
+            }
+
+            let word = wordlist[random_index(wordlist.len())];
+            let mut letters = word.chars();
+            match letters.next() {
+                Some(first) if options.capitalize => {
+                    value.push(first.to_ascii_uppercase());
+                    value.push_str(letters.as_str());
+                }
+                _ => value.push_str(word),
+            }
+
+            if let Some((position, digit)) = number
+                && position == index
+            {
+                value.push(char::from(b'0' + digit as u8));
+            }
+        }
+
+        let mut entropy_bits = options.words as f64 * (wordlist.len() as f64).log2();
+        if options.include_number {
+            entropy_bits += 10f64.log2() + (options.words as f64).log2();
+        }
+
+        Ok(Self {
+            value,
+            entropy_bits,
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use zeroize::Zeroize;
+
+use super::{GeneratedPassword, random_index};
+use crate::errors::{Error, Result};
+
+pub const MIN_PASSWORD_LENGTH: usize = 4;
+pub const MAX_PASSWORD_LENGTH: usize = 256;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+// Quotes, backslash and backtick are left out, they routinely break web forms and shells.
+const SYMBOLS: &str = "!#$%&()*+,-./:;<=>?@[]^_{|}~";
+const LOOK_ALIKES: &str = "Il1O0|";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PasswordOptions {
+    pub length: usize,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    pub exclude_look_alikes: bool,
+}
+
+impl Default for PasswordOptions {
+    fn default() -> Self {
+        Self {
+            length: 20,
+            lowercase: true,
+            uppercase: true,
+            digits: true,
+            symbols: true,
+            exclude_look_alikes: false,
+        }
+    }
+}
+
+impl PasswordOptions {
+    fn classes(&self) -> Vec<Vec<u8>> {
+        [
+            (self.lowercase, LOWERCASE),
+            (self.uppercase, UPPERCASE),
+            (self.digits, DIGITS),
+            (self.symbols, SYMBOLS),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, class)| {
+            class
+                .bytes()
+                .filter(|c| !(self.exclude_look_alikes && LOOK_ALIKES.as_bytes().contains(c)))
+                .collect()
+        })
+        .collect()
+    }
+}
+
+impl GeneratedPassword {
+    // Every enabled class appears at least once: candidates missing one are drawn again,
+    // which keeps the result uniform over all passwords that satisfy the rules.
+    pub fn generate(options: &PasswordOptions) -> Result<Self> {
+        if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&options.length) {
+            return Err(Error::InvalidGeneratorOptions(format!(
+                "Password length must be between {MIN_PASSWORD_LENGTH} and {MAX_PASSWORD_LENGTH}"
+            )));
+        }
+
+        let classes = options.classes();
+        if classes.is_empty() {
+            return Err(Error::InvalidGeneratorOptions(
+                "At least one character class must be enabled".to_string(),
+            ));
+        }
+        if options.length < classes.len() {
+            return Err(Error::InvalidGeneratorOptions(
+                "Password is too short to include every character class".to_string(),
+            ));
+        }
+
+        let pool = classes.concat();
+        let mut value = String::with_capacity(options.length);
+        loop {
+            value.zeroize();
+            for _ in 0..options.length {
+                value.push(char::from(pool[random_index(pool.len())]));
+            }
+
+            if classes
+                .iter()
+                .all(|class| value.bytes().any(|c| class.contains(&c)))
+            {
+                break;
+            }
+        }
+
+        Ok(Self {
+            value,
+            entropy_bits: entropy_bits(&classes, options.length),
+        })
+    }
+}
+
+// log2 of the number of accepted passwords: pool^length scaled by the fraction that uses
+// every class, counted by inclusion-exclusion over the classes left out.
+fn entropy_bits(classes: &[Vec<u8>], length: usize) -> f64 {
+    let pool = classes.iter().map(Vec::len).sum::<usize>() as f64;
+
+    let fraction: f64 = (0..1u32 << classes.len())
+        .map(|mask| {
+            let missing = classes
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| mask & (1 << index) != 0)
+                .map(|(_, class)| class.len())
+                .sum::<usize>() as f64;
+            let sign = if mask.count_ones() % 2 == 0 {
+                1.0
+            } else {
+                -1.0
+            };
+
+            sign * (1.0 - missing / pool).powi(length as i32)
+        })
+        .sum();
+
+    length as f64 * pool.log2() + fraction.log2()
+}
@@ -0,0 +1,16 @@
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+use crate::errors::{Error, Result};
+
+// Only kept for interoperability with protocols that mandate it, such as RFC 4226 one-time codes.
+pub struct HmacSha1;
+
+impl HmacSha1 {
+    pub fn mac(key: &[u8], data: &[u8]) -> Result<[u8; 20]> {
+        let mut mac = Hmac::<Sha1>::new_from_slice(key).map_err(|_| Error::InvalidKeyLength)?;
+        mac.update(data);
+
+        Ok(mac.finalize().into_bytes().into())
+    }
+}
@@ -0,0 +1,29 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::errors::{Error, Result};
+
+pub struct HmacSha256;
+
+impl HmacSha256 {
+    pub fn mac(key: &[u8], data: &[u8]) -> Result<[u8; 32]> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).map_err(|_| Error::InvalidKeyLength)?;
+        mac.update(data);
+
+        Ok(mac.finalize().into_bytes().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_depends_on_key() {
+        let first = HmacSha256::mac(b"first-key", b"payload").unwrap();
+        let second = HmacSha256::mac(b"second-key", b"payload").unwrap();
+
+        assert_eq!(first, HmacSha256::mac(b"first-key", b"payload").unwrap());
+        assert_ne!(first, second);
+    }
+}
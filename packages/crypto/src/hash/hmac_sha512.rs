@@ -0,0 +1,15 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+use crate::errors::{Error, Result};
+
+pub struct HmacSha512;
+
+impl HmacSha512 {
+    pub fn mac(key: &[u8], data: &[u8]) -> Result<[u8; 64]> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).map_err(|_| Error::InvalidKeyLength)?;
+        mac.update(data);
+
+        Ok(mac.finalize().into_bytes().into())
+    }
+}
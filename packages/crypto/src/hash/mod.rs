@@ -1,7 +1,15 @@
 pub mod crc32;
+pub mod hmac_sha1;
+pub mod hmac_sha256;
+pub mod hmac_sha512;
+pub mod sha1;
 pub mod sha256;
 
 pub use crc32::Crc32Hasher;
+pub use hmac_sha1::HmacSha1;
+pub use hmac_sha256::HmacSha256;
+pub use hmac_sha512::HmacSha512;
+pub use sha1::Sha1Hasher;
 pub use sha256::Sha256Hasher;
 
 pub trait Hasher {
@@ -0,0 +1,10 @@
+use sha1::{Digest, Sha1};
+
+// Not for integrity: breach corpora such as Pwned Passwords are indexed by SHA-1.
+pub struct Sha1Hasher;
+
+impl Sha1Hasher {
+    pub fn hash(data: &[u8]) -> [u8; 20] {
+        Sha1::digest(data).into()
+    }
+}
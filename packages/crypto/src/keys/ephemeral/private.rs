@@ -24,6 +24,11 @@ impl EphemeralPrivateKey {
         self.key.as_ref()
     }
 
+    pub fn public_key(&self) -> EphemeralPublicKey {
+        let secret = StaticSecret::from(*self.as_bytes());
+        EphemeralPublicKey(X25519PublicKey::from(&secret).to_bytes())
+    }
+
     pub fn shared_secret(&self, peer: &EphemeralPublicKey) -> EphemeralPrivateKeyType {
         let secret = StaticSecret::from(*self.as_bytes());
         let peer_public = X25519PublicKey::from(peer.0);
@@ -0,0 +1,165 @@
+use std::time::{Duration, Instant};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use crate::errors::{Error, Result};
+
+pub const KDF_PARAMS_SIZE: usize = 12;
+
+const CALIBRATION_PASSWORD: &[u8] = b"openvault/kdf/calibration";
+const CALIBRATION_SALT: &[u8] = b"openvault/kdf/salt";
+const MAX_MEMORY_KIB: u32 = 1024 * 1024;
+const MAX_ITERATIONS: u32 = 64;
+const MAX_PARALLELISM: u32 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    pub const LEGACY: Self = Self {
+        memory_kib: Params::DEFAULT_M_COST,
+        iterations: Params::DEFAULT_T_COST,
+        parallelism: Params::DEFAULT_P_COST,
+    };
+
+    // Ceiling for parameters read from untrusted input, calibration never goes past it either.
+    pub const MAX: Self = Self {
+        memory_kib: MAX_MEMORY_KIB,
+        iterations: MAX_ITERATIONS,
+        parallelism: MAX_PARALLELISM,
+    };
+
+    pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<Self> {
+        let params = Self {
+            memory_kib,
+            iterations,
+            parallelism,
+        };
+        params.to_argon2()?;
+
+        Ok(params)
+    }
+
+    pub fn calibrate(target: Duration) -> Result<Self> {
+        let mut params = Self::default();
+        let mut elapsed = params.measure()?;
+
+        while elapsed < target && params.memory_kib < MAX_MEMORY_KIB {
+            params.memory_kib = params.memory_kib.saturating_mul(2).min(MAX_MEMORY_KIB);
+            elapsed = params.measure()?;
+        }
+
+        if elapsed < target {
+            let scale = target.as_secs_f64() / elapsed.as_secs_f64().max(f64::EPSILON);
+            params.iterations =
+                ((params.iterations as f64 * scale).ceil() as u32).min(MAX_ITERATIONS);
+        }
+
+        Ok(params)
+    }
+
+    // Every cost must hold, otherwise memory could be traded away for iterations.
+    pub fn is_at_least(&self, other: &Self) -> bool {
+        self.memory_kib >= other.memory_kib
+            && self.iterations >= other.iterations
+            && self.parallelism >= other.parallelism
+    }
+
+    pub fn to_argon2(&self) -> Result<Argon2<'static>> {
+        let params = Params::new(self.memory_kib, self.iterations, self.parallelism, None)
+            .map_err(|_| Error::InvalidKdfParams)?;
+
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+
+    pub fn to_bytes(&self) -> [u8; KDF_PARAMS_SIZE] {
+        let mut bytes = [0u8; KDF_PARAMS_SIZE];
+
+        bytes[0..4].copy_from_slice(&self.memory_kib.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.iterations.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.parallelism.to_le_bytes());
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: [u8; KDF_PARAMS_SIZE]) -> Result<Self> {
+        let read_u32 = |start: usize| {
+            let mut word = [0u8; 4];
+            word.copy_from_slice(&bytes[start..start + 4]);
+            u32::from_le_bytes(word)
+        };
+
+        Self::new(read_u32(0), read_u32(4), read_u32(8))
+    }
+
+    fn measure(&self) -> Result<Duration> {
+        let argon2 = self.to_argon2()?;
+        let mut output = [0u8; 32];
+
+        let started = Instant::now();
+        argon2
+            .hash_password_into(CALIBRATION_PASSWORD, CALIBRATION_SALT, &mut output)
+            .map_err(|_| Error::KeyDerivationFailed)?;
+
+        Ok(started.elapsed())
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self::LEGACY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kdf_params_roundtrip() {
+        let params = KdfParams::new(64 * 1024, 3, 2).unwrap();
+        let restored = KdfParams::from_bytes(params.to_bytes()).unwrap();
+        assert_eq!(params, restored);
+    }
+
+    #[test]
+    fn test_kdf_params_rejects_invalid() {
+        assert!(matches!(
+            KdfParams::new(64 * 1024, 0, 1),
+            Err(Error::InvalidKdfParams)
+        ));
+        assert!(matches!(
+            KdfParams::from_bytes([0u8; KDF_PARAMS_SIZE]),
+            Err(Error::InvalidKdfParams)
+        ));
+    }
+
+    #[test]
+    fn test_kdf_params_max_bounds_every_cost() {
+        assert!(KdfParams::MAX.is_at_least(&KdfParams::LEGACY));
+        assert!(
+            !KdfParams::MAX.is_at_least(&KdfParams::new(64 * 1024, MAX_ITERATIONS + 1, 1).unwrap())
+        );
+        assert!(!KdfParams::MAX.is_at_least(&KdfParams::new(MAX_MEMORY_KIB * 2, 1, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_kdf_params_at_least() {
+        let stronger = KdfParams::new(64 * 1024, 3, 1).unwrap();
+        assert!(stronger.is_at_least(&KdfParams::LEGACY));
+        assert!(!KdfParams::LEGACY.is_at_least(&stronger));
+        assert!(KdfParams::LEGACY.is_at_least(&KdfParams::LEGACY));
+
+        let fewer_lanes = KdfParams::new(64 * 1024, 3, 1).unwrap();
+        let more_lanes = KdfParams::new(64 * 1024, 3, 2).unwrap();
+        assert!(!fewer_lanes.is_at_least(&more_lanes));
+
+        let less_memory = KdfParams::new(8 * 1024, 10, 1).unwrap();
+        assert!(!less_memory.is_at_least(&stronger));
+        assert!(!stronger.is_at_least(&less_memory));
+    }
+}
@@ -1,10 +1,11 @@
-use argon2::{Algorithm, Argon2, Params, Version};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
 use hkdf::Hkdf;
 use sha2::Sha256;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate::errors::{Error, Result};
 use crate::keys::derived_key::DerivedKey;
+use crate::keys::kdf_params::KdfParams;
 use crate::keys::salt::Salt;
 use crate::memory::SecretSlice;
 
@@ -21,9 +22,22 @@ impl MasterKey {
         Ok(Self { key })
     }
 
+    pub fn generate() -> Result<Self> {
+        let mut raw = [0u8; MKEY_SIZE];
+        OsRng.fill_bytes(&mut raw);
+
+        let master = Self::new(raw)?;
+        raw.zeroize();
+
+        Ok(master)
+    }
+
     pub fn derive(password: &[u8], salt: &Salt) -> Result<Self> {
-        let params = Params::default();
-        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+        Self::derive_with_params(password, salt, &KdfParams::default())
+    }
+
+    pub fn derive_with_params(password: &[u8], salt: &Salt, params: &KdfParams) -> Result<Self> {
+        let argon2 = params.to_argon2()?;
 
         let mut raw = [0u8; MKEY_SIZE];
         argon2
@@ -72,4 +86,11 @@ mod tests {
         let key = MasterKey::new([0u8; MKEY_SIZE]).unwrap();
         assert_eq!(key.as_bytes(), &[0u8; MKEY_SIZE]);
     }
+
+    #[test]
+    fn test_master_key_generate_is_random() {
+        let first = MasterKey::generate().unwrap();
+        let second = MasterKey::generate().unwrap();
+        assert_ne!(first.as_bytes(), second.as_bytes());
+    }
 }
@@ -1,6 +1,8 @@
 pub mod derived_key;
 pub mod ephemeral;
+pub mod kdf_params;
 pub mod master_key;
+pub mod recovery_key;
 pub mod salt;
 pub mod signing;
 
@@ -0,0 +1,93 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::errors::{Error, Result};
+
+pub const RECOVERY_KEY_SIZE: usize = 20;
+
+const GROUP_SIZE: usize = 4;
+const ENCODED_SIZE: usize = RECOVERY_KEY_SIZE * 2;
+
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct RecoveryKey(String);
+
+impl RecoveryKey {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; RECOVERY_KEY_SIZE];
+        OsRng.fill_bytes(&mut bytes);
+
+        let mut encoded: String = bytes.iter().map(|b| format!("{b:02X}")).collect();
+        bytes.zeroize();
+
+        let key = Self::from_normalized(&encoded);
+        encoded.zeroize();
+
+        key
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut normalized: String = input
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let is_valid =
+            normalized.len() == ENCODED_SIZE && normalized.chars().all(|c| c.is_ascii_hexdigit());
+
+        let key = is_valid
+            .then(|| Self::from_normalized(&normalized))
+            .ok_or(Error::InvalidRecoveryKey);
+        normalized.zeroize();
+
+        key
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn secret_bytes(&self) -> Vec<u8> {
+        self.0.bytes().filter(|b| *b != b'-').collect()
+    }
+
+    fn from_normalized(normalized: &str) -> Self {
+        let groups: Vec<&str> = normalized
+            .as_bytes()
+            .chunks(GROUP_SIZE)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+            .collect();
+
+        Self(groups.join("-"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery_key_generate_format() {
+        let key = RecoveryKey::generate();
+        assert_eq!(
+            key.as_str().len(),
+            ENCODED_SIZE + ENCODED_SIZE / GROUP_SIZE - 1
+        );
+        assert_eq!(key.secret_bytes().len(), ENCODED_SIZE);
+    }
+
+    #[test]
+    fn test_recovery_key_parse_normalizes() {
+        let key = RecoveryKey::generate();
+        let relaxed = key.as_str().to_lowercase().replace('-', " ");
+
+        let parsed = RecoveryKey::parse(&relaxed).unwrap();
+        assert_eq!(parsed.as_str(), key.as_str());
+    }
+
+    #[test]
+    fn test_recovery_key_parse_rejects_invalid() {
+        assert!(RecoveryKey::parse("not-a-key").is_err());
+        assert!(RecoveryKey::parse(&"Z".repeat(ENCODED_SIZE)).is_err());
+    }
+}
@@ -77,6 +77,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_salt_from_salt() {
         let salt = Salt::new([0u8; SALT_SIZE]);
         let salt2 = Salt::from(salt);
@@ -1,5 +1,6 @@
 pub mod compression;
 pub mod encryption;
+pub mod generator;
 pub mod hash;
 pub mod keys;
 pub mod memory;
@@ -0,0 +1,15 @@
+use base64::Engine;
+use base64::engine::general_purpose;
+
+use crate::errors::{Error, Result};
+
+/// Text encoding for binary envelopes, for transports that only carry strings.
+pub fn armor(envelope: &[u8]) -> String {
+    general_purpose::STANDARD.encode(envelope)
+}
+
+pub fn dearmor(armored: &[u8]) -> Result<Vec<u8>> {
+    general_purpose::STANDARD
+        .decode(armored.trim_ascii())
+        .map_err(|_| Error::DecodeBase64)
+}
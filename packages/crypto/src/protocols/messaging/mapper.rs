@@ -1,8 +1,6 @@
-use base64::Engine;
-use base64::engine::general_purpose;
-
 use crate::errors::{Error, Result};
-use crate::protocols::messaging::MessageEnvelope;
+use crate::protocols::messaging::signed::SenderFingerprint;
+use crate::protocols::messaging::{MessageEnvelope, MultiRecipientEnvelope};
 
 const SIGNATURE_LEN_BYTES: usize = 2;
 
@@ -22,6 +20,15 @@ pub fn encode_payload(signature: &[u8], message: &[u8]) -> Result<Vec<u8>> {
     Ok(output)
 }
 
+pub fn encode_signed_payload(
+    sender: &SenderFingerprint,
+    signature: &[u8],
+    message: &[u8],
+) -> Result<Vec<u8>> {
+    let payload = encode_payload(signature, message)?;
+    Ok([sender.as_slice(), &payload].concat())
+}
+
 pub fn decode_payload(payload: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
     if payload.len() < SIGNATURE_LEN_BYTES {
         return Err(Error::InvalidEnvelope);
@@ -41,15 +48,18 @@ pub fn decode_payload(payload: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
     Ok((signature, message))
 }
 
-pub fn encode_message(message: &MessageEnvelope) -> Result<String> {
-    let bytes = postcard::to_allocvec(message).map_err(|_| Error::InvalidMessageFormat)?;
-    Ok(general_purpose::STANDARD.encode(bytes))
+pub fn encode_message(message: &MessageEnvelope) -> Result<Vec<u8>> {
+    postcard::to_allocvec(message).map_err(|_| Error::InvalidMessageFormat)
 }
 
 pub fn decode_message(bytes: &[u8]) -> Result<MessageEnvelope> {
-    let decoded = general_purpose::STANDARD
-        .decode(bytes)
-        .map_err(|_| Error::DecodeBase64)?;
+    postcard::from_bytes(bytes).map_err(|_| Error::InvalidMessageFormat)
+}
+
+pub fn encode_multi_message(message: &MultiRecipientEnvelope) -> Result<Vec<u8>> {
+    postcard::to_allocvec(message).map_err(|_| Error::InvalidMessageFormat)
+}
 
-    postcard::from_bytes(&decoded).map_err(|_| Error::InvalidMessageFormat)
+pub fn decode_multi_message(bytes: &[u8]) -> Result<MultiRecipientEnvelope> {
+    postcard::from_bytes(bytes).map_err(|_| Error::InvalidMessageFormat)
 }
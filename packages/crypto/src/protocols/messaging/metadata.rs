@@ -3,9 +3,16 @@ use serde::{Deserialize, Serialize};
 use crate::compression::CompressionAlgorithm;
 use crate::encryption::EncryptionAlgorithm;
 use crate::errors::{Error, Result};
+use crate::hash::{Hasher, Sha256Hasher};
+use crate::keys::EphemeralPublicKey;
 use crate::signature::SignatureAlgorithm;
 
-pub const ENVELOPE_VERSION: u8 = 1;
+pub const LEGACY_ENVELOPE_VERSION: u8 = 1;
+pub const LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION: u8 = 2;
+pub const ENVELOPE_VERSION: u8 = 3;
+pub const MULTI_RECIPIENT_ENVELOPE_VERSION: u8 = 4;
+pub const KEY_ID_SIZE: usize = 8;
+pub const HEADER_AAD_SIZE: usize = 38;
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -21,6 +28,28 @@ pub enum KdfAlgorithm {
     HkdfSha256 = 1,
 }
 
+impl TryFrom<u8> for HashAlgorithm {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::Sha256),
+            _ => Err(Error::InvalidEnvelope),
+        }
+    }
+}
+
+impl TryFrom<u8> for KdfAlgorithm {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::HkdfSha256),
+            _ => Err(Error::InvalidEnvelope),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MessageHeader {
     pub version: u8,
@@ -38,6 +67,21 @@ pub struct MessageEnvelope {
     pub ciphertext: Vec<u8>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecipientWrap {
+    pub key_id: [u8; KEY_ID_SIZE],
+    pub wrapped_key: Vec<u8>,
+}
+
+/// Payload encrypted once under a random content key, with that key wrapped for
+/// every recipient. `header.ephemeral_public_key` is shared by all wraps.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiRecipientEnvelope {
+    pub header: MessageHeader,
+    pub recipients: Vec<RecipientWrap>,
+    pub ciphertext: Vec<u8>,
+}
+
 #[derive(Default)]
 pub struct MessageConfig {
     pub hash: HashAlgorithm,
@@ -61,11 +105,62 @@ impl MessageHeader {
         aad
     }
 
+    pub fn from_aad_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != HEADER_AAD_SIZE {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        let mut ephemeral_public_key = [0u8; 32];
+        ephemeral_public_key.copy_from_slice(&bytes[6..]);
+
+        Ok(Self {
+            version: bytes[0],
+            hash: HashAlgorithm::try_from(bytes[1])?,
+            kdf: KdfAlgorithm::try_from(bytes[2])?,
+            signature: SignatureAlgorithm::try_from(bytes[3])?,
+            encryption: EncryptionAlgorithm::try_from(bytes[4])?,
+            compression: CompressionAlgorithm::try_from(bytes[5])?,
+            ephemeral_public_key,
+        })
+    }
+
     pub fn ensure_supported(&self) -> Result<()> {
-        if self.version != ENVELOPE_VERSION {
+        if self.version == LEGACY_ENVELOPE_VERSION {
+            return Ok(());
+        }
+
+        self.ensure_version(ENVELOPE_VERSION)
+    }
+
+    pub fn ensure_multi_recipient_supported(&self) -> Result<()> {
+        if self.version == LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION {
+            return Ok(());
+        }
+
+        self.ensure_version(MULTI_RECIPIENT_ENVELOPE_VERSION)
+    }
+
+    /// Envelopes from before versions 3 and 4 carry no sender fingerprint in their payload.
+    pub fn has_sender_fingerprint(&self) -> bool {
+        !matches!(
+            self.version,
+            LEGACY_ENVELOPE_VERSION | LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION
+        )
+    }
+
+    pub fn ensure_version(&self, expected: u8) -> Result<()> {
+        if self.version != expected {
             return Err(Error::UnsupportedEnvelopeVersion(self.version));
         }
 
         Ok(())
     }
 }
+
+pub fn recipient_key_id(public_key: &EphemeralPublicKey) -> [u8; KEY_ID_SIZE] {
+    let hash = Sha256Hasher::hash(public_key.as_bytes());
+
+    let mut key_id = [0u8; KEY_ID_SIZE];
+    key_id.copy_from_slice(&hash[..KEY_ID_SIZE]);
+    key_id
+}
@@ -1,11 +1,32 @@
 mod operations;
 
+pub mod armor;
 pub(crate) mod kdf;
 pub(crate) mod mapper;
 pub mod metadata;
+pub mod signed;
+pub(crate) mod stream;
 
+pub use armor::{armor, dearmor};
 pub use metadata::{
-    ENVELOPE_VERSION, HashAlgorithm, KdfAlgorithm, MessageConfig, MessageEnvelope, MessageHeader,
+    ENVELOPE_VERSION, HashAlgorithm, KEY_ID_SIZE, KdfAlgorithm, LEGACY_ENVELOPE_VERSION,
+    LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION, MULTI_RECIPIENT_ENVELOPE_VERSION, MessageConfig,
+    MessageEnvelope, MessageHeader, MultiRecipientEnvelope, RecipientWrap, recipient_key_id,
 };
-pub use operations::sign_then_encrypt::{sign_then_encrypt, sign_then_encrypt_with};
-pub use operations::verify_then_decrypt::verify_then_decrypt;
+pub use operations::sign_then_encrypt::{
+    sign_then_encrypt, sign_then_encrypt_bytes, sign_then_encrypt_bytes_with,
+    sign_then_encrypt_with,
+};
+pub use operations::sign_then_encrypt_multi::{
+    sign_then_encrypt_multi, sign_then_encrypt_multi_with,
+};
+pub use operations::sign_then_encrypt_stream::{
+    sign_then_encrypt_stream, sign_then_encrypt_stream_with,
+};
+pub use operations::verify_then_decrypt::{
+    open_signed_message, verify_then_decrypt, verify_then_decrypt_bytes,
+};
+pub use operations::verify_then_decrypt_multi::verify_then_decrypt_multi;
+pub use operations::verify_then_decrypt_stream::{MessageStreamReader, verify_then_decrypt_stream};
+pub use signed::{SENDER_FINGERPRINT_SIZE, SenderFingerprint, SignedMessage, sender_fingerprint};
+pub use stream::{STREAM_CHUNK_SIZE, STREAM_MAGIC};
@@ -1,2 +1,6 @@
 pub mod sign_then_encrypt;
+pub mod sign_then_encrypt_multi;
+pub mod sign_then_encrypt_stream;
 pub mod verify_then_decrypt;
+pub mod verify_then_decrypt_multi;
+pub mod verify_then_decrypt_stream;
@@ -2,11 +2,13 @@ use crate::errors::Result;
 use crate::hash::{Hasher, Sha256Hasher};
 use crate::keys::ephemeral::{EphemeralKeyPair, EphemeralPublicKey};
 use crate::keys::signing::SigningKeyPair;
+use crate::protocols::messaging::armor::armor;
 use crate::protocols::messaging::kdf::derive_encryption_key;
-use crate::protocols::messaging::mapper::{encode_message, encode_payload};
+use crate::protocols::messaging::mapper::{encode_message, encode_signed_payload};
 use crate::protocols::messaging::metadata::{
     ENVELOPE_VERSION, MessageConfig, MessageEnvelope, MessageHeader,
 };
+use crate::protocols::messaging::signed::sender_fingerprint;
 
 pub fn sign_then_encrypt(
     payload: &[u8],
@@ -27,12 +29,36 @@ pub fn sign_then_encrypt_with(
     recipient_pub: &EphemeralPublicKey,
     config: &MessageConfig,
 ) -> Result<String> {
+    let envelope = sign_then_encrypt_bytes_with(payload, sender_signing, recipient_pub, config)?;
+    Ok(armor(&envelope))
+}
+
+pub fn sign_then_encrypt_bytes(
+    payload: &[u8],
+    sender_signing: &SigningKeyPair,
+    recipient_pub: &EphemeralPublicKey,
+) -> Result<Vec<u8>> {
+    sign_then_encrypt_bytes_with(
+        payload,
+        sender_signing,
+        recipient_pub,
+        &MessageConfig::default(),
+    )
+}
+
+pub fn sign_then_encrypt_bytes_with(
+    payload: &[u8],
+    sender_signing: &SigningKeyPair,
+    recipient_pub: &EphemeralPublicKey,
+    config: &MessageConfig,
+) -> Result<Vec<u8>> {
     let payload_hash = Sha256Hasher::hash(payload);
 
     let singer = config.signature.resolve();
     let signature = singer.sign(sender_signing.private.as_bytes(), &payload_hash);
 
-    let payload = encode_payload(&signature, payload)?;
+    let sender = sender_fingerprint(&sender_signing.public);
+    let payload = encode_signed_payload(&sender, &signature, payload)?;
     let compressor = config.compression.resolve();
     let compressed = compressor.compress(&payload)?;
 
@@ -0,0 +1,95 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::errors::{Error, Result};
+use crate::hash::{Hasher, Sha256Hasher};
+use crate::keys::ephemeral::{EphemeralKeyPair, EphemeralPublicKey};
+use crate::keys::signing::SigningKeyPair;
+use crate::protocols::messaging::kdf::derive_wrapping_key;
+use crate::protocols::messaging::mapper::{encode_multi_message, encode_signed_payload};
+use crate::protocols::messaging::metadata::{
+    MULTI_RECIPIENT_ENVELOPE_VERSION, MessageConfig, MessageHeader, MultiRecipientEnvelope,
+    RecipientWrap, recipient_key_id,
+};
+use crate::protocols::messaging::signed::sender_fingerprint;
+
+pub fn sign_then_encrypt_multi(
+    payload: &[u8],
+    sender_signing: &SigningKeyPair,
+    recipients: &[EphemeralPublicKey],
+) -> Result<Vec<u8>> {
+    sign_then_encrypt_multi_with(
+        payload,
+        sender_signing,
+        recipients,
+        &MessageConfig::default(),
+    )
+}
+
+pub fn sign_then_encrypt_multi_with(
+    payload: &[u8],
+    sender_signing: &SigningKeyPair,
+    recipients: &[EphemeralPublicKey],
+    config: &MessageConfig,
+) -> Result<Vec<u8>> {
+    if recipients.is_empty() {
+        return Err(Error::RecipientNotFound);
+    }
+
+    let payload_hash = Sha256Hasher::hash(payload);
+
+    let signer = config.signature.resolve();
+    let signature = signer.sign(sender_signing.private.as_bytes(), &payload_hash);
+
+    let sender = sender_fingerprint(&sender_signing.public);
+    let payload = encode_signed_payload(&sender, &signature, payload)?;
+    let compressor = config.compression.resolve();
+    let compressed = compressor.compress(&payload)?;
+
+    let ephemeral = EphemeralKeyPair::generate()?;
+
+    let header = MessageHeader {
+        version: MULTI_RECIPIENT_ENVELOPE_VERSION,
+        hash: config.hash,
+        signature: config.signature,
+        kdf: config.kdf,
+        encryption: config.encryption,
+        compression: config.compression,
+        ephemeral_public_key: ephemeral.public.to_bytes(),
+    };
+
+    let aad = header.aad_bytes();
+    let cipher = config.encryption.resolve();
+
+    let mut content_key = Zeroizing::new([0u8; 32]);
+    OsRng.fill_bytes(content_key.as_mut_slice());
+    let ciphertext = cipher.encrypt_prefixed_nonce(content_key.as_slice(), &compressed, &aad)?;
+
+    let mut wraps: Vec<RecipientWrap> = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let key_id = recipient_key_id(recipient);
+        if wraps.iter().any(|wrap| wrap.key_id == key_id) {
+            continue;
+        }
+
+        let wrap_aad = [aad.as_slice(), &key_id].concat();
+        let shared_secret = ephemeral.private.shared_secret(recipient);
+        let wrapping_key = Zeroizing::new(derive_wrapping_key(&shared_secret, &wrap_aad)?);
+        let wrapped_key = cipher.encrypt_prefixed_nonce(
+            wrapping_key.as_slice(),
+            content_key.as_slice(),
+            &wrap_aad,
+        )?;
+
+        wraps.push(RecipientWrap {
+            key_id,
+            wrapped_key,
+        });
+    }
+
+    encode_multi_message(&MultiRecipientEnvelope {
+        header,
+        recipients: wraps,
+        ciphertext,
+    })
+}
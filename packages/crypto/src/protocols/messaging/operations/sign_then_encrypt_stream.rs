@@ -0,0 +1,96 @@
+use std::io::{Read, Write};
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::errors::Result;
+use crate::keys::ephemeral::{EphemeralKeyPair, EphemeralPublicKey};
+use crate::keys::signing::SigningKeyPair;
+use crate::protocols::messaging::kdf::derive_encryption_key;
+use crate::protocols::messaging::metadata::{MessageConfig, MessageHeader};
+use crate::protocols::messaging::stream::{
+    FrameKind, STREAM_CHUNK_SIZE, STREAM_MAGIC, STREAM_VERSION, frame_aad, read_chunk, write_frame,
+};
+
+pub fn sign_then_encrypt_stream(
+    source: &mut dyn Read,
+    destination: &mut dyn Write,
+    sender_signing: &SigningKeyPair,
+    recipient_pub: &EphemeralPublicKey,
+) -> Result<u64> {
+    sign_then_encrypt_stream_with(
+        source,
+        destination,
+        sender_signing,
+        recipient_pub,
+        &MessageConfig::default(),
+    )
+}
+
+/// Encrypts `source` in chunks for `recipient_pub`, followed by a signature over the
+/// SHA-256 of the whole plaintext. Returns the number of plaintext bytes written.
+pub fn sign_then_encrypt_stream_with(
+    source: &mut dyn Read,
+    destination: &mut dyn Write,
+    sender_signing: &SigningKeyPair,
+    recipient_pub: &EphemeralPublicKey,
+    config: &MessageConfig,
+) -> Result<u64> {
+    let ephemeral = EphemeralKeyPair::generate()?;
+    let shared_secret = ephemeral.private.shared_secret(recipient_pub);
+
+    let header = MessageHeader {
+        version: STREAM_VERSION,
+        hash: config.hash,
+        signature: config.signature,
+        kdf: config.kdf,
+        encryption: config.encryption,
+        compression: config.compression,
+        ephemeral_public_key: ephemeral.public.to_bytes(),
+    };
+
+    let aad = header.aad_bytes();
+    let key = Zeroizing::new(derive_encryption_key(&shared_secret, &aad)?);
+    let cipher = config.encryption.resolve();
+    let compressor = config.compression.resolve();
+
+    destination.write_all(STREAM_MAGIC)?;
+    destination.write_all(&aad)?;
+
+    let mut hasher = Sha256::new();
+    let mut chunk = Zeroizing::new(vec![0u8; STREAM_CHUNK_SIZE]);
+    let mut index = 0u64;
+    let mut total = 0u64;
+
+    loop {
+        let read = read_chunk(source, &mut chunk)?;
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&chunk[..read]);
+        let compressed = compressor.compress(&chunk[..read])?;
+        let frame = cipher.encrypt_prefixed_nonce(
+            key.as_slice(),
+            &compressed,
+            &frame_aad(&aad, index, FrameKind::Data),
+        )?;
+        write_frame(destination, FrameKind::Data, &frame)?;
+
+        index += 1;
+        total += read as u64;
+    }
+
+    let payload_hash: [u8; 32] = hasher.finalize().into();
+    let signer = config.signature.resolve();
+    let signature = signer.sign(sender_signing.private.as_bytes(), &payload_hash);
+
+    let frame = cipher.encrypt_prefixed_nonce(
+        key.as_slice(),
+        &signature,
+        &frame_aad(&aad, index, FrameKind::Signature),
+    )?;
+    write_frame(destination, FrameKind::Signature, &frame)?;
+
+    Ok(total)
+}
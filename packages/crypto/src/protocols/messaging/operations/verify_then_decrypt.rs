@@ -1,16 +1,48 @@
 use crate::errors::{Error, Result};
-use crate::hash::{Hasher, Sha256Hasher};
 use crate::keys::ephemeral::{EphemeralPrivateKey, EphemeralPublicKey};
 use crate::keys::signing::SigningPublicKey;
+use crate::protocols::messaging::armor::dearmor;
 use crate::protocols::messaging::kdf::derive_encryption_key;
-use crate::protocols::messaging::mapper::{decode_message, decode_payload};
-use crate::protocols::messaging::metadata::{HashAlgorithm, KdfAlgorithm};
+use crate::protocols::messaging::mapper::decode_message;
+use crate::protocols::messaging::metadata::{
+    HashAlgorithm, KdfAlgorithm, LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION,
+    MULTI_RECIPIENT_ENVELOPE_VERSION,
+};
+use crate::protocols::messaging::operations::verify_then_decrypt_multi::open_multi_message;
+use crate::protocols::messaging::signed::SignedMessage;
 
 pub fn verify_then_decrypt(
     ciphertext: &[u8],
     recipient_private: &EphemeralPrivateKey,
     sender_public: &SigningPublicKey,
 ) -> Result<String> {
+    let envelope = dearmor(ciphertext)?;
+    let message = verify_then_decrypt_bytes(&envelope, recipient_private, sender_public)?;
+
+    String::from_utf8(message).map_err(|_| Error::InvalidMessageFormat)
+}
+
+pub fn verify_then_decrypt_bytes(
+    ciphertext: &[u8],
+    recipient_private: &EphemeralPrivateKey,
+    sender_public: &SigningPublicKey,
+) -> Result<Vec<u8>> {
+    open_signed_message(ciphertext, recipient_private)?.verify(sender_public)
+}
+
+/// Decrypts either envelope kind without checking the signature, so the sender can be
+/// looked up by fingerprint first.
+pub fn open_signed_message(
+    ciphertext: &[u8],
+    recipient_private: &EphemeralPrivateKey,
+) -> Result<SignedMessage> {
+    if matches!(
+        ciphertext.first(),
+        Some(&(MULTI_RECIPIENT_ENVELOPE_VERSION | LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION))
+    ) {
+        return open_multi_message(ciphertext, recipient_private);
+    }
+
     let envelope = decode_message(ciphertext)?;
 
     envelope.header.ensure_supported()?;
@@ -34,17 +66,5 @@ pub fn verify_then_decrypt(
     let compressor = envelope.header.compression.resolve();
     let payload = compressor.decompress(&compressed)?;
 
-    let (signature, message) = decode_payload(&payload)?;
-    if signature.len() != 64 {
-        return Err(Error::InvalidEnvelope);
-    }
-
-    let message_hash = Sha256Hasher::hash(&message);
-    let signer = envelope.header.signature.resolve();
-
-    if !signer.verify(sender_public.as_bytes(), &message_hash, &signature) {
-        return Err(Error::SignatureVerificationFailed);
-    }
-
-    String::from_utf8(message).map_err(|_| Error::InvalidMessageFormat)
+    SignedMessage::decode(&envelope.header, &payload)
 }
@@ -0,0 +1,63 @@
+use zeroize::Zeroizing;
+
+use crate::errors::{Error, Result};
+use crate::keys::ephemeral::{EphemeralPrivateKey, EphemeralPublicKey};
+use crate::keys::signing::SigningPublicKey;
+use crate::protocols::messaging::kdf::derive_wrapping_key;
+use crate::protocols::messaging::mapper::decode_multi_message;
+use crate::protocols::messaging::metadata::{HashAlgorithm, KdfAlgorithm, recipient_key_id};
+use crate::protocols::messaging::signed::SignedMessage;
+
+pub fn verify_then_decrypt_multi(
+    ciphertext: &[u8],
+    recipient_private: &EphemeralPrivateKey,
+    sender_public: &SigningPublicKey,
+) -> Result<Vec<u8>> {
+    open_multi_message(ciphertext, recipient_private)?.verify(sender_public)
+}
+
+pub fn open_multi_message(
+    ciphertext: &[u8],
+    recipient_private: &EphemeralPrivateKey,
+) -> Result<SignedMessage> {
+    let envelope = decode_multi_message(ciphertext)?;
+
+    envelope.header.ensure_multi_recipient_supported()?;
+
+    if envelope.header.hash != HashAlgorithm::Sha256 {
+        return Err(Error::InvalidEnvelope);
+    }
+
+    if envelope.header.kdf != KdfAlgorithm::HkdfSha256 {
+        return Err(Error::InvalidEnvelope);
+    }
+
+    let aad = envelope.header.aad_bytes();
+    let cipher = envelope.header.encryption.resolve();
+
+    let key_id = recipient_key_id(&recipient_private.public_key());
+    let wrap_aad = [aad.as_slice(), &key_id].concat();
+
+    let peer_public = EphemeralPublicKey::from_bytes(envelope.header.ephemeral_public_key);
+    let shared_secret = recipient_private.shared_secret(&peer_public);
+    let wrapping_key = Zeroizing::new(derive_wrapping_key(&shared_secret, &wrap_aad)?);
+
+    // Key ids are short, so a colliding entry just fails to unwrap and the next one is tried.
+    let content_key = envelope
+        .recipients
+        .iter()
+        .filter(|wrap| wrap.key_id == key_id)
+        .find_map(|wrap| {
+            cipher
+                .decrypt_prefixed_nonce(wrapping_key.as_slice(), &wrap.wrapped_key, &wrap_aad)
+                .ok()
+        })
+        .map(Zeroizing::new)
+        .ok_or(Error::RecipientNotFound)?;
+
+    let compressed = cipher.decrypt_prefixed_nonce(&content_key, &envelope.ciphertext, &aad)?;
+    let compressor = envelope.header.compression.resolve();
+    let payload = compressor.decompress(&compressed)?;
+
+    SignedMessage::decode(&envelope.header, &payload)
+}
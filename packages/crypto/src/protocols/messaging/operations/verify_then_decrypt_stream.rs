@@ -0,0 +1,159 @@
+use std::io::{self, Read, Write};
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::errors::{Error, Result};
+use crate::keys::ephemeral::{EphemeralPrivateKey, EphemeralPublicKey};
+use crate::keys::signing::SigningPublicKey;
+use crate::protocols::messaging::kdf::derive_encryption_key;
+use crate::protocols::messaging::metadata::{
+    HEADER_AAD_SIZE, HashAlgorithm, KdfAlgorithm, MessageHeader,
+};
+use crate::protocols::messaging::stream::{
+    FrameKind, STREAM_CHUNK_SIZE, STREAM_MAGIC, STREAM_VERSION, frame_aad, into_error, read_exact,
+    read_frame,
+};
+
+/// Decrypts a stream produced by `sign_then_encrypt_stream` into `destination`.
+///
+/// Plaintext is written before the trailing signature is checked, callers must discard
+/// the output when this returns an error.
+pub fn verify_then_decrypt_stream(
+    source: &mut dyn Read,
+    destination: &mut dyn Write,
+    recipient_private: &EphemeralPrivateKey,
+    sender_public: &SigningPublicKey,
+) -> Result<u64> {
+    let mut reader = MessageStreamReader::open(source, recipient_private)?;
+    io::copy(&mut reader, destination).map_err(into_error)?;
+    reader.finish(sender_public)
+}
+
+/// Yields the decrypted plaintext of a message stream. Nothing read from it is
+/// authenticated as coming from the sender until `finish` succeeds.
+pub struct MessageStreamReader<'a> {
+    source: &'a mut dyn Read,
+    header: MessageHeader,
+    aad: Vec<u8>,
+    key: Zeroizing<[u8; 32]>,
+    hasher: Sha256,
+    index: u64,
+    buffer: Zeroizing<Vec<u8>>,
+    position: usize,
+    signature: Option<Vec<u8>>,
+    total: u64,
+}
+
+impl<'a> MessageStreamReader<'a> {
+    pub fn open(source: &'a mut dyn Read, recipient_private: &EphemeralPrivateKey) -> Result<Self> {
+        let mut magic = [0u8; STREAM_MAGIC.len()];
+        read_exact(source, &mut magic)?;
+        if &magic != STREAM_MAGIC {
+            return Err(Error::InvalidMessageFormat);
+        }
+
+        let mut aad = vec![0u8; HEADER_AAD_SIZE];
+        read_exact(source, &mut aad)?;
+        let header = MessageHeader::from_aad_bytes(&aad)?;
+
+        header.ensure_version(STREAM_VERSION)?;
+
+        if header.hash != HashAlgorithm::Sha256 {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        if header.kdf != KdfAlgorithm::HkdfSha256 {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        let peer_public = EphemeralPublicKey::from_bytes(header.ephemeral_public_key);
+        let shared_secret = recipient_private.shared_secret(&peer_public);
+        let key = Zeroizing::new(derive_encryption_key(&shared_secret, &aad)?);
+
+        Ok(Self {
+            source,
+            header,
+            aad,
+            key,
+            hasher: Sha256::new(),
+            index: 0,
+            buffer: Zeroizing::new(Vec::new()),
+            position: 0,
+            signature: None,
+            total: 0,
+        })
+    }
+
+    pub fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    /// Drains any unread plaintext, rejects trailing bytes and checks the sender's
+    /// signature. Returns the total plaintext size.
+    pub fn finish(mut self, sender_public: &SigningPublicKey) -> Result<u64> {
+        io::copy(&mut self, &mut io::sink()).map_err(into_error)?;
+
+        let mut trailing = [0u8; 1];
+        if self.source.read(&mut trailing)? != 0 {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        let signature = self.signature.take().ok_or(Error::InvalidEnvelope)?;
+        if signature.len() != 64 {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        let payload_hash: [u8; 32] = self.hasher.finalize().into();
+        let signer = self.header.signature.resolve();
+
+        if !signer.verify(sender_public.as_bytes(), &payload_hash, &signature) {
+            return Err(Error::SignatureVerificationFailed);
+        }
+
+        Ok(self.total)
+    }
+
+    fn next_frame(&mut self) -> Result {
+        let (kind, frame) = read_frame(self.source)?;
+        let aad = frame_aad(&self.aad, self.index, kind);
+
+        let cipher = self.header.encryption.resolve();
+        let plaintext = cipher.decrypt_prefixed_nonce(self.key.as_slice(), &frame, &aad)?;
+
+        match kind {
+            FrameKind::Data => {
+                let compressor = self.header.compression.resolve();
+                let chunk = Zeroizing::new(plaintext);
+
+                // Senders never put more than one chunk in a frame, so anything larger is a bomb.
+                self.buffer =
+                    Zeroizing::new(compressor.decompress_bounded(&chunk, STREAM_CHUNK_SIZE)?);
+                self.position = 0;
+                self.hasher.update(self.buffer.as_slice());
+                self.total += self.buffer.len() as u64;
+                self.index += 1;
+            }
+            FrameKind::Signature => self.signature = Some(plaintext),
+        }
+
+        Ok(())
+    }
+}
+
+impl Read for MessageStreamReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.buffer.len() {
+            if self.signature.is_some() {
+                return Ok(0);
+            }
+            self.next_frame().map_err(io::Error::other)?;
+        }
+
+        let read = buf.len().min(self.buffer.len() - self.position);
+        buf[..read].copy_from_slice(&self.buffer[self.position..self.position + read]);
+        self.position += read;
+
+        Ok(read)
+    }
+}
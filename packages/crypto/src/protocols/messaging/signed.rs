@@ -0,0 +1,135 @@
+use zeroize::Zeroizing;
+
+use crate::errors::{Error, Result};
+use crate::hash::{Hasher, Sha256Hasher};
+use crate::keys::signing::SigningPublicKey;
+use crate::protocols::messaging::mapper::decode_payload;
+use crate::protocols::messaging::metadata::MessageHeader;
+use crate::signature::SignatureAlgorithm;
+
+pub const SENDER_FINGERPRINT_SIZE: usize = 32;
+
+pub type SenderFingerprint = [u8; SENDER_FINGERPRINT_SIZE];
+
+pub fn sender_fingerprint(public_key: &SigningPublicKey) -> SenderFingerprint {
+    Sha256Hasher::hash(public_key.as_bytes())
+}
+
+/// A decrypted message whose signature has not been checked yet. The plaintext is only
+/// released by `verify`.
+pub struct SignedMessage {
+    sender: Option<SenderFingerprint>,
+    algorithm: SignatureAlgorithm,
+    signature: Vec<u8>,
+    message: Zeroizing<Vec<u8>>,
+}
+
+impl SignedMessage {
+    pub(crate) fn decode(header: &MessageHeader, payload: &[u8]) -> Result<Self> {
+        let (sender, payload) = if header.has_sender_fingerprint() {
+            if payload.len() < SENDER_FINGERPRINT_SIZE {
+                return Err(Error::InvalidEnvelope);
+            }
+            let (fingerprint, rest) = payload.split_at(SENDER_FINGERPRINT_SIZE);
+            let mut sender = [0u8; SENDER_FINGERPRINT_SIZE];
+            sender.copy_from_slice(fingerprint);
+            (Some(sender), rest)
+        } else {
+            (None, payload)
+        };
+
+        let (signature, message) = decode_payload(payload)?;
+        if signature.len() != 64 {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        Ok(Self {
+            sender,
+            algorithm: header.signature,
+            signature,
+            message: Zeroizing::new(message),
+        })
+    }
+
+    pub fn sender_fingerprint(&self) -> Option<&SenderFingerprint> {
+        self.sender.as_ref()
+    }
+
+    pub fn is_signed_by(&self, sender_public: &SigningPublicKey) -> bool {
+        let message_hash = Sha256Hasher::hash(&self.message);
+        let signer = self.algorithm.resolve();
+
+        signer.verify(sender_public.as_bytes(), &message_hash, &self.signature)
+    }
+
+    pub fn verify(mut self, sender_public: &SigningPublicKey) -> Result<Vec<u8>> {
+        let claimed = self
+            .sender
+            .is_some_and(|sender| sender != sender_fingerprint(sender_public));
+
+        if claimed || !self.is_signed_by(sender_public) {
+            return Err(Error::SignatureVerificationFailed);
+        }
+
+        Ok(std::mem::take(&mut *self.message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::SigningKeyPair;
+    use crate::protocols::messaging::mapper::{encode_payload, encode_signed_payload};
+    use crate::protocols::messaging::metadata::{ENVELOPE_VERSION, LEGACY_ENVELOPE_VERSION};
+
+    fn header(version: u8) -> MessageHeader {
+        MessageHeader {
+            version,
+            hash: Default::default(),
+            kdf: Default::default(),
+            signature: Default::default(),
+            encryption: Default::default(),
+            compression: Default::default(),
+            ephemeral_public_key: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn test_legacy_payload_has_no_fingerprint() {
+        let keys = SigningKeyPair::generate().unwrap();
+        let message = b"legacy";
+        let signature = SignatureAlgorithm::Ed25519
+            .resolve()
+            .sign(keys.private.as_bytes(), &Sha256Hasher::hash(message));
+
+        let legacy = encode_payload(&signature, message).unwrap();
+        let decoded = SignedMessage::decode(&header(LEGACY_ENVELOPE_VERSION), &legacy).unwrap();
+        assert!(decoded.sender_fingerprint().is_none());
+        assert_eq!(decoded.verify(&keys.public).unwrap(), message);
+
+        let fingerprint = sender_fingerprint(&keys.public);
+        let current = encode_signed_payload(&fingerprint, &signature, message).unwrap();
+        let decoded = SignedMessage::decode(&header(ENVELOPE_VERSION), &current).unwrap();
+        assert_eq!(decoded.sender_fingerprint(), Some(&fingerprint));
+        assert!(decoded.is_signed_by(&keys.public));
+    }
+
+    #[test]
+    fn test_verify_rejects_mismatched_fingerprint() {
+        let keys = SigningKeyPair::generate().unwrap();
+        let other = SigningKeyPair::generate().unwrap();
+        let message = b"claimed";
+        let signature = SignatureAlgorithm::Ed25519
+            .resolve()
+            .sign(keys.private.as_bytes(), &Sha256Hasher::hash(message));
+
+        let payload =
+            encode_signed_payload(&sender_fingerprint(&other.public), &signature, message).unwrap();
+        let decoded = SignedMessage::decode(&header(ENVELOPE_VERSION), &payload).unwrap();
+
+        assert!(matches!(
+            decoded.verify(&keys.public),
+            Err(Error::SignatureVerificationFailed)
+        ));
+    }
+}
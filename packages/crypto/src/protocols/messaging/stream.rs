@@ -0,0 +1,98 @@
+use std::io::{self, Read, Write};
+
+use crate::errors::{Error, Result};
+
+pub const STREAM_MAGIC: &[u8; 4] = b"OVMS";
+pub const STREAM_VERSION: u8 = 1;
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+const FRAME_LEN_BYTES: usize = 4;
+const MAX_FRAME_SIZE: usize = 2 * STREAM_CHUNK_SIZE + 1024;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FrameKind {
+    Data = 1,
+    Signature = 2,
+}
+
+impl TryFrom<u8> for FrameKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::Data),
+            2 => Ok(Self::Signature),
+            _ => Err(Error::InvalidEnvelope),
+        }
+    }
+}
+
+// Every frame is bound to the stream header, its position and its kind, so frames
+// cannot be reordered, dropped or replayed across streams.
+pub(crate) fn frame_aad(header_aad: &[u8], index: u64, kind: FrameKind) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(header_aad.len() + 9);
+    aad.extend_from_slice(header_aad);
+    aad.extend_from_slice(&index.to_be_bytes());
+    aad.push(kind as u8);
+    aad
+}
+
+pub(crate) fn write_frame(writer: &mut dyn Write, kind: FrameKind, frame: &[u8]) -> Result {
+    if frame.len() > MAX_FRAME_SIZE {
+        return Err(Error::InvalidEnvelope);
+    }
+
+    writer.write_all(&[kind as u8])?;
+    writer.write_all(&(frame.len() as u32).to_be_bytes())?;
+    writer.write_all(frame)?;
+    Ok(())
+}
+
+pub(crate) fn read_frame(reader: &mut dyn Read) -> Result<(FrameKind, Vec<u8>)> {
+    let mut prefix = [0u8; 1 + FRAME_LEN_BYTES];
+    read_exact(reader, &mut prefix)?;
+
+    let kind = FrameKind::try_from(prefix[0])?;
+    let len = u32::from_be_bytes([prefix[1], prefix[2], prefix[3], prefix[4]]) as usize;
+    if len > MAX_FRAME_SIZE {
+        return Err(Error::InvalidEnvelope);
+    }
+
+    let mut frame = vec![0u8; len];
+    read_exact(reader, &mut frame)?;
+    Ok((kind, frame))
+}
+
+pub(crate) fn read_exact(reader: &mut dyn Read, buf: &mut [u8]) -> Result {
+    reader.read_exact(buf).map_err(|err| match err.kind() {
+        io::ErrorKind::UnexpectedEof => Error::InvalidEnvelope,
+        _ => Error::Io(err),
+    })
+}
+
+pub(crate) fn read_chunk(reader: &mut dyn Read, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(filled)
+}
+
+// `MessageStreamReader` surfaces protocol errors through `io::Error`, unwrap them again
+// so callers see the original failure.
+pub(crate) fn into_error(err: io::Error) -> Error {
+    if !err.get_ref().is_some_and(|inner| inner.is::<Error>()) {
+        return Error::Io(err);
+    }
+
+    match err.into_inner().map(|inner| inner.downcast::<Error>()) {
+        Some(Ok(err)) => *err,
+        _ => Error::InvalidEnvelope,
+    }
+}
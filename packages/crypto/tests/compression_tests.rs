@@ -65,6 +65,21 @@ fn test_zstd_invalid_data() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_zstd_bounded_decompression() {
+    let compressor = CompressionAlgorithm::Zstd.resolve();
+    let data = vec![0u8; 1024 * 1024];
+    let compressed = compressor.compress(&data).unwrap();
+
+    let decompressed = compressor
+        .decompress_bounded(&compressed, data.len())
+        .unwrap();
+    assert_eq!(data, decompressed);
+
+    let result = compressor.decompress_bounded(&compressed, data.len() - 1);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_zstd_large_data_roundtrip() {
     let compressor = CompressionAlgorithm::Zstd.resolve();
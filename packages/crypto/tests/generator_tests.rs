@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use openvault_crypto::errors::Error;
+use openvault_crypto::generator::{GeneratedPassword, PassphraseOptions, PasswordOptions};
+
+const WORDLIST: &str = include_str!("../src/generator/wordlist.txt");
+
+#[test]
+fn test_password_uses_every_enabled_class() {
+    let options = PasswordOptions {
+        length: 4,
+        ..Default::default()
+    };
+
+    for _ in 0..200 {
+        let password = GeneratedPassword::generate(&options).unwrap();
+        let value = password.as_str();
+
+        assert_eq!(value.len(), 4);
+        assert!(value.chars().any(|c| c.is_ascii_lowercase()));
+        assert!(value.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(value.chars().any(|c| c.is_ascii_digit()));
+        assert!(value.chars().any(|c| c.is_ascii_punctuation()));
+    }
+}
+
+#[test]
+fn test_password_respects_disabled_classes_and_look_alikes() {
+    let options = PasswordOptions {
+        length: 64,
+        symbols: false,
+        exclude_look_alikes: true,
+        ..Default::default()
+    };
+
+    for _ in 0..50 {
+        let password = GeneratedPassword::generate(&options).unwrap();
+
+        assert!(password.as_str().chars().all(|c| c.is_ascii_alphanumeric()));
+        assert!(!password.as_str().contains(['I', 'l', '1', 'O', '0']));
+    }
+}
+
+#[test]
+fn test_password_entropy_estimates() {
+    let digits = PasswordOptions {
+        length: 10,
+        lowercase: false,
+        uppercase: false,
+        digits: true,
+        symbols: false,
+        exclude_look_alikes: false,
+    };
+    let entropy = GeneratedPassword::generate(&digits).unwrap().entropy_bits();
+    assert!((entropy - 10.0 * 10f64.log2()).abs() < 1e-9);
+
+    // Requiring every class removes a little entropy compared to a free pick from the pool.
+    let mixed = GeneratedPassword::generate(&PasswordOptions::default()).unwrap();
+    let unconstrained = 20.0 * 90f64.log2();
+    assert!(mixed.entropy_bits() < unconstrained);
+    assert!(mixed.entropy_bits() > unconstrained - 1.0);
+}
+
+#[test]
+fn test_password_rejects_invalid_options() {
+    let invalid = [
+        PasswordOptions {
+            length: 3,
+            ..Default::default()
+        },
+        PasswordOptions {
+            length: 1024,
+            ..Default::default()
+        },
+        PasswordOptions {
+            lowercase: false,
+            uppercase: false,
+            digits: false,
+            symbols: false,
+            ..Default::default()
+        },
+    ];
+
+    for options in invalid {
+        assert!(matches!(
+            GeneratedPassword::generate(&options),
+            Err(Error::InvalidGeneratorOptions(_))
+        ));
+    }
+}
+
+#[test]
+fn test_passphrase_draws_words_from_the_bundled_list() {
+    let wordlist: HashSet<&str> = WORDLIST.lines().collect();
+    assert_eq!(wordlist.len(), 2048);
+
+    let passphrase = GeneratedPassword::passphrase(&PassphraseOptions::default()).unwrap();
+    let words: Vec<&str> = passphrase.as_str().split('-').collect();
+
+    assert_eq!(words.len(), 6);
+    assert!(words.iter().all(|word| wordlist.contains(word)));
+    assert_eq!(passphrase.entropy_bits(), 66.0);
+}
+
+#[test]
+fn test_passphrase_capitalizes_and_adds_a_number() {
+    let options = PassphraseOptions {
+        words: 4,
+        separator: " ".to_string(),
+        capitalize: true,
+        include_number: true,
+    };
+    let passphrase = GeneratedPassword::passphrase(&options).unwrap();
+    let words: Vec<&str> = passphrase.as_str().split(' ').collect();
+
+    assert_eq!(words.len(), 4);
+    assert!(
+        words
+            .iter()
+            .all(|word| word.starts_with(|c: char| c.is_ascii_uppercase()))
+    );
+    assert_eq!(
+        passphrase
+            .as_str()
+            .chars()
+            .filter(char::is_ascii_digit)
+            .count(),
+        1
+    );
+    assert!((passphrase.entropy_bits() - (44.0 + 10f64.log2() + 2.0)).abs() < 1e-9);
+
+    assert!(matches!(
+        GeneratedPassword::passphrase(&PassphraseOptions {
+            words: 2,
+            ..Default::default()
+        }),
+        Err(Error::InvalidGeneratorOptions(_))
+    ));
+}
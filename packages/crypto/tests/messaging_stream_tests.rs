@@ -0,0 +1,125 @@
+use std::io::{Cursor, Read};
+
+use openvault_crypto::errors::Error;
+use openvault_crypto::keys::{EphemeralKeyPair, SigningKeyPair};
+use openvault_crypto::protocols::messaging::{
+    MessageStreamReader, STREAM_CHUNK_SIZE, sign_then_encrypt_stream, verify_then_decrypt_stream,
+};
+
+struct Parties {
+    sender: SigningKeyPair,
+    recipient: EphemeralKeyPair,
+}
+
+impl Parties {
+    fn generate() -> Self {
+        Self {
+            sender: SigningKeyPair::generate().unwrap(),
+            recipient: EphemeralKeyPair::generate().unwrap(),
+        }
+    }
+}
+
+fn encrypt(parties: &Parties, payload: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let written = sign_then_encrypt_stream(
+        &mut Cursor::new(payload),
+        &mut output,
+        &parties.sender,
+        &parties.recipient.public,
+    )
+    .unwrap();
+
+    assert_eq!(written, payload.len() as u64);
+    output
+}
+
+fn decrypt(parties: &Parties, stream: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::new();
+    verify_then_decrypt_stream(
+        &mut Cursor::new(stream),
+        &mut output,
+        &parties.recipient.private,
+        &parties.sender.public,
+    )?;
+    Ok(output)
+}
+
+#[test]
+fn test_stream_roundtrip_binary_payloads() {
+    let parties = Parties::generate();
+
+    let sizes = [0, 1, STREAM_CHUNK_SIZE, 3 * STREAM_CHUNK_SIZE + 17];
+    for size in sizes {
+        let payload: Vec<u8> = (0..size).map(|i| (i * 7 % 256) as u8).collect();
+        let stream = encrypt(&parties, &payload);
+
+        assert_eq!(decrypt(&parties, &stream).unwrap(), payload);
+    }
+}
+
+#[test]
+fn test_stream_reader_requires_finish_for_authentication() {
+    let parties = Parties::generate();
+    let payload = vec![0xffu8; STREAM_CHUNK_SIZE + 5];
+    let stream = encrypt(&parties, &payload);
+
+    let mut source = Cursor::new(stream.as_slice());
+    let mut reader = MessageStreamReader::open(&mut source, &parties.recipient.private).unwrap();
+    let mut first = [0u8; 10];
+    reader.read_exact(&mut first).unwrap();
+    assert_eq!(first, [0xffu8; 10]);
+
+    let total = reader.finish(&parties.sender.public).unwrap();
+    assert_eq!(total, payload.len() as u64);
+}
+
+#[test]
+fn test_stream_rejects_wrong_keys() {
+    let parties = Parties::generate();
+    let stranger = Parties::generate();
+    let stream = encrypt(&parties, b"\x00\x9f binary \xff");
+
+    let mut output = Vec::new();
+    let result = verify_then_decrypt_stream(
+        &mut Cursor::new(&stream),
+        &mut output,
+        &stranger.recipient.private,
+        &parties.sender.public,
+    );
+    assert!(matches!(result, Err(Error::DecryptionFailed)));
+
+    let result = verify_then_decrypt_stream(
+        &mut Cursor::new(&stream),
+        &mut output,
+        &parties.recipient.private,
+        &stranger.sender.public,
+    );
+    assert!(matches!(result, Err(Error::SignatureVerificationFailed)));
+}
+
+#[test]
+fn test_stream_rejects_tampering_and_truncation() {
+    let parties = Parties::generate();
+    let payload = vec![42u8; 2 * STREAM_CHUNK_SIZE];
+    let stream = encrypt(&parties, &payload);
+
+    let mut tampered = stream.clone();
+    let index = tampered.len() / 2;
+    tampered[index] ^= 0x01;
+    assert!(decrypt(&parties, &tampered).is_err());
+
+    assert!(decrypt(&parties, &stream[..stream.len() - 1]).is_err());
+
+    let mut trailing = stream.clone();
+    trailing.push(0);
+    assert!(matches!(
+        decrypt(&parties, &trailing),
+        Err(Error::InvalidEnvelope)
+    ));
+
+    assert!(matches!(
+        decrypt(&parties, b"not a stream at all"),
+        Err(Error::InvalidMessageFormat)
+    ));
+}
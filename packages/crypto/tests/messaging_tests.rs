@@ -0,0 +1,208 @@
+use openvault_crypto::errors::Error;
+use openvault_crypto::keys::{
+    EphemeralKeyPair, EphemeralPrivateKey, SigningKeyPair, SigningPublicKey,
+};
+use openvault_crypto::protocols::messaging::{
+    LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION, MULTI_RECIPIENT_ENVELOPE_VERSION,
+    MultiRecipientEnvelope, armor, dearmor, recipient_key_id, sign_then_encrypt,
+    sign_then_encrypt_bytes, sign_then_encrypt_multi, verify_then_decrypt,
+    verify_then_decrypt_bytes, verify_then_decrypt_multi,
+};
+
+// Version 2 multi-recipient envelope sealed for the recipient key [7; 32], before payloads
+// carried a sender fingerprint.
+const LEGACY_MULTI_SENDER: &str =
+    "000846eb2e8d18015d4fa9af39c1b49064b66fb1c6e272b5308c431ee05fe522";
+const LEGACY_MULTI_ENVELOPE: &str = "020000000000235e3dfc0b4781adaffec6de4714618fdbd333d1ebc75209c6cc948e939e4d15010f6d2553ff0c0f4448ebd9e9c00336f863b8c81396ebc3a8094bc3ca29373c3a6f48004eff1a8806df0f3c03bb231cda2dc290cb043fe19093d553964969560ee209df50d2d3a1bc2062d92b480d41038c8d01e324e2bdee09166c5fc1ae5e385e297ec96be4944899b0d47619dcf1be2aaa359425194767ce5b25030d7898a89d0f063b3fa75c21b6e40642264c43d8068d0677e6ce4ee531cec96986c9ad9de0c29dae2e32ece71b4d358d24c2be1883f57e5defb8f787bab9ad17969cc234d9e173785331de5ac167e14789bae259304c1571c5d54712c81fea57718da6fd";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn binary_payloads() -> Vec<Vec<u8>> {
+    vec![
+        Vec::new(),
+        vec![0u8],
+        vec![0xff, 0xfe, 0x00, 0x80, 0xc3, 0x28],
+        (0..=255u8).collect(),
+        (0..200_000).map(|i| (i * 131 % 256) as u8).collect(),
+    ]
+}
+
+#[test]
+fn test_bytes_roundtrip_arbitrary_payloads() {
+    let sender = SigningKeyPair::generate().unwrap();
+    let recipient = EphemeralKeyPair::generate().unwrap();
+
+    for payload in binary_payloads() {
+        let envelope = sign_then_encrypt_bytes(&payload, &sender, &recipient.public).unwrap();
+        let decrypted =
+            verify_then_decrypt_bytes(&envelope, &recipient.private, &sender.public).unwrap();
+
+        assert_eq!(decrypted, payload);
+    }
+}
+
+#[test]
+fn test_armor_is_a_separate_layer() {
+    let sender = SigningKeyPair::generate().unwrap();
+    let recipient = EphemeralKeyPair::generate().unwrap();
+    let payload = [0xc3u8, 0x28, 0x00, 0xff];
+
+    let envelope = sign_then_encrypt_bytes(&payload, &sender, &recipient.public).unwrap();
+    let armored = format!("{}\n", armor(&envelope));
+    assert_eq!(dearmor(armored.as_bytes()).unwrap(), envelope);
+
+    let decrypted = verify_then_decrypt_bytes(
+        &dearmor(armored.as_bytes()).unwrap(),
+        &recipient.private,
+        &sender.public,
+    )
+    .unwrap();
+    assert_eq!(decrypted, payload);
+
+    assert!(matches!(dearmor(b"not base64 !"), Err(Error::DecodeBase64)));
+}
+
+#[test]
+fn test_text_api_keeps_utf8_contract() {
+    let sender = SigningKeyPair::generate().unwrap();
+    let recipient = EphemeralKeyPair::generate().unwrap();
+
+    let armored = sign_then_encrypt("grüße".as_bytes(), &sender, &recipient.public).unwrap();
+    assert_eq!(
+        verify_then_decrypt(armored.as_bytes(), &recipient.private, &sender.public).unwrap(),
+        "grüße"
+    );
+
+    let envelope = sign_then_encrypt_bytes(&[0xff, 0xfe], &sender, &recipient.public).unwrap();
+    let armored = armor(&envelope);
+    assert!(matches!(
+        verify_then_decrypt(armored.as_bytes(), &recipient.private, &sender.public),
+        Err(Error::InvalidMessageFormat)
+    ));
+}
+
+#[test]
+fn test_bytes_rejects_tampering_and_wrong_sender() {
+    let sender = SigningKeyPair::generate().unwrap();
+    let stranger = SigningKeyPair::generate().unwrap();
+    let recipient = EphemeralKeyPair::generate().unwrap();
+
+    let envelope = sign_then_encrypt_bytes(&[1, 2, 3], &sender, &recipient.public).unwrap();
+
+    let mut tampered = envelope.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 0x01;
+    assert!(verify_then_decrypt_bytes(&tampered, &recipient.private, &sender.public).is_err());
+
+    assert!(matches!(
+        verify_then_decrypt_bytes(&envelope, &recipient.private, &stranger.public),
+        Err(Error::SignatureVerificationFailed)
+    ));
+}
+
+#[test]
+fn test_multi_recipient_envelope_wraps_key_per_recipient() {
+    let sender = SigningKeyPair::generate().unwrap();
+    let team: Vec<EphemeralKeyPair> = (0..5)
+        .map(|_| EphemeralKeyPair::generate().unwrap())
+        .collect();
+    let outsider = EphemeralKeyPair::generate().unwrap();
+    let payload: Vec<u8> = (0..10_000).map(|i| (i % 253) as u8).collect();
+
+    let mut recipients: Vec<_> = team.iter().map(|keys| keys.public.clone()).collect();
+    recipients.push(team[0].public.clone());
+
+    let envelope = sign_then_encrypt_multi(&payload, &sender, &recipients).unwrap();
+
+    let decoded: MultiRecipientEnvelope = postcard::from_bytes(&envelope).unwrap();
+    assert_eq!(decoded.recipients.len(), team.len());
+    for (wrap, keys) in decoded.recipients.iter().zip(&team) {
+        assert_eq!(wrap.key_id, recipient_key_id(&keys.public));
+    }
+
+    for keys in &team {
+        assert_eq!(
+            verify_then_decrypt_multi(&envelope, &keys.private, &sender.public).unwrap(),
+            payload
+        );
+        assert_eq!(
+            verify_then_decrypt_bytes(&envelope, &keys.private, &sender.public).unwrap(),
+            payload
+        );
+    }
+
+    assert!(matches!(
+        verify_then_decrypt_bytes(&envelope, &outsider.private, &sender.public),
+        Err(Error::RecipientNotFound)
+    ));
+    assert!(matches!(
+        sign_then_encrypt_multi(&payload, &sender, &[]),
+        Err(Error::RecipientNotFound)
+    ));
+}
+
+#[test]
+fn test_legacy_multi_recipient_envelope_still_decrypts() {
+    let recipient = EphemeralPrivateKey::from_bytes([7u8; 32]).unwrap();
+    let sender = SigningPublicKey::from_bytes(decode_hex(LEGACY_MULTI_SENDER).try_into().unwrap());
+    let envelope = decode_hex(LEGACY_MULTI_ENVELOPE);
+    assert_eq!(envelope[0], LEGACY_MULTI_RECIPIENT_ENVELOPE_VERSION);
+
+    for decrypted in [
+        verify_then_decrypt_multi(&envelope, &recipient, &sender).unwrap(),
+        verify_then_decrypt_bytes(&envelope, &recipient, &sender).unwrap(),
+    ] {
+        assert_eq!(decrypted, b"sealed before fingerprints");
+    }
+
+    let stranger = SigningKeyPair::generate().unwrap();
+    assert!(matches!(
+        verify_then_decrypt_multi(&envelope, &recipient, &stranger.public),
+        Err(Error::SignatureVerificationFailed)
+    ));
+
+    let current = sign_then_encrypt_multi(
+        b"sealed with a fingerprint",
+        &stranger,
+        &[recipient.public_key()],
+    )
+    .unwrap();
+    assert_eq!(current[0], MULTI_RECIPIENT_ENVELOPE_VERSION);
+}
+
+#[test]
+fn test_multi_recipient_envelope_rejects_tampering() {
+    let sender = SigningKeyPair::generate().unwrap();
+    let stranger = SigningKeyPair::generate().unwrap();
+    let recipient = EphemeralKeyPair::generate().unwrap();
+
+    let envelope = sign_then_encrypt_multi(
+        b"team note",
+        &sender,
+        std::slice::from_ref(&recipient.public),
+    )
+    .unwrap();
+
+    let mut decoded: MultiRecipientEnvelope = postcard::from_bytes(&envelope).unwrap();
+    decoded.recipients[0].wrapped_key[30] ^= 0x01;
+    let tampered = postcard::to_allocvec(&decoded).unwrap();
+    assert!(matches!(
+        verify_then_decrypt_bytes(&tampered, &recipient.private, &sender.public),
+        Err(Error::RecipientNotFound)
+    ));
+
+    let mut tampered = envelope.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 0x01;
+    assert!(verify_then_decrypt_bytes(&tampered, &recipient.private, &sender.public).is_err());
+
+    assert!(matches!(
+        verify_then_decrypt_bytes(&envelope, &recipient.private, &stranger.public),
+        Err(Error::SignatureVerificationFailed)
+    ));
+}
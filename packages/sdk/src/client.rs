@@ -1,9 +1,15 @@
 use std::path::Path;
 
+use openvault_core::errors::Error as CoreError;
 use openvault_core::operations::config::CreateConfig;
 use openvault_core::operations::vault::{
-    create_and_open_vault, create_vault, create_vault_with, open_vault,
+    create_and_open_vault, create_vault, create_vault_with, open_vault, unlock_vault,
 };
+use openvault_core::operations::verify::verify_vault;
+use openvault_core::vault::crypto::credential::Credential;
+use openvault_core::vault::versions::shared::verify::VerifyReport;
+use openvault_crypto::keys::recovery_key::RecoveryKey;
+use zeroize::Zeroize;
 
 use crate::errors::Error;
 use crate::errors::Result;
@@ -36,6 +42,37 @@ impl VaultClient {
         Vault::new(session)
     }
 
+    pub fn open_with_recovery_key(
+        &self,
+        path: impl AsRef<Path>,
+        recovery_key: impl AsRef<str>,
+    ) -> Result<Vault> {
+        let recovery_key = RecoveryKey::parse(recovery_key.as_ref()).map_err(CoreError::from)?;
+        self.unlock(path, &Credential::recovery_key(&recovery_key))
+    }
+
+    pub fn open_with_keyfile(
+        &self,
+        path: impl AsRef<Path>,
+        keyfile: impl AsRef<Path>,
+    ) -> Result<Vault> {
+        let mut contents = std::fs::read(keyfile)?;
+        let credential = Credential::keyfile(&contents);
+        contents.zeroize();
+
+        self.unlock(path, &credential)
+    }
+
+    pub fn unlock(&self, path: impl AsRef<Path>, credential: &Credential) -> Result<Vault> {
+        let session = unlock_vault(path.as_ref(), credential).map_err(Error::from)?;
+
+        Vault::new(session)
+    }
+
+    pub fn verify(&self, path: impl AsRef<Path>, credential: &Credential) -> Result<VerifyReport> {
+        verify_vault(path.as_ref(), credential).map_err(Into::into)
+    }
+
     pub fn create_and_open(
         &self,
         path: impl AsRef<Path>,
@@ -1,11 +1,19 @@
 use std::fs::File;
+use std::io;
 use std::path::Path;
 use uuid::Uuid;
 
-use openvault_core::features::filesystem::{FileMetadata, FilesystemStore, FolderMetadata};
+use chrono::TimeDelta;
+use openvault_core::features::chunks::ChunksStore;
+use openvault_core::features::filesystem::{
+    FileMetadata, FileRevision, FilesystemStore, FilesystemTrashEntry, FolderMetadata,
+    RevisionRetention,
+};
+use openvault_core::features::shared::BlobRef;
 use openvault_core::internal::scanner::{ScannedFolder, scan_directory};
 use openvault_core::operations::blob;
 use openvault_core::vault::runtime::VaultSession;
+use openvault_core::vault::versions::shared::format::BlobStream;
 
 use crate::errors::{Error, Result};
 use crate::internal::file::{
@@ -16,11 +24,20 @@ use crate::internal::file::{
 pub struct FilesystemService<'a> {
     session: &'a mut VaultSession,
     store: &'a mut FilesystemStore,
+    chunks: &'a mut ChunksStore,
 }
 
 impl<'a> FilesystemService<'a> {
-    pub fn new(session: &'a mut VaultSession, store: &'a mut FilesystemStore) -> Self {
-        FilesystemService { session, store }
+    pub fn new(
+        session: &'a mut VaultSession,
+        store: &'a mut FilesystemStore,
+        chunks: &'a mut ChunksStore,
+    ) -> Self {
+        FilesystemService {
+            session,
+            store,
+            chunks,
+        }
     }
 
     pub fn read_file_bytes(&mut self, id: Uuid) -> Result<Vec<u8>> {
@@ -29,6 +46,12 @@ impl<'a> FilesystemService<'a> {
         blob::read_blob(self.session, &blob_ref).map_err(Error::from)
     }
 
+    pub fn open_file(&mut self, id: Uuid) -> Result<BlobStream<'_>> {
+        let blob_ref = self.get_file(&id)?.blob.clone();
+
+        blob::open_blob(self.session, &blob_ref).map_err(Error::from)
+    }
+
     pub fn get_file_extension(&self, id: Uuid) -> Result<String> {
         let file = self.get_file(&id)?;
         Ok(file.extension.clone())
@@ -56,13 +79,45 @@ impl<'a> FilesystemService<'a> {
             .to_owned();
 
         let mut file = File::open(source_path)?;
-        let blob_ref = blob::write_blob(self.session, &mut file)?;
+        let blob_ref = blob::write_blob(self.session, self.chunks, &mut file)?;
 
         let file_id = self.store.add_file(parent_id, name, extension, blob_ref)?;
 
         Ok(file_id)
     }
 
+    pub fn replace_file(&mut self, id: Uuid, source_path: &Path) -> Result {
+        self.get_file(&id)?;
+
+        let mut file = File::open(source_path)?;
+        let blob_ref = blob::write_blob(self.session, self.chunks, &mut file)?;
+
+        self.store
+            .replace_file_blob(id, blob_ref)
+            .map_err(Error::from)
+    }
+
+    pub fn file_revisions(&self, id: Uuid) -> Result<Vec<FileRevision>> {
+        Ok(self.get_file(&id)?.revisions.clone())
+    }
+
+    pub fn restore_file_revision(&mut self, id: Uuid, revision_id: Uuid) -> Result {
+        self.store
+            .restore_file_revision(id, revision_id)
+            .map_err(Error::from)
+    }
+
+    pub fn prune_file_revisions(
+        &mut self,
+        id: Uuid,
+        retention: RevisionRetention,
+    ) -> Result<usize> {
+        let pruned = self.store.prune_file_revisions(id, retention)?;
+        self.release_blobs(&pruned)?;
+
+        Ok(pruned.len())
+    }
+
     pub fn upload_folder(&mut self, parent_id: Uuid, source_path: &Path) -> Result<Uuid> {
         let scanned = scan_directory(source_path)?;
         self.upload_scanned_folder(parent_id, &scanned)
@@ -77,10 +132,29 @@ impl<'a> FilesystemService<'a> {
             std::fs::create_dir_all(parent)?;
         }
 
-        let content = self.read_file_bytes(id)?;
-        std::fs::write(destination, content)?;
+        let blob_ref = file.blob.clone();
+        self.export_blob(&blob_ref, &destination)
+    }
 
-        Ok(())
+    pub fn export_file_revision(
+        &mut self,
+        id: Uuid,
+        revision_id: Uuid,
+        destination_path: &Path,
+    ) -> Result {
+        let file = self.get_file(&id)?;
+        let revision = file
+            .revision(&revision_id)
+            .ok_or(Error::ItemNotFound(revision_id.to_string()))?;
+
+        let destination = resolve_export_file_destination(destination_path, &file.name)?;
+
+        if let Some(parent) = destination.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let blob_ref = revision.blob.clone();
+        self.export_blob(&blob_ref, &destination)
     }
 
     pub fn export_folder(&mut self, id: Uuid, destination_path: &Path) -> Result {
@@ -111,11 +185,34 @@ impl<'a> FilesystemService<'a> {
     }
 
     pub fn remove_folder(&mut self, id: Uuid) -> Result {
-        self.store.remove_folder(id).map_err(Error::from)
+        self.store.trash_folder(id).map_err(Error::from)
     }
 
     pub fn remove_file(&mut self, id: Uuid) -> Result {
-        self.store.remove_file(id).map_err(Error::from)
+        self.store.trash_file(id).map_err(Error::from)
+    }
+
+    pub fn trash(&self) -> Vec<FilesystemTrashEntry> {
+        self.store.trash()
+    }
+
+    pub fn restore_from_trash(&mut self, id: Uuid) -> Result {
+        self.store.restore_from_trash(id).map_err(Error::from)
+    }
+
+    pub fn purge_from_trash(&mut self, id: Uuid) -> Result {
+        let purged = self.store.purge_from_trash(id)?;
+        self.release_blobs(&purged)
+    }
+
+    pub fn empty_trash(&mut self) -> Result {
+        let purged = self.store.empty_trash()?;
+        self.release_blobs(&purged)
+    }
+
+    pub fn purge_expired_trash(&mut self, retention: TimeDelta) -> Result {
+        let purged = self.store.purge_expired_trash(retention)?;
+        self.release_blobs(&purged)
     }
 
     pub fn set_folder_favorite(&mut self, id: Uuid, is_favourite: bool) -> Result {
@@ -163,13 +260,33 @@ impl<'a> FilesystemService<'a> {
         Ok(folder_id)
     }
 
+    fn release_blobs(&mut self, blobs: &[BlobRef]) -> Result {
+        for blob_ref in blobs {
+            blob::release_blob(self.session, self.chunks, blob_ref)?;
+        }
+
+        Ok(())
+    }
+
+    fn export_blob(&mut self, blob_ref: &BlobRef, destination: &Path) -> Result {
+        let mut reader = blob::open_blob(self.session, blob_ref)?;
+        let mut output = File::create(destination)?;
+
+        if let Err(error) = io::copy(&mut reader, &mut output) {
+            drop(output);
+            let _ = std::fs::remove_file(destination);
+            return Err(error.into());
+        }
+
+        Ok(())
+    }
+
     fn export_folder_contents(&mut self, folder_id: Uuid, destination_path: &Path) -> Result {
         let (folders, files) = self.store.browse(&folder_id)?;
 
         for file in files {
             let file_path = find_available_path(&destination_path.join(&file.name), true)?;
-            let content = blob::read_blob(self.session, &file.blob)?;
-            std::fs::write(file_path, content)?;
+            self.export_blob(&file.blob, &file_path)?;
         }
 
         for folder in folders {
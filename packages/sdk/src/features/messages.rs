@@ -1,8 +1,10 @@
+use std::io::{self, Read, Write};
+
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use openvault_core::features::messages::{
-    MessageContact, MessageCredentials, MessageCredentialsView, MessagesStore,
+    DecryptedMessage, MessageContact, MessageCredentials, MessageCredentialsView, MessagesStore,
 };
 use openvault_core::vault::runtime::VaultSession;
 
@@ -86,4 +88,73 @@ impl<'a> MessagesService<'a> {
             .decrypt_from_contact_name(name, payload)
             .map_err(Error::from)
     }
+
+    pub fn encrypt_bytes_for_contact(&self, id: Uuid, payload: &[u8]) -> Result<Vec<u8>> {
+        self.store
+            .encrypt_bytes_for_contact(id, payload)
+            .map_err(Error::from)
+    }
+
+    pub fn encrypt_bytes_for_contact_name(&self, name: &str, payload: &[u8]) -> Result<Vec<u8>> {
+        self.store
+            .encrypt_bytes_for_contact_name(name, payload)
+            .map_err(Error::from)
+    }
+
+    pub fn decrypt_bytes_from_contact(&self, id: Uuid, payload: &[u8]) -> Result<Vec<u8>> {
+        self.store
+            .decrypt_bytes_from_contact(id, payload)
+            .map_err(Error::from)
+    }
+
+    pub fn decrypt_bytes_from_contact_name(&self, name: &str, payload: &[u8]) -> Result<Vec<u8>> {
+        self.store
+            .decrypt_bytes_from_contact_name(name, payload)
+            .map_err(Error::from)
+    }
+
+    pub fn encrypt_for_contacts(&self, ids: &[Uuid], payload: &[u8]) -> Result<String> {
+        self.store
+            .encrypt_for_contacts(ids, payload)
+            .map_err(Error::from)
+    }
+
+    pub fn encrypt_bytes_for_contacts(&self, ids: &[Uuid], payload: &[u8]) -> Result<Vec<u8>> {
+        self.store
+            .encrypt_bytes_for_contacts(ids, payload)
+            .map_err(Error::from)
+    }
+
+    pub fn decrypt_auto(&self, payload: &[u8]) -> Result<DecryptedMessage> {
+        self.store.decrypt_auto(payload).map_err(Error::from)
+    }
+
+    pub fn decrypt_bytes_auto(&self, payload: &[u8]) -> Result<DecryptedMessage> {
+        self.store.decrypt_bytes_auto(payload).map_err(Error::from)
+    }
+
+    pub fn encrypt_stream_for_contact(
+        &self,
+        id: Uuid,
+        source: &mut dyn Read,
+        destination: &mut dyn Write,
+    ) -> Result<u64> {
+        self.store
+            .encrypt_stream_for_contact(id, source, destination)
+            .map_err(Error::from)
+    }
+
+    /// Plaintext reaches `destination` before the signature is checked, discard it
+    /// when this returns an error.
+    pub fn decrypt_stream_from_contact(
+        &self,
+        id: Uuid,
+        source: &mut dyn Read,
+        destination: &mut dyn Write,
+    ) -> Result<u64> {
+        let mut content = self.store.open_stream_from_contact(id, source)?;
+        io::copy(&mut content, destination)?;
+
+        content.finish().map_err(Error::from)
+    }
 }
@@ -1,8 +1,11 @@
+use chrono::TimeDelta;
 use uuid::Uuid;
 
 use openvault_core::features::secrets::{
-    LoginEntry, LoginEntryPatch, LoginEntryView, NewLoginSecret, NewLoginSecretPatch,
-    SECRETS_FEATURE_ID, SecretFolder, SecretStore,
+    AuditOptions, AuditReport, NewApiKeySecret, NewApiKeySecretPatch, NewLoginSecret,
+    NewLoginSecretPatch, NewSecretEntry, NewSecretEntryPatch, SECRETS_FEATURE_ID, SecretEntry,
+    SecretEntryPatch, SecretEntryView, SecretError, SecretFolder, SecretStore, SecretTrashEntry,
+    TotpCode,
 };
 use openvault_core::vault::runtime::VaultSession;
 use openvault_crypto::keys::derived_key::DerivedKey;
@@ -19,7 +22,7 @@ impl<'a> SecretsService<'a> {
         Self { session, store }
     }
 
-    pub fn browse(&self, parent_id: &Uuid) -> Result<(Vec<SecretFolder>, Vec<LoginEntry>)> {
+    pub fn browse(&self, parent_id: &Uuid) -> Result<(Vec<SecretFolder>, Vec<SecretEntry>)> {
         self.store.browse(parent_id).map_err(Error::from)
     }
 
@@ -27,11 +30,11 @@ impl<'a> SecretsService<'a> {
         self.store.folders(parent_id)
     }
 
-    pub fn list_entries(&self, parent_id: Uuid) -> Vec<LoginEntry> {
+    pub fn list_entries(&self, parent_id: Uuid) -> Vec<SecretEntry> {
         self.store.entries(parent_id)
     }
 
-    pub fn list_entries_revealed(&self, parent_id: Uuid) -> Result<Vec<LoginEntryView>> {
+    pub fn list_entries_revealed(&self, parent_id: Uuid) -> Result<Vec<SecretEntryView>> {
         let key = self.secrets_key()?;
         let cipher = self.session.cipher();
         self.store
@@ -50,21 +53,37 @@ impl<'a> SecretsService<'a> {
     }
 
     pub fn remove_folder(&mut self, id: Uuid) -> Result {
-        self.store.remove_folder(id).map_err(Error::from)
+        self.store.trash_folder(id).map_err(Error::from)
     }
 
-    pub fn add_login(&mut self, input: NewLoginSecret) -> Result<Uuid> {
+    pub fn add_entry(&mut self, input: NewSecretEntry) -> Result<Uuid> {
         let key = self.secrets_key()?;
-        let entry = LoginEntry::seal(input, &key, self.session.cipher())?;
+        let entry = SecretEntry::seal(input, &key, self.session.cipher())?;
         self.store.add_entry(entry).map_err(Error::from)
     }
 
-    pub fn update_login(&mut self, id: Uuid, input: NewLoginSecretPatch) -> Result {
+    pub fn update_entry(&mut self, id: Uuid, input: NewSecretEntryPatch) -> Result {
         let key = self.secrets_key()?;
-        let patch = LoginEntryPatch::from_plaintext(input, &key, self.session.cipher())?;
+        let patch = SecretEntryPatch::from_plaintext(input, &key, self.session.cipher())?;
         self.store.update_entry(id, patch).map_err(Error::from)
     }
 
+    pub fn add_login(&mut self, input: NewLoginSecret) -> Result<Uuid> {
+        self.add_entry(NewSecretEntry::Login(input))
+    }
+
+    pub fn update_login(&mut self, id: Uuid, input: NewLoginSecretPatch) -> Result {
+        self.update_entry(id, NewSecretEntryPatch::Login(input))
+    }
+
+    pub fn add_api_key(&mut self, input: NewApiKeySecret) -> Result<Uuid> {
+        self.add_entry(NewSecretEntry::ApiKey(input))
+    }
+
+    pub fn update_api_key(&mut self, id: Uuid, input: NewApiKeySecretPatch) -> Result {
+        self.update_entry(id, NewSecretEntryPatch::ApiKey(input))
+    }
+
     pub fn move_entry(&mut self, id: Uuid, new_folder_id: Uuid) -> Result {
         self.store
             .move_entry(id, new_folder_id)
@@ -72,17 +91,39 @@ impl<'a> SecretsService<'a> {
     }
 
     pub fn remove_entry(&mut self, id: Uuid) -> Result {
-        self.store.remove_entry(id).map_err(Error::from)
+        self.store.trash_entry(id).map_err(Error::from)
     }
 
-    pub fn entry(&self, id: &Uuid) -> Result<LoginEntry> {
+    pub fn trash(&self) -> Vec<SecretTrashEntry> {
+        self.store.trash()
+    }
+
+    pub fn restore_from_trash(&mut self, id: Uuid) -> Result {
+        self.store.restore_from_trash(id).map_err(Error::from)
+    }
+
+    pub fn purge_from_trash(&mut self, id: Uuid) -> Result {
+        self.store.purge_from_trash(id).map_err(Error::from)
+    }
+
+    pub fn empty_trash(&mut self) -> Result {
+        self.store.empty_trash().map_err(Error::from)
+    }
+
+    pub fn purge_expired_trash(&mut self, retention: TimeDelta) -> Result {
+        self.store
+            .purge_expired_trash(retention)
+            .map_err(Error::from)
+    }
+
+    pub fn entry(&self, id: &Uuid) -> Result<SecretEntry> {
         self.store
             .entry(id)
             .cloned()
             .ok_or_else(|| Error::ItemNotFound(id.to_string()))
     }
 
-    pub fn reveal_entry(&self, id: &Uuid) -> Result<LoginEntryView> {
+    pub fn reveal_entry(&self, id: &Uuid) -> Result<SecretEntryView> {
         let key = self.secrets_key()?;
         let cipher = self.session.cipher();
         let entry = self
@@ -93,6 +134,30 @@ impl<'a> SecretsService<'a> {
         entry.reveal(&key, cipher).map_err(Error::from)
     }
 
+    // Only the TOTP secret is revealed, and it is zeroized as soon as the code is computed.
+    pub fn current_totp(&self, id: &Uuid) -> Result<TotpCode> {
+        let key = self.secrets_key()?;
+        let entry = self
+            .store
+            .entry(id)
+            .ok_or_else(|| Error::ItemNotFound(id.to_string()))?;
+        let totp = entry
+            .as_login()
+            .and_then(|login| login.totp.as_ref())
+            .ok_or(SecretError::TotpNotConfigured(*id))?
+            .reveal(&key, self.session.cipher())?;
+
+        totp.current_code().map_err(Error::from)
+    }
+
+    // Logins are revealed one at a time and zeroized before the next is read.
+    pub fn audit(&self, options: &AuditOptions) -> Result<AuditReport> {
+        let key = self.secrets_key()?;
+        self.store
+            .audit(&key, self.session.cipher(), options)
+            .map_err(Error::from)
+    }
+
     fn secrets_key(&self) -> Result<DerivedKey> {
         self.session
             .keyring()
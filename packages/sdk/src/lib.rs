@@ -3,6 +3,7 @@ mod errors;
 mod features;
 mod internal;
 mod vault;
+mod view;
 
 use std::path::Path;
 
@@ -11,19 +12,53 @@ use crate::errors::Result;
 
 pub use errors::Error;
 pub use vault::Vault;
+pub use view::VaultView;
 
+pub use openvault_core::archive::{ArchiveCredential, ArchiveRecipient};
 pub use openvault_core::features::filesystem::{
-    FILESYSTEM_ROOT_FOLDER_ID, FileMetadata, FolderMetadata,
+    FILESYSTEM_ROOT_FOLDER_ID, FileMetadata, FileRevision, FilesystemTrashEntry,
+    FilesystemTrashItem, FolderMetadata, RevisionRetention,
+};
+pub use openvault_core::features::messages::{
+    DecryptedMessage, MessageContact, MessageCredentials,
 };
-pub use openvault_core::features::messages::{MessageContact, MessageCredentials};
 pub use openvault_core::features::secrets::{
-    LoginEntry, LoginEntryView, SECRETS_ROOT_FOLDER_ID, SecretFolder, TOTP,
+    ApiKeyEntry, ApiKeyEntryView, AuditFinding, AuditIssue, AuditOptions, AuditReport, BreachIndex,
+    CustomEntryView, CustomField, IdentityEntryView, LoginEntry, LoginEntryView, NewApiKeySecret,
+    NewApiKeySecretPatch, NewCustomSecret, NewCustomSecretPatch, NewIdentitySecret,
+    NewIdentitySecretPatch, NewLoginSecret, NewLoginSecretPatch, NewPaymentCardSecret,
+    NewPaymentCardSecretPatch, NewSecretEntry, NewSecretEntryPatch, NewSecureNoteSecret,
+    NewSecureNoteSecretPatch, NewSshKeySecret, NewSshKeySecretPatch, NewWifiNetworkSecret,
+    NewWifiNetworkSecretPatch, OtpAuthUri, PaymentCardEntryView, SECRETS_ROOT_FOLDER_ID,
+    SecretEntry, SecretEntryView, SecretFolder, SecretKind, SecretTrashEntry, SecretTrashItem,
+    SecureNoteEntryView, SshKeyEntryView, TOTP, TotpAlgorithm, TotpCode, WeakPattern,
+    WifiNetworkEntryView, WifiSecurity,
+};
+pub use openvault_core::features::shared::DEFAULT_TRASH_RETENTION;
+pub use openvault_core::operations::archive::{
+    ArchiveDestination, ArchiveImport, ArchiveSelection,
+};
+pub use openvault_core::operations::config::{CreateConfig, KdfConfig};
+pub use openvault_core::operations::key_slots::KeySlotInfo;
+pub use openvault_core::operations::share::SharedFileDestination;
+pub use openvault_core::operations::space::{
+    AutoCompaction, CompactionPolicy, CompactionTrigger, SpaceReport,
+};
+pub use openvault_core::vault::crypto::credential::Credential;
+pub use openvault_core::vault::versions::shared::format::BlobStream;
+pub use openvault_core::vault::versions::shared::key_slot::KeySlotKind;
+pub use openvault_core::vault::versions::shared::replay::ReplayTarget;
+pub use openvault_core::vault::versions::shared::verify::{
+    BrokenFrame, DanglingBlob, FrameKind, OrphanedFrame, SequenceGap, VerifyReport,
 };
-pub use openvault_core::operations::config::CreateConfig;
 pub use openvault_crypto::compression::CompressionAlgorithm;
 pub use openvault_crypto::encryption::EncryptionAlgorithm;
+pub use openvault_crypto::generator::{GeneratedPassword, PassphraseOptions, PasswordOptions};
+pub use openvault_crypto::keys::kdf_params::KdfParams;
+pub use openvault_crypto::keys::recovery_key::RecoveryKey;
 pub use openvault_crypto::keys::{EphemeralPublicKey, SigningPublicKey};
 pub use openvault_crypto::memory::{SecretSlice, SecretVec};
+pub use openvault_crypto::protocols::messaging::STREAM_MAGIC;
 
 pub fn client() -> VaultClient {
     VaultClient::new()
@@ -45,6 +80,10 @@ pub fn open_vault(path: impl AsRef<Path>, password: impl AsRef<[u8]>) -> Result<
     client().open(path, password)
 }
 
+pub fn verify_vault(path: impl AsRef<Path>, password: impl AsRef<[u8]>) -> Result<VerifyReport> {
+    client().verify(path, &Credential::password(password.as_ref()))
+}
+
 pub fn create_and_open_vault(
     path: impl AsRef<Path>,
     password: impl AsRef<[u8]>,
@@ -52,3 +91,11 @@ pub fn create_and_open_vault(
 ) -> Result<Vault> {
     client().create_and_open(path, password, options)
 }
+
+pub fn generate_password(options: &PasswordOptions) -> Result<GeneratedPassword> {
+    GeneratedPassword::generate(options).map_err(|e| Error::Core(e.into()))
+}
+
+pub fn generate_passphrase(options: &PassphraseOptions) -> Result<GeneratedPassword> {
+    GeneratedPassword::passphrase(options).map_err(|e| Error::Core(e.into()))
+}
@@ -1,20 +1,41 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
 use std::path::Path;
 
+use chrono::TimeDelta;
+use uuid::Uuid;
+
+use openvault_core::archive::{ArchiveCredential, ArchiveRecipient};
+
+use openvault_core::features::chunks::ChunksStore;
 use openvault_core::features::filesystem::FilesystemStore;
 use openvault_core::features::messages::MessagesStore;
 use openvault_core::features::secrets::SecretStore;
-use openvault_core::operations::{compact, history, replay};
+use openvault_core::features::shared::DEFAULT_TRASH_RETENTION;
+use openvault_core::operations::archive::{
+    self, ArchiveDestination, ArchiveImport, ArchiveSelection,
+};
+use openvault_core::operations::key_slots::{self, KeySlotInfo};
+use openvault_core::operations::share::{self, SharedFileDestination};
+use openvault_core::operations::space::{self, AutoCompaction, CompactionTrigger, SpaceReport};
+use openvault_core::operations::{compact, history, replay, vault};
 use openvault_core::repositories::{
-    FeatureRepository, FilesystemRepository, MessagesRepository, SecretsRepository,
+    ChunksRepository, FeatureRepository, FilesystemRepository, MessagesRepository,
+    SecretsRepository,
 };
+use openvault_core::vault::crypto::credential::Credential;
 use openvault_core::vault::runtime::VaultSession;
 use openvault_core::vault::versions::shared::checkpoint::Checkpoint;
+use openvault_core::vault::versions::shared::replay::ReplayTarget;
+use openvault_crypto::keys::kdf_params::KdfParams;
+use openvault_crypto::keys::recovery_key::RecoveryKey;
 use zeroize::Zeroize;
 
 use crate::errors::Result;
 use crate::features::filesystem::FilesystemService;
 use crate::features::messages::MessagesService;
 use crate::features::secrets::SecretsService;
+use crate::view::VaultView;
 
 // @todo-soon fix the OCP problem on commit, commit_checkpoint, compact
 // @todo-soon return the checkpoint from the compact
@@ -22,24 +43,33 @@ use crate::features::secrets::SecretsService;
 #[derive(Zeroize)]
 pub struct Vault {
     session: VaultSession,
+    chunks: ChunksStore,
     filesystem: FilesystemStore,
     messages: MessagesStore,
     secrets: SecretStore,
+    #[zeroize(skip)]
+    trash_retention: TimeDelta,
+    #[zeroize(skip)]
+    auto_compaction: Option<AutoCompaction>,
 }
 
 impl Vault {
     pub(crate) fn new(mut session: VaultSession) -> Result<Self> {
         let replay = replay::replay_since_checkpoint(&mut session)?;
 
+        let chunks = ChunksRepository::restore_from_replay(&replay)?;
         let filesystem = FilesystemRepository::restore_from_replay(&replay)?;
         let messages = MessagesRepository::restore_from_replay(&replay)?;
         let secrets = SecretsRepository::restore_from_replay(&replay)?;
 
         Ok(Self {
             session,
+            chunks,
             filesystem,
             messages,
             secrets,
+            trash_retention: DEFAULT_TRASH_RETENTION,
+            auto_compaction: None,
         })
     }
 
@@ -56,6 +86,16 @@ impl Vault {
     }
 
     pub fn commit(&mut self) -> Result {
+        self.commit_pending()?;
+        self.auto_compact(CompactionTrigger::OnCommit)?;
+
+        Ok(())
+    }
+
+    fn commit_pending(&mut self) -> Result {
+        self.purge_expired_trash()?;
+
+        ChunksRepository::commit(&mut self.session, &mut self.chunks)?;
         FilesystemRepository::commit(&mut self.session, &mut self.filesystem)?;
         MessagesRepository::commit(&mut self.session, &mut self.messages)?;
         SecretsRepository::commit(&mut self.session, &mut self.secrets)?;
@@ -69,6 +109,7 @@ impl Vault {
 
     fn commit_checkpoint(&mut self) -> Result {
         let checkpoint_features = vec![
+            ChunksRepository::create_checkpoint(&self.chunks)?,
             FilesystemRepository::create_checkpoint(&self.filesystem)?,
             MessagesRepository::create_checkpoint(&self.messages)?,
             SecretsRepository::create_checkpoint(&self.secrets)?,
@@ -81,24 +122,276 @@ impl Vault {
     }
 
     pub fn compact(&mut self) -> Result {
-        self.commit()?;
+        self.commit_pending()?;
 
         compact::compact_vault(&mut self.session)?;
 
-        self.filesystem = FilesystemRepository::load(&mut self.session)?;
-        self.messages = MessagesRepository::load(&mut self.session)?;
-        self.secrets = SecretsRepository::load(&mut self.session)?;
+        self.reload()
+    }
+
+    pub fn compact_and_rotate_keys(&mut self, credentials: &[Credential]) -> Result {
+        self.commit_pending()?;
+
+        compact::compact_and_rotate_keys(&mut self.session, credentials)?;
+
+        self.reload()
+    }
+
+    pub fn space_report(&mut self) -> Result<SpaceReport> {
+        space::space_report(&mut self.session).map_err(Into::into)
+    }
+
+    pub fn auto_compaction(&self) -> Option<AutoCompaction> {
+        self.auto_compaction
+    }
+
+    pub fn set_auto_compaction(&mut self, auto_compaction: Option<AutoCompaction>) {
+        self.auto_compaction = auto_compaction;
+    }
+
+    pub fn compact_if_needed(&mut self) -> Result<bool> {
+        let Some(auto_compaction) = self.auto_compaction else {
+            return Ok(false);
+        };
+
+        if !auto_compaction.policy.should_compact(&self.space_report()?) {
+            return Ok(false);
+        }
+
+        self.compact()?;
+
+        Ok(true)
+    }
+
+    fn auto_compact(&mut self, trigger: CompactionTrigger) -> Result {
+        if self
+            .auto_compaction
+            .is_some_and(|auto_compaction| auto_compaction.trigger == trigger)
+        {
+            self.compact_if_needed()?;
+        }
 
         Ok(())
     }
 
+    pub fn lock(mut self) -> Result {
+        let result = self
+            .commit_pending()
+            .and_then(|_| self.auto_compact(CompactionTrigger::OnLock));
+
+        self.zeroize();
+
+        result
+    }
+
+    pub fn change_password(
+        &mut self,
+        current_password: impl AsRef<[u8]>,
+        new_password: impl AsRef<[u8]>,
+    ) -> Result {
+        self.commit()?;
+
+        vault::change_password(
+            &mut self.session,
+            current_password.as_ref(),
+            new_password.as_ref(),
+        )?;
+
+        self.reload()
+    }
+
+    pub fn kdf_params(&mut self) -> Result<KdfParams> {
+        vault::kdf_params(&mut self.session).map_err(Into::into)
+    }
+
+    pub fn upgrade_kdf_params(&mut self, credentials: &[Credential], params: KdfParams) -> Result {
+        self.commit_pending()?;
+
+        vault::upgrade_kdf_params(&mut self.session, credentials, params)?;
+
+        self.reload()
+    }
+
+    pub fn key_slots(&mut self) -> Result<Vec<KeySlotInfo>> {
+        key_slots::list_key_slots(&mut self.session).map_err(Into::into)
+    }
+
+    pub fn add_password(&mut self, password: impl AsRef<[u8]>) -> Result<usize> {
+        let credential = Credential::password(password.as_ref());
+        key_slots::add_key_slot(&mut self.session, &credential).map_err(Into::into)
+    }
+
+    pub fn add_recovery_key(&mut self) -> Result<RecoveryKey> {
+        let recovery_key = RecoveryKey::generate();
+        let credential = Credential::recovery_key(&recovery_key);
+
+        key_slots::add_key_slot(&mut self.session, &credential)?;
+
+        Ok(recovery_key)
+    }
+
+    pub fn add_keyfile(&mut self, keyfile: impl AsRef<Path>) -> Result<usize> {
+        let mut contents = std::fs::read(keyfile)?;
+        let credential = Credential::keyfile(&contents);
+        contents.zeroize();
+
+        key_slots::add_key_slot(&mut self.session, &credential).map_err(Into::into)
+    }
+
+    pub fn revoke_key_slot(&mut self, index: usize) -> Result {
+        key_slots::revoke_key_slot(&mut self.session, index).map_err(Into::into)
+    }
+
     pub fn path(&self) -> &Path {
         self.session.file_path()
     }
 
+    pub fn trash_retention(&self) -> TimeDelta {
+        self.trash_retention
+    }
+
+    pub fn set_trash_retention(&mut self, retention: TimeDelta) {
+        self.trash_retention = retention;
+    }
+
+    pub fn purge_expired_trash(&mut self) -> Result {
+        let retention = self.trash_retention;
+
+        self.filesystem().purge_expired_trash(retention)?;
+        self.secrets().purge_expired_trash(retention)
+    }
+
+    pub fn export_archive(
+        &mut self,
+        selection: &ArchiveSelection,
+        recipient: &ArchiveRecipient,
+        destination: impl AsRef<Path>,
+    ) -> Result {
+        let destination = destination.as_ref();
+        let mut output = BufWriter::new(File::create_new(destination)?);
+
+        let result = archive::export_archive(
+            &mut self.session,
+            &self.filesystem,
+            &self.secrets,
+            selection,
+            recipient,
+            &mut output,
+        );
+        drop(output);
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(destination);
+        }
+
+        result.map_err(Into::into)
+    }
+
+    pub fn import_archive(
+        &mut self,
+        source: impl AsRef<Path>,
+        credential: &ArchiveCredential,
+        destination: &ArchiveDestination,
+    ) -> Result<ArchiveImport> {
+        self.commit_pending()?;
+
+        let mut input = BufReader::new(File::open(source)?);
+
+        let result = archive::import_archive(
+            &mut self.session,
+            &mut self.chunks,
+            &mut self.filesystem,
+            &mut self.secrets,
+            destination,
+            credential,
+            &mut input,
+        );
+
+        match result {
+            Ok(summary) => Ok(summary),
+            Err(error) => {
+                self.reload()?;
+                Err(error.into())
+            }
+        }
+    }
+
+    pub fn share_file(
+        &mut self,
+        file_id: Uuid,
+        contact_id: Uuid,
+        destination: impl AsRef<Path>,
+    ) -> Result<u64> {
+        let destination = destination.as_ref();
+        let mut output = BufWriter::new(File::create_new(destination)?);
+
+        let result = share::share_file(
+            &mut self.session,
+            &self.filesystem,
+            &self.messages,
+            file_id,
+            contact_id,
+            &mut output,
+        );
+        let result = result.and_then(|written| {
+            output.into_inner().map_err(|err| err.into_error())?;
+            Ok(written)
+        });
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(destination);
+        }
+
+        result.map_err(Into::into)
+    }
+
+    pub fn import_shared_file(
+        &mut self,
+        contact_id: Uuid,
+        source: impl AsRef<Path>,
+        destination: SharedFileDestination,
+    ) -> Result<Uuid> {
+        self.commit_pending()?;
+
+        let mut input = BufReader::new(File::open(source)?);
+
+        let result = share::import_shared_file(
+            &mut self.session,
+            &mut self.chunks,
+            &mut self.filesystem,
+            &self.messages,
+            contact_id,
+            destination,
+            &mut input,
+        );
+
+        match result {
+            Ok(id) => Ok(id),
+            Err(error) => {
+                self.reload()?;
+                Err(error.into())
+            }
+        }
+    }
+
+    pub fn view_at(&mut self, target: ReplayTarget) -> Result<VaultView<'_>> {
+        VaultView::open(&mut self.session, target)
+    }
+
+    fn reload(&mut self) -> Result {
+        let replay = replay::replay_since_checkpoint(&mut self.session)?;
+
+        self.chunks = ChunksRepository::restore_from_replay(&replay)?;
+        self.filesystem = FilesystemRepository::restore_from_replay(&replay)?;
+        self.messages = MessagesRepository::restore_from_replay(&replay)?;
+        self.secrets = SecretsRepository::restore_from_replay(&replay)?;
+
+        Ok(())
+    }
+
     #[inline]
     pub fn filesystem(&mut self) -> FilesystemService<'_> {
-        FilesystemService::new(&mut self.session, &mut self.filesystem)
+        FilesystemService::new(&mut self.session, &mut self.filesystem, &mut self.chunks)
     }
 
     #[inline]
@@ -0,0 +1,115 @@
+use uuid::Uuid;
+use zeroize::Zeroize;
+
+use openvault_core::features::filesystem::{FileMetadata, FilesystemStore, FolderMetadata};
+use openvault_core::features::messages::{MessageContact, MessagesStore};
+use openvault_core::features::secrets::{
+    SECRETS_FEATURE_ID, SecretEntry, SecretEntryView, SecretFolder, SecretStore,
+};
+use openvault_core::operations::{blob, replay};
+use openvault_core::repositories::{
+    FeatureRepository, FilesystemRepository, MessagesRepository, SecretsRepository,
+};
+use openvault_core::vault::runtime::VaultSession;
+use openvault_core::vault::versions::shared::format::BlobStream;
+use openvault_core::vault::versions::shared::replay::{ReplayState, ReplayTarget};
+
+use crate::errors::{Error, Result};
+
+#[derive(Zeroize)]
+pub struct VaultView<'a> {
+    #[zeroize(skip)]
+    session: &'a mut VaultSession,
+    #[zeroize(skip)]
+    sequence: u64,
+    filesystem: FilesystemStore,
+    messages: MessagesStore,
+    secrets: SecretStore,
+}
+
+impl<'a> VaultView<'a> {
+    pub(crate) fn open(session: &'a mut VaultSession, target: ReplayTarget) -> Result<Self> {
+        let replay = replay::replay_at(session, target)?;
+
+        let filesystem = FilesystemRepository::restore_from_replay(&replay)?;
+        let messages = MessagesRepository::restore_from_replay(&replay)?;
+        let secrets = SecretsRepository::restore_from_replay(&replay)?;
+
+        Ok(Self {
+            session,
+            sequence: replayed_sequence(&replay),
+            filesystem,
+            messages,
+            secrets,
+        })
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn browse_files(
+        &self,
+        parent_id: &Uuid,
+    ) -> Result<(Vec<FolderMetadata>, Vec<FileMetadata>)> {
+        self.filesystem.browse(parent_id).map_err(Error::from)
+    }
+
+    pub fn file(&self, id: &Uuid) -> Result<FileMetadata> {
+        self.get_file(id).cloned()
+    }
+
+    pub fn read_file_bytes(&mut self, id: Uuid) -> Result<Vec<u8>> {
+        let blob_ref = self.get_file(&id)?.blob.clone();
+
+        blob::read_blob(self.session, &blob_ref).map_err(Error::from)
+    }
+
+    pub fn open_file(&mut self, id: Uuid) -> Result<BlobStream<'_>> {
+        let blob_ref = self.get_file(&id)?.blob.clone();
+
+        blob::open_blob(self.session, &blob_ref).map_err(Error::from)
+    }
+
+    pub fn browse_secrets(
+        &self,
+        parent_id: &Uuid,
+    ) -> Result<(Vec<SecretFolder>, Vec<SecretEntry>)> {
+        self.secrets.browse(parent_id).map_err(Error::from)
+    }
+
+    pub fn reveal_entry(&self, id: &Uuid) -> Result<SecretEntryView> {
+        let key = self
+            .session
+            .keyring()
+            .derive_feature_key(self.session.version(), SECRETS_FEATURE_ID)?;
+
+        let entry = self
+            .secrets
+            .entry(id)
+            .ok_or_else(|| Error::ItemNotFound(id.to_string()))?;
+
+        entry
+            .reveal(&key, self.session.cipher())
+            .map_err(Error::from)
+    }
+
+    pub fn list_contacts(&self) -> Vec<MessageContact> {
+        self.messages.list_contacts()
+    }
+
+    fn get_file(&self, id: &Uuid) -> Result<&FileMetadata> {
+        self.filesystem
+            .file(id)
+            .ok_or(Error::ItemNotFound(id.to_string()))
+    }
+}
+
+fn replayed_sequence(replay: &ReplayState) -> u64 {
+    replay
+        .records
+        .last()
+        .map(|record| record.header.sequence)
+        .or_else(|| replay.checkpoint.as_ref().map(|cp| cp.sequence))
+        .unwrap_or_default()
+}